@@ -1,11 +1,9 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use std::fs;
-use std::time::Instant;
-use serde_json;
 use flatbuffers::root;
 
-mod cards_generated;
-use cards_generated::altered_cards::*;
+use altered_harvester::card_db::{CardDb, CardFilter, OptimizedCardDb};
+use altered_harvester::cards_generated::altered_cards::*;
 
 fn benchmark_json_parsing(c: &mut Criterion) {
     let json_data = fs::read_to_string("altered_optimized.json")
@@ -72,7 +70,69 @@ fn benchmark_card_lookup(c: &mut Criterion) {
             black_box(count);
         })
     });
+    
+    c.bench_function("card_db_card_lookup", |b| {
+        b.iter(|| {
+            let card_db = CardDb::new(&fb_data).unwrap();
+            let count = card_db.cards().filter(|card| card.name().contains("Sierra")).count();
+            black_box(count);
+        })
+    });    
+    c.bench_function("card_db_name_search", |b| {
+        let card_db = CardDb::new(&fb_data).unwrap();
+        b.iter(|| {
+            let matches = card_db.search_names(black_box("Sierra"), 20);
+            black_box(matches.len());
+        })
+    });
+    
+    c.bench_function("card_db_lookup_by_reference", |b| {
+        let card_db = CardDb::new(&fb_data).unwrap();
+        b.iter(|| {
+            let card = card_db.card(black_box("ALT_CORE_B_AX_04_C"));
+            black_box(card.map(|card| card.index()));
+        })
+    });
+}
+
+fn benchmark_reader_filters(c: &mut Criterion) {
+    let fb_data = fs::read("altered_cards.fb")
+        .expect("Failed to read FlatBuffer file");
+    let optimized_data = fs::read("altered_cards_optimized_v2.fb")
+        .expect("Failed to read optimized FlatBuffer file");
+    let card_db = CardDb::new(&fb_data).unwrap();
+    let optimized_db = OptimizedCardDb::new(&optimized_data).unwrap();
+    
+    c.bench_function("card_db_faction_filter", |b| {
+        b.iter(|| {
+            let count = card_db.cards()
+                .filter(|card| card.faction().is_some_and(|f| f.reference == black_box("AX")))
+                .count();
+            black_box(count);
+        })
+    });
+    
+    c.bench_function("card_db_posting_filter", |b| {
+        b.iter(|| {
+            let cards = card_db.filter(black_box(&CardFilter {
+                faction: Some("AX"),
+                main_cost: Some(2),
+                suspended: Some(false),
+                ..CardFilter::default()
+            }));
+            black_box(cards.len());
+        })
+    });
+    
+    c.bench_function("optimized_card_db_faction_filter", |b| {
+        b.iter(|| {
+            let count = optimized_db.cards()
+                .filter(|card| card.faction().is_some_and(|f| f.reference == black_box("AX")))
+                .count();
+            black_box(count);
+        })
+    });
 }
 
-criterion_group!(benches, benchmark_json_parsing, benchmark_flatbuffer_access, benchmark_card_lookup);
+criterion_group!(benches, benchmark_json_parsing, benchmark_flatbuffer_access, benchmark_card_lookup, benchmark_reader_filters);
 criterion_main!(benches);
\ No newline at end of file
@@ -0,0 +1,157 @@
+// Content-addressed store for card images and assets.
+// Every downloaded file is saved as `<dir>/objects/<aa>/<sha256>.<ext>`, so
+// identical files served under different URLs are stored once. `index.json`
+// maps each downloaded URL to its file, which lets later runs skip URLs whose
+// file is already present without downloading them again.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use crate::checkpoint::stable_hash;
+use crate::config::{AssetConfig, CacheConfig, HarvestConfig};
+use crate::fetcher::Fetcher;
+use crate::harvester::run_workers;
+
+const INDEX_FILENAME: &str = "index.json";
+
+/// A downloaded file.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct LocalFile {
+    /// Path relative to the store directory, with `/` separators.
+    pub path: String,
+    /// Hex SHA-256 of the content.
+    pub sha256: String,
+    pub size: u64,
+}
+
+pub struct AssetStore {
+    dir: PathBuf,
+    /// Downloaded URLs and their files.
+    index: Mutex<BTreeMap<String, LocalFile>>,
+}
+
+impl AssetStore {
+    /// Opens the store in `dir`, creating it if needed.
+    pub fn open(dir: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        fs::create_dir_all(dir.join("objects"))?;
+        let index_path = dir.join(INDEX_FILENAME);
+        let index = if index_path.exists() {
+            serde_json::from_reader(BufReader::new(File::open(&index_path)?))
+                .map_err(|e| format!("corrupt asset index '{}': {}", index_path.display(), e))?
+        } else {
+            BTreeMap::new()
+        };
+        Ok(AssetStore {
+            dir: dir.to_path_buf(),
+            index: Mutex::new(index),
+        })
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Returns the file downloaded from `url`, if it is still on disk.
+    pub fn get(&self, url: &str) -> Option<LocalFile> {
+        let file = self.index.lock().unwrap().get(url).cloned()?;
+        self.dir.join(&file.path).is_file().then_some(file)
+    }
+
+    /// Stores the content downloaded from `url`. The file is only written
+    /// when no identical content is stored yet.
+    pub fn insert(&self, url: &str, content: &[u8]) -> Result<LocalFile, Box<dyn std::error::Error>> {
+        let sha256 = format!("{:x}", Sha256::digest(content));
+        let path = format!("objects/{}/{}.{}", &sha256[..2], sha256, extension(url));
+        let full_path = self.dir.join(&path);
+        if !full_path.is_file() {
+            fs::create_dir_all(full_path.parent().unwrap())?;
+            // Named after the URL so that two workers storing the same
+            // content never write to the same temporary file.
+            let tmp_path = full_path.with_extension(format!("{:016x}.tmp", stable_hash(url)));
+            fs::write(&tmp_path, content)?;
+            fs::rename(&tmp_path, &full_path)?;
+        }
+        let file = LocalFile {
+            path,
+            sha256,
+            size: content.len() as u64,
+        };
+        self.index.lock().unwrap().insert(url.to_string(), file.clone());
+        Ok(file)
+    }
+
+    pub fn save_index(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let path = self.dir.join(INDEX_FILENAME);
+        let tmp_path = path.with_extension("json.tmp");
+        let mut writer = BufWriter::new(File::create(&tmp_path)?);
+        serde_json::to_writer_pretty(&mut writer, &*self.index.lock().unwrap())?;
+        writer.flush()?;
+        fs::rename(&tmp_path, &path)?;
+        Ok(())
+    }
+}
+
+/// File extension of the URL path, e.g. `jpg`, or `bin` when there is none.
+fn extension(url: &str) -> String {
+    let path = url.split(['?', '#']).next().unwrap_or(url);
+    let file_name = path.rsplit('/').next().unwrap_or(path);
+    match file_name.rsplit_once('.') {
+        Some((_, ext)) if !ext.is_empty() && ext.len() <= 5 && ext.bytes().all(|b| b.is_ascii_alphanumeric()) => {
+            ext.to_ascii_lowercase()
+        }
+        _ => "bin".to_string(),
+    }
+}
+
+/// Downloads every URL missing from the store and returns the file of each
+/// URL. The index is saved even when a download fails, so the files fetched
+/// so far are skipped by the next run.
+pub fn download_assets(
+    config: &AssetConfig,
+    harvest: &HarvestConfig,
+    urls: &BTreeSet<String>,
+) -> Result<BTreeMap<String, LocalFile>, Box<dyn std::error::Error>> {
+    let store = AssetStore::open(Path::new(&config.dir))?;
+    let missing: Vec<&String> = urls.iter().filter(|url| store.get(url).is_none()).collect();
+    println!(
+        "   > {} file(s) referenced, {} already in '{}'",
+        urls.len(),
+        urls.len() - missing.len(),
+        store.dir().display()
+    );
+
+    if !missing.is_empty() {
+        // Images are served by a different host than the API, with its own
+        // rate limit. They are content-addressed, so the response cache is not used.
+        let fetcher = Fetcher::new(&HarvestConfig {
+            rate_limit: config.rate_limit.clone(),
+            cache: CacheConfig {
+                enabled: false,
+                ..CacheConfig::default()
+            },
+            ..harvest.clone()
+        })?;
+        let worker_count = config.workers.clamp(1, missing.len());
+        println!(
+            "   > Downloading {} file(s) with {} worker(s)",
+            missing.len(),
+            worker_count
+        );
+        let result = run_workers(worker_count, &missing, |url| {
+            let content = fetcher.get_bytes(url)?;
+            store.insert(url, &content)
+        });
+        store.save_index()?;
+        result?;
+    }
+
+    Ok(urls
+        .iter()
+        .filter_map(|url| store.get(url).map(|file| (url.clone(), file)))
+        .collect())
+}
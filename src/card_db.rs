@@ -0,0 +1,749 @@
+// Zero-copy readers for the card FlatBuffers.
+// `CardDb` reads the `CardDatabase` of `schema/cards.fbs` (altered_cards.fb)
+// and `OptimizedCardDb` the `OptimizedCardDatabase` of
+// `schema/cards_optimized.fbs` (altered_cards_optimized_v2.fb). The buffer,
+// read into memory or memory-mapped, is verified once when the reader is
+// created; accessors then borrow from it and do not allocate. Both
+// readers resolve the faction, rarity and type indices of a card and decode
+// its power, so consumers never index the lookup vectors by hand. Cards are
+// sorted by reference in both layouts, and the optimized one carries an index
+// sorted by numeric ID, so lookups are binary searches. `CardDb` also exposes
+// the posting lists embedded per faction, type, rarity, main cost and
+// suspension, which `CardDb::filter` intersects, and `CardDb::search_names`
+// uses the embedded trigram index; both return their results in a `Vec`.
+// Databases written before the posting lists or the trigram index existed
+// are still read; their cards are scanned instead. Neither layout carries a
+// file identifier, so `AnyCardDb` tells them apart by which one verifies.
+
+use flatbuffers::{ForwardsUOffset, Vector};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use crate::cards_generated::altered_cards as full;
+use crate::cards_optimized_generated::altered_cards as optimized;
+use crate::name_index::{fold, query_grams};
+use crate::optimizer_v2::{unpack_power_values, FLAG_SUSPENDED};
+use crate::suspensions::suspension_timeline;
+
+type Tables<'a, T> = Vector<'a, ForwardsUOffset<T>>;
+
+/// Power of a card in each region.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Power {
+    pub mountain: u8,
+    pub ocean: u8,
+    pub forest: u8,
+}
+
+/// A faction, rarity or card type resolved from a card.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lookup<'a> {
+    pub reference: &'a str,
+    pub name: &'a str,
+}
+
+/// Binary search over the positions `0..len`, `compare` telling how the
+/// element at a position orders against the key. The flatbuffers release we
+/// depend on predates `Vector::lookup_by_key`.
+fn lookup_by_key(len: usize, compare: impl Fn(usize) -> Ordering) -> Option<usize> {
+    let (mut low, mut high) = (0, len);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        match compare(mid) {
+            Ordering::Less => low = mid + 1,
+            Ordering::Greater => high = mid,
+            Ordering::Equal => return Some(mid),
+        }
+    }
+    None
+}
+
+/// Ascending card indices read from a posting list, or collected by a scan
+/// of the cards when the database has no posting lists.
+#[derive(Clone)]
+pub struct Postings<'a> {
+    indices: Option<Vector<'a, u32>>,
+    scanned: Vec<usize>,
+}
+
+impl<'a> Postings<'a> {
+    fn embedded(indices: Option<Vector<'a, u32>>) -> Postings<'a> {
+        Postings {
+            indices,
+            scanned: Vec::new(),
+        }
+    }
+
+    fn scanned(scanned: Vec<usize>) -> Postings<'a> {
+        Postings { indices: None, scanned }
+    }
+
+    pub fn len(&self) -> usize {
+        self.indices.map_or(self.scanned.len(), |indices| indices.len())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn contains(&self, index: usize) -> bool {
+        match self.indices {
+            Some(indices) => {
+                lookup_by_key(indices.len(), |position| (indices.get(position) as usize).cmp(&index)).is_some()
+            }
+            None => self.scanned.binary_search(&index).is_ok(),
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = usize> + 'a {
+        self.indices
+            .map(|indices| indices.iter())
+            .into_iter()
+            .flatten()
+            .map(|index| index as usize)
+            .chain(self.scanned.clone())
+    }
+}
+
+/// Criteria of `CardDb::filter`; a `None` field matches every card.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CardFilter<'f> {
+    /// Faction reference, e.g. "AX".
+    pub faction: Option<&'f str>,
+    pub card_type: Option<&'f str>,
+    pub rarity: Option<&'f str>,
+    pub main_cost: Option<u8>,
+    pub suspended: Option<bool>,
+}
+
+/// Share of the query trigrams a card must have to be a fuzzy match.
+const MIN_FUZZY_SIMILARITY: f32 = 0.5;
+
+/// How a card name matched a search, from worst to best.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum MatchKind {
+    /// Shares enough trigrams with the query, e.g. despite a typo.
+    Fuzzy,
+    /// Contains the query.
+    Substring,
+    /// Has a word starting with the query.
+    WordPrefix,
+    /// Starts with the query.
+    Prefix,
+    Exact,
+}
+
+/// A result of `CardDb::search_names`.
+#[derive(Clone, Copy)]
+pub struct NameMatch<'a> {
+    pub card: CardRef<'a>,
+    /// Best match over the card's names in every locale.
+    pub kind: MatchKind,
+    /// Share of the query trigrams found in the card's names.
+    pub similarity: f32,
+}
+
+/// How a folded name matches a folded query, if it does beyond trigrams.
+fn match_kind(name: &str, query: &str) -> MatchKind {
+    if name == query {
+        MatchKind::Exact
+    } else if name.starts_with(query) {
+        MatchKind::Prefix
+    } else if name.contains(&format!(" {}", query)) {
+        MatchKind::WordPrefix
+    } else if name.contains(query) {
+        MatchKind::Substring
+    } else {
+        MatchKind::Fuzzy
+    }
+}
+
+/// Best match of the query over the names of `card` in every locale.
+fn best_match_kind(card: CardRef, folded_query: &str) -> MatchKind {
+    let localized = card
+        .raw()
+        .names()
+        .map(|names| names.iter())
+        .into_iter()
+        .flatten()
+        .filter_map(|name| name.value());
+    std::iter::once(card.name())
+        .chain(localized)
+        .map(|name| match_kind(&fold(name), folded_query))
+        .max()
+        .unwrap_or(MatchKind::Fuzzy)
+}
+
+/// First of the positions `0..len` for which `is_less` is false.
+fn lower_bound(len: usize, is_less: impl Fn(usize) -> bool) -> usize {
+    let (mut low, mut high) = (0, len);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if is_less(mid) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
+
+fn missing(vector: &str) -> Box<dyn std::error::Error> {
+    format!("FlatBuffer database has no {} vector", vector).into()
+}
+
+/// Reader over a `CardDatabase` buffer.
+#[derive(Clone, Copy)]
+pub struct CardDb<'a> {
+    database: full::CardDatabase<'a>,
+    cards: Tables<'a, full::Card<'a>>,
+    factions: Tables<'a, full::Faction<'a>>,
+    rarities: Tables<'a, full::Rarity<'a>>,
+    card_types: Tables<'a, full::CardType<'a>>,
+    // Absent from databases written before the posting lists were embedded.
+    cards_by_faction: Option<Tables<'a, full::PostingList<'a>>>,
+    cards_by_card_type: Option<Tables<'a, full::PostingList<'a>>>,
+    cards_by_rarity: Option<Tables<'a, full::PostingList<'a>>>,
+    cards_by_main_cost: Option<Tables<'a, full::PostingList<'a>>>,
+    suspended_cards: Option<Vector<'a, u32>>,
+    name_grams: Option<Tables<'a, full::NameGram<'a>>>,
+}
+
+/// The list of `value` in lists sorted by value, empty when there is none.
+fn posting_list<'a>(lists: Tables<'a, full::PostingList<'a>>, value: u32) -> Postings<'a> {
+    let indices = lookup_by_key(lists.len(), |index| lists.get(index).key_compare_with_value(value))
+        .and_then(|index| lists.get(index).cards());
+    Postings::embedded(indices)
+}
+
+impl<'a> CardDb<'a> {
+    pub fn new(bytes: &'a [u8]) -> Result<CardDb<'a>, Box<dyn std::error::Error>> {
+        let database = flatbuffers::root::<full::CardDatabase>(bytes)?;
+        Ok(CardDb {
+            database,
+            cards: database.cards().ok_or_else(|| missing("cards"))?,
+            factions: database.factions().ok_or_else(|| missing("factions"))?,
+            rarities: database.rarities().ok_or_else(|| missing("rarities"))?,
+            card_types: database.card_types().ok_or_else(|| missing("card_types"))?,
+            cards_by_faction: database.cards_by_faction(),
+            cards_by_card_type: database.cards_by_card_type(),
+            cards_by_rarity: database.cards_by_rarity(),
+            cards_by_main_cost: database.cards_by_main_cost(),
+            suspended_cards: database.suspended_cards(),
+            name_grams: database.name_grams(),
+        })
+    }
+
+    /// The root table, for the fields the reader does not cover.
+    pub fn database(&self) -> full::CardDatabase<'a> {
+        self.database
+    }
+
+    pub fn generated_at_utc(&self) -> &'a str {
+        self.database.generated_at_utc().unwrap_or("")
+    }
+
+    pub fn script_version(&self) -> &'a str {
+        self.database.script_version().unwrap_or("")
+    }
+
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<CardRef<'a>> {
+        (index < self.cards.len()).then(|| CardRef {
+            db: *self,
+            index,
+            card: self.cards.get(index),
+        })
+    }
+
+    /// Cards in reference order.
+    pub fn cards(&self) -> impl Iterator<Item = CardRef<'a>> + 'a {
+        let db = *self;
+        self.cards
+            .iter()
+            .enumerate()
+            .map(move |(index, card)| CardRef { db, index, card })
+    }
+
+    /// Finds a card by reference, its key.
+    pub fn card(&self, reference: &str) -> Option<CardRef<'a>> {
+        let index = lookup_by_key(self.cards.len(), |index| {
+            self.cards.get(index).key_compare_with_value(reference)
+        })?;
+        self.get(index)
+    }
+
+    /// The list of `value` in `lists`, or when the database predates the
+    /// posting lists the cards for which `matches` holds.
+    fn postings(
+        &self,
+        lists: Option<Tables<'a, full::PostingList<'a>>>,
+        value: u32,
+        matches: impl Fn(full::Card<'a>) -> bool,
+    ) -> Postings<'a> {
+        match lists {
+            Some(lists) => posting_list(lists, value),
+            None => self.scan(matches),
+        }
+    }
+
+    fn scan(&self, matches: impl Fn(full::Card<'a>) -> bool) -> Postings<'a> {
+        Postings::scanned(
+            self.cards
+                .iter()
+                .enumerate()
+                .filter(|(_, card)| matches(*card))
+                .map(|(index, _)| index)
+                .collect(),
+        )
+    }
+
+    pub fn cards_with_faction(&self, reference: &str) -> Postings<'a> {
+        match self.factions.iter().position(|faction| faction.reference() == Some(reference)) {
+            Some(index) => self.postings(self.cards_by_faction, index as u32, |card| {
+                card.faction_idx() as usize == index
+            }),
+            None => Postings::embedded(None),
+        }
+    }
+
+    pub fn cards_with_card_type(&self, reference: &str) -> Postings<'a> {
+        match self.card_types.iter().position(|card_type| card_type.reference() == Some(reference)) {
+            Some(index) => self.postings(self.cards_by_card_type, index as u32, |card| {
+                card.card_type_idx() as usize == index
+            }),
+            None => Postings::embedded(None),
+        }
+    }
+
+    pub fn cards_with_rarity(&self, reference: &str) -> Postings<'a> {
+        match self.rarities.iter().position(|rarity| rarity.reference() == Some(reference)) {
+            Some(index) => self.postings(self.cards_by_rarity, index as u32, |card| {
+                card.rarity_idx() as usize == index
+            }),
+            None => Postings::embedded(None),
+        }
+    }
+
+    pub fn cards_with_main_cost(&self, main_cost: u8) -> Postings<'a> {
+        self.postings(self.cards_by_main_cost, main_cost as u32, |card| card.main_cost() == main_cost)
+    }
+
+    pub fn suspended_cards(&self) -> Postings<'a> {
+        match self.suspended_cards {
+            Some(indices) => Postings::embedded(Some(indices)),
+            None => self.scan(|card| card.is_suspended()),
+        }
+    }
+
+    /// Suspension status changes, oldest first, optionally restricted to one
+    /// card.
+    pub fn suspension_timeline(&self, reference: Option<&str>) -> Vec<full::SuspensionEvent<'a>> {
+        suspension_timeline(&self.database, reference)
+    }
+
+    /// Cards matching every criterion, in reference order. Walks the shortest
+    /// of the selected posting lists and probes the others.
+    pub fn filter(&self, filter: &CardFilter) -> Vec<CardRef<'a>> {
+        let mut lists = Vec::new();
+        if let Some(faction) = filter.faction {
+            lists.push(self.cards_with_faction(faction));
+        }
+        if let Some(card_type) = filter.card_type {
+            lists.push(self.cards_with_card_type(card_type));
+        }
+        if let Some(rarity) = filter.rarity {
+            lists.push(self.cards_with_rarity(rarity));
+        }
+        if let Some(main_cost) = filter.main_cost {
+            lists.push(self.cards_with_main_cost(main_cost));
+        }
+        let suspended = self.suspended_cards();
+        if filter.suspended == Some(true) {
+            lists.push(suspended.clone());
+        }
+        lists.sort_by_key(|list| list.len());
+
+        let candidates: Box<dyn Iterator<Item = usize> + 'a> = match lists.first() {
+            Some(shortest) => Box::new(shortest.iter()),
+            None => Box::new(0..self.len()),
+        };
+        candidates
+            .filter(|&index| lists.iter().skip(1).all(|list| list.contains(index)))
+            .filter(|&index| filter.suspended != Some(false) || !suspended.contains(index))
+            .filter_map(|index| self.get(index))
+            .collect()
+    }
+
+    /// Cards containing `gram`, or for a gram shorter than three characters
+    /// every trigram it starts, each card once per gram.
+    fn cards_with_gram(grams: Tables<'a, full::NameGram<'a>>, gram: &str, found: impl FnMut(usize)) {
+        if gram.chars().count() >= 3 {
+            let index = lookup_by_key(grams.len(), |index| grams.get(index).key_compare_with_value(gram));
+            let postings = Postings::embedded(index.and_then(|index| grams.get(index).cards()));
+            postings.iter().for_each(found);
+            return;
+        }
+        let mut seen = Vec::new();
+        let first = lower_bound(grams.len(), |index| grams.get(index).gram() < gram);
+        for index in first..grams.len() {
+            let entry = grams.get(index);
+            if !entry.gram().starts_with(gram) {
+                break;
+            }
+            seen.extend(Postings::embedded(entry.cards()).iter());
+        }
+        seen.sort_unstable();
+        seen.dedup();
+        seen.into_iter().for_each(found);
+    }
+
+    /// Searches the names of the cards in every locale, ignoring case and
+    /// accents. The last word of the query matches as a prefix, so results
+    /// can be shown while typing; names sharing at least half of the query
+    /// trigrams are returned as fuzzy matches. Results are ranked by match
+    /// kind, then similarity, then reference, and at most `limit` are kept.
+    /// Databases without the trigram index are scanned for names containing
+    /// the query, with no fuzzy matches.
+    pub fn search_names(&self, query: &str, limit: usize) -> Vec<NameMatch<'a>> {
+        let folded_query = fold(query);
+        let grams = query_grams(&folded_query);
+        if grams.is_empty() {
+            return Vec::new();
+        }
+        let mut matches: Vec<NameMatch<'a>> = match self.name_grams {
+            Some(name_grams) => {
+                let mut shared: HashMap<usize, usize> = HashMap::new();
+                for gram in &grams {
+                    Self::cards_with_gram(name_grams, gram, |index| *shared.entry(index).or_default() += 1);
+                }
+                shared
+                    .into_iter()
+                    .filter_map(|(index, count)| {
+                        let card = self.get(index)?;
+                        let similarity = count as f32 / grams.len() as f32;
+                        let kind = best_match_kind(card, &folded_query);
+                        (kind > MatchKind::Fuzzy || similarity >= MIN_FUZZY_SIMILARITY).then_some(NameMatch {
+                            card,
+                            kind,
+                            similarity,
+                        })
+                    })
+                    .collect()
+            }
+            None => self
+                .cards()
+                .filter_map(|card| {
+                    let kind = best_match_kind(card, &folded_query);
+                    (kind > MatchKind::Fuzzy).then_some(NameMatch {
+                        card,
+                        kind,
+                        similarity: 1.0,
+                    })
+                })
+                .collect(),
+        };
+        matches.sort_by(|a, b| {
+            b.kind
+                .cmp(&a.kind)
+                .then(b.similarity.total_cmp(&a.similarity))
+                .then(a.card.reference().cmp(b.card.reference()))
+        });
+        matches.truncate(limit);
+        matches
+    }
+}
+
+/// One card of a `CardDb`.
+#[derive(Clone, Copy)]
+pub struct CardRef<'a> {
+    db: CardDb<'a>,
+    index: usize,
+    card: full::Card<'a>,
+}
+
+impl<'a> CardRef<'a> {
+    /// Position in the `cards` vector.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The card table, for the fields the reader does not cover.
+    pub fn raw(&self) -> full::Card<'a> {
+        self.card
+    }
+
+    pub fn reference(&self) -> &'a str {
+        self.card.reference()
+    }
+
+    pub fn name(&self) -> &'a str {
+        self.card.name().unwrap_or("")
+    }
+
+    pub fn image_path(&self) -> &'a str {
+        self.card.image_path().unwrap_or("")
+    }
+
+    pub fn faction(&self) -> Option<Lookup<'a>> {
+        let index = self.card.faction_idx() as usize;
+        let faction = (index < self.db.factions.len()).then(|| self.db.factions.get(index))?;
+        Some(Lookup {
+            reference: faction.reference().unwrap_or(""),
+            name: faction.name().unwrap_or(""),
+        })
+    }
+
+    pub fn rarity(&self) -> Option<Lookup<'a>> {
+        let index = self.card.rarity_idx() as usize;
+        let rarity = (index < self.db.rarities.len()).then(|| self.db.rarities.get(index))?;
+        Some(Lookup {
+            reference: rarity.reference().unwrap_or(""),
+            name: rarity.name().unwrap_or(""),
+        })
+    }
+
+    pub fn card_type(&self) -> Option<Lookup<'a>> {
+        let index = self.card.card_type_idx() as usize;
+        let card_type = (index < self.db.card_types.len()).then(|| self.db.card_types.get(index))?;
+        Some(Lookup {
+            reference: card_type.reference().unwrap_or(""),
+            name: card_type.name().unwrap_or(""),
+        })
+    }
+
+    pub fn main_cost(&self) -> u8 {
+        self.card.main_cost()
+    }
+
+    pub fn recall_cost(&self) -> u8 {
+        self.card.recall_cost()
+    }
+
+    pub fn power(&self) -> Power {
+        self.card
+            .power()
+            .map(|power| Power {
+                mountain: power.mountain(),
+                ocean: power.ocean(),
+                forest: power.forest(),
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn is_suspended(&self) -> bool {
+        self.card.is_suspended()
+    }
+}
+
+/// Reader over an `OptimizedCardDatabase` buffer. Strings live in the
+/// database's string pool; lookup tables are indexed by their numeric ID.
+#[derive(Clone, Copy)]
+pub struct OptimizedCardDb<'a> {
+    database: optimized::OptimizedCardDatabase<'a>,
+    strings: Vector<'a, ForwardsUOffset<&'a str>>,
+    cards: Tables<'a, optimized::Card<'a>>,
+    factions: Tables<'a, optimized::Faction<'a>>,
+    rarities: Tables<'a, optimized::Rarity<'a>>,
+    card_types: Tables<'a, optimized::CardType<'a>>,
+    id_index: Vector<'a, u32>,
+}
+
+impl<'a> OptimizedCardDb<'a> {
+    pub fn new(bytes: &'a [u8]) -> Result<OptimizedCardDb<'a>, Box<dyn std::error::Error>> {
+        let database = optimized::root_as_optimized_card_database(bytes)?;
+        Ok(OptimizedCardDb {
+            database,
+            strings: database
+                .string_pool()
+                .and_then(|pool| pool.strings())
+                .ok_or_else(|| missing("string pool"))?,
+            cards: database.cards().ok_or_else(|| missing("cards"))?,
+            factions: database.factions().ok_or_else(|| missing("factions"))?,
+            rarities: database.rarities().ok_or_else(|| missing("rarities"))?,
+            card_types: database.card_types().ok_or_else(|| missing("card_types"))?,
+            id_index: database.id_index().ok_or_else(|| missing("id_index"))?,
+        })
+    }
+
+    /// The root table, for the fields the reader does not cover.
+    pub fn database(&self) -> optimized::OptimizedCardDatabase<'a> {
+        self.database
+    }
+
+    /// A string of the pool; out-of-range indices read as empty.
+    pub fn string(&self, index: u32) -> &'a str {
+        let index = index as usize;
+        if index < self.strings.len() {
+            self.strings.get(index)
+        } else {
+            ""
+        }
+    }
+
+    pub fn generated_at_utc(&self) -> &'a str {
+        self.string(self.database.generated_at_utc_idx())
+    }
+
+    pub fn script_version(&self) -> &'a str {
+        self.string(self.database.script_version_idx())
+    }
+
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<OptimizedCardRef<'a>> {
+        (index < self.cards.len()).then(|| OptimizedCardRef {
+            db: *self,
+            index,
+            card: self.cards.get(index),
+        })
+    }
+
+    /// Cards in reference order.
+    pub fn cards(&self) -> impl Iterator<Item = OptimizedCardRef<'a>> + 'a {
+        let db = *self;
+        self.cards
+            .iter()
+            .enumerate()
+            .map(move |(index, card)| OptimizedCardRef { db, index, card })
+    }
+
+    /// Finds a card by reference.
+    pub fn card(&self, reference: &str) -> Option<OptimizedCardRef<'a>> {
+        let index = lookup_by_key(self.cards.len(), |index| {
+            self.string(self.cards.get(index).reference_idx()).cmp(reference)
+        })?;
+        self.get(index)
+    }
+
+    /// Finds a card by numeric ID through the ID index.
+    pub fn card_by_id(&self, id: u32) -> Option<OptimizedCardRef<'a>> {
+        let position = lookup_by_key(self.id_index.len(), |position| {
+            match self.get(self.id_index.get(position) as usize) {
+                Some(card) => card.id().cmp(&id),
+                None => Ordering::Greater,
+            }
+        })?;
+        self.get(self.id_index.get(position) as usize)
+    }
+}
+
+/// One card of an `OptimizedCardDb`.
+#[derive(Clone, Copy)]
+pub struct OptimizedCardRef<'a> {
+    db: OptimizedCardDb<'a>,
+    index: usize,
+    card: optimized::Card<'a>,
+}
+
+impl<'a> OptimizedCardRef<'a> {
+    /// Position in the `cards` vector.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The card table, for the fields the reader does not cover.
+    pub fn raw(&self) -> optimized::Card<'a> {
+        self.card
+    }
+
+    /// Numeric ID, see `optimizer_v2::generate_numeric_id`; a card whose
+    /// hash collides with another gets the next free ID.
+    pub fn id(&self) -> u32 {
+        self.card.id()
+    }
+
+    pub fn reference(&self) -> &'a str {
+        self.db.string(self.card.reference_idx())
+    }
+
+    pub fn name(&self) -> &'a str {
+        self.db.string(self.card.name_idx())
+    }
+
+    pub fn image_path(&self) -> &'a str {
+        self.db.string(self.card.image_path_idx())
+    }
+
+    pub fn faction(&self) -> Option<Lookup<'a>> {
+        let index = self.card.faction_id() as usize;
+        let faction = (index < self.db.factions.len()).then(|| self.db.factions.get(index))?;
+        Some(Lookup {
+            reference: self.db.string(faction.reference_idx()),
+            name: self.db.string(faction.name_idx()),
+        })
+    }
+
+    pub fn rarity(&self) -> Option<Lookup<'a>> {
+        let index = self.card.rarity_id() as usize;
+        let rarity = (index < self.db.rarities.len()).then(|| self.db.rarities.get(index))?;
+        Some(Lookup {
+            reference: self.db.string(rarity.reference_idx()),
+            name: self.db.string(rarity.name_idx()),
+        })
+    }
+
+    pub fn card_type(&self) -> Option<Lookup<'a>> {
+        let index = self.card.card_type_id() as usize;
+        let card_type = (index < self.db.card_types.len()).then(|| self.db.card_types.get(index))?;
+        Some(Lookup {
+            reference: self.db.string(card_type.reference_idx()),
+            name: self.db.string(card_type.name_idx()),
+        })
+    }
+
+    pub fn main_cost(&self) -> u8 {
+        self.card.main_cost()
+    }
+
+    pub fn recall_cost(&self) -> u8 {
+        self.card.recall_cost()
+    }
+
+    pub fn power(&self) -> Power {
+        let (mountain, ocean, forest) = unpack_power_values(self.card.power_packed());
+        Power {
+            mountain,
+            ocean,
+            forest,
+        }
+    }
+
+    pub fn is_suspended(&self) -> bool {
+        self.card.flags() & FLAG_SUSPENDED != 0
+    }
+}
+
+/// Reader over a buffer of either layout.
+#[derive(Clone, Copy)]
+pub enum AnyCardDb<'a> {
+    Full(CardDb<'a>),
+    Optimized(OptimizedCardDb<'a>),
+}
+
+impl<'a> AnyCardDb<'a> {
+    /// Reads `bytes` as a `CardDatabase`, or as an `OptimizedCardDatabase`
+    /// when they do not verify as one. Fails with the `CardDatabase` error
+    /// when neither layout verifies.
+    pub fn new(bytes: &'a [u8]) -> Result<AnyCardDb<'a>, Box<dyn std::error::Error>> {
+        match CardDb::new(bytes) {
+            Ok(db) => Ok(AnyCardDb::Full(db)),
+            Err(error) => OptimizedCardDb::new(bytes)
+                .map(AnyCardDb::Optimized)
+                .map_err(|_| error),
+        }
+    }
+}
@@ -18,6 +18,114 @@ pub mod altered_cards {
   extern crate flatbuffers;
   use self::flatbuffers::{EndianScalar, Follow};
 
+pub enum LocalizedStringOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct LocalizedString<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for LocalizedString<'a> {
+  type Inner = LocalizedString<'a>;
+  #[inline]
+  fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table { buf, loc } }
+  }
+}
+
+impl<'a> LocalizedString<'a> {
+  pub const VT_LOCALE: flatbuffers::VOffsetT = 4;
+  pub const VT_VALUE: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    LocalizedString { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args LocalizedStringArgs<'args>
+  ) -> flatbuffers::WIPOffset<LocalizedString<'bldr>> {
+    let mut builder = LocalizedStringBuilder::new(_fbb);
+    if let Some(x) = args.value { builder.add_value(x); }
+    if let Some(x) = args.locale { builder.add_locale(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn locale(&self) -> Option<&'a str> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(LocalizedString::VT_LOCALE, None)
+  }
+  #[inline]
+  pub fn value(&self) -> Option<&'a str> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(LocalizedString::VT_VALUE, None)
+  }
+}
+
+impl flatbuffers::Verifiable for LocalizedString<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("locale", Self::VT_LOCALE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("value", Self::VT_VALUE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct LocalizedStringArgs<'a> {
+    pub locale: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub value: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for LocalizedStringArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    LocalizedStringArgs {
+      locale: None,
+      value: None,
+    }
+  }
+}
+
+pub struct LocalizedStringBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> LocalizedStringBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_locale(&mut self, locale: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(LocalizedString::VT_LOCALE, locale);
+  }
+  #[inline]
+  pub fn add_value(&mut self, value: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(LocalizedString::VT_VALUE, value);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> LocalizedStringBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    LocalizedStringBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<LocalizedString<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for LocalizedString<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("LocalizedString");
+      ds.field("locale", &self.locale());
+      ds.field("value", &self.value());
+      ds.finish()
+  }
+}
 pub enum FactionOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
@@ -37,6 +145,7 @@ impl<'a> Faction<'a> {
   pub const VT_REFERENCE: flatbuffers::VOffsetT = 4;
   pub const VT_NAME: flatbuffers::VOffsetT = 6;
   pub const VT_COLOR: flatbuffers::VOffsetT = 8;
+  pub const VT_NAMES: flatbuffers::VOffsetT = 10;
 
   #[inline]
   pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -48,6 +157,7 @@ impl<'a> Faction<'a> {
     args: &'args FactionArgs<'args>
   ) -> flatbuffers::WIPOffset<Faction<'bldr>> {
     let mut builder = FactionBuilder::new(_fbb);
+    if let Some(x) = args.names { builder.add_names(x); }
     if let Some(x) = args.color { builder.add_color(x); }
     if let Some(x) = args.name { builder.add_name(x); }
     if let Some(x) = args.reference { builder.add_reference(x); }
@@ -67,6 +177,10 @@ impl<'a> Faction<'a> {
   pub fn color(&self) -> Option<&'a str> {
     self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Faction::VT_COLOR, None)
   }
+  #[inline]
+  pub fn names(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<LocalizedString<'a>>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<LocalizedString>>>>(Faction::VT_NAMES, None)
+  }
 }
 
 impl flatbuffers::Verifiable for Faction<'_> {
@@ -79,6 +193,7 @@ impl flatbuffers::Verifiable for Faction<'_> {
      .visit_field::<flatbuffers::ForwardsUOffset<&str>>("reference", Self::VT_REFERENCE, false)?
      .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
      .visit_field::<flatbuffers::ForwardsUOffset<&str>>("color", Self::VT_COLOR, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<LocalizedString>>>>("names", Self::VT_NAMES, false)?
      .finish();
     Ok(())
   }
@@ -87,6 +202,7 @@ pub struct FactionArgs<'a> {
     pub reference: Option<flatbuffers::WIPOffset<&'a str>>,
     pub name: Option<flatbuffers::WIPOffset<&'a str>>,
     pub color: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub names: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<LocalizedString<'a>>>>>,
 }
 impl<'a> Default for FactionArgs<'a> {
   #[inline]
@@ -95,6 +211,7 @@ impl<'a> Default for FactionArgs<'a> {
       reference: None,
       name: None,
       color: None,
+      names: None,
     }
   }
 }
@@ -117,6 +234,10 @@ impl<'a: 'b, 'b> FactionBuilder<'a, 'b> {
     self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Faction::VT_COLOR, color);
   }
   #[inline]
+  pub fn add_names(&mut self, names: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<LocalizedString<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Faction::VT_NAMES, names);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> FactionBuilder<'a, 'b> {
     let start = _fbb.start_table();
     FactionBuilder {
@@ -137,6 +258,7 @@ impl core::fmt::Debug for Faction<'_> {
       ds.field("reference", &self.reference());
       ds.field("name", &self.name());
       ds.field("color", &self.color());
+      ds.field("names", &self.names());
       ds.finish()
   }
 }
@@ -158,6 +280,7 @@ impl<'a> flatbuffers::Follow<'a> for Rarity<'a> {
 impl<'a> Rarity<'a> {
   pub const VT_REFERENCE: flatbuffers::VOffsetT = 4;
   pub const VT_NAME: flatbuffers::VOffsetT = 6;
+  pub const VT_NAMES: flatbuffers::VOffsetT = 8;
 
   #[inline]
   pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -169,6 +292,7 @@ impl<'a> Rarity<'a> {
     args: &'args RarityArgs<'args>
   ) -> flatbuffers::WIPOffset<Rarity<'bldr>> {
     let mut builder = RarityBuilder::new(_fbb);
+    if let Some(x) = args.names { builder.add_names(x); }
     if let Some(x) = args.name { builder.add_name(x); }
     if let Some(x) = args.reference { builder.add_reference(x); }
     builder.finish()
@@ -183,6 +307,10 @@ impl<'a> Rarity<'a> {
   pub fn name(&self) -> Option<&'a str> {
     self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Rarity::VT_NAME, None)
   }
+  #[inline]
+  pub fn names(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<LocalizedString<'a>>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<LocalizedString>>>>(Rarity::VT_NAMES, None)
+  }
 }
 
 impl flatbuffers::Verifiable for Rarity<'_> {
@@ -194,6 +322,7 @@ impl flatbuffers::Verifiable for Rarity<'_> {
     v.visit_table(pos)?
      .visit_field::<flatbuffers::ForwardsUOffset<&str>>("reference", Self::VT_REFERENCE, false)?
      .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<LocalizedString>>>>("names", Self::VT_NAMES, false)?
      .finish();
     Ok(())
   }
@@ -201,6 +330,7 @@ impl flatbuffers::Verifiable for Rarity<'_> {
 pub struct RarityArgs<'a> {
     pub reference: Option<flatbuffers::WIPOffset<&'a str>>,
     pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub names: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<LocalizedString<'a>>>>>,
 }
 impl<'a> Default for RarityArgs<'a> {
   #[inline]
@@ -208,6 +338,7 @@ impl<'a> Default for RarityArgs<'a> {
     RarityArgs {
       reference: None,
       name: None,
+      names: None,
     }
   }
 }
@@ -226,6 +357,10 @@ impl<'a: 'b, 'b> RarityBuilder<'a, 'b> {
     self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Rarity::VT_NAME, name);
   }
   #[inline]
+  pub fn add_names(&mut self, names: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<LocalizedString<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Rarity::VT_NAMES, names);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> RarityBuilder<'a, 'b> {
     let start = _fbb.start_table();
     RarityBuilder {
@@ -245,6 +380,7 @@ impl core::fmt::Debug for Rarity<'_> {
     let mut ds = f.debug_struct("Rarity");
       ds.field("reference", &self.reference());
       ds.field("name", &self.name());
+      ds.field("names", &self.names());
       ds.finish()
   }
 }
@@ -266,6 +402,7 @@ impl<'a> flatbuffers::Follow<'a> for CardType<'a> {
 impl<'a> CardType<'a> {
   pub const VT_REFERENCE: flatbuffers::VOffsetT = 4;
   pub const VT_NAME: flatbuffers::VOffsetT = 6;
+  pub const VT_NAMES: flatbuffers::VOffsetT = 8;
 
   #[inline]
   pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -277,6 +414,7 @@ impl<'a> CardType<'a> {
     args: &'args CardTypeArgs<'args>
   ) -> flatbuffers::WIPOffset<CardType<'bldr>> {
     let mut builder = CardTypeBuilder::new(_fbb);
+    if let Some(x) = args.names { builder.add_names(x); }
     if let Some(x) = args.name { builder.add_name(x); }
     if let Some(x) = args.reference { builder.add_reference(x); }
     builder.finish()
@@ -291,6 +429,10 @@ impl<'a> CardType<'a> {
   pub fn name(&self) -> Option<&'a str> {
     self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(CardType::VT_NAME, None)
   }
+  #[inline]
+  pub fn names(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<LocalizedString<'a>>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<LocalizedString>>>>(CardType::VT_NAMES, None)
+  }
 }
 
 impl flatbuffers::Verifiable for CardType<'_> {
@@ -302,6 +444,7 @@ impl flatbuffers::Verifiable for CardType<'_> {
     v.visit_table(pos)?
      .visit_field::<flatbuffers::ForwardsUOffset<&str>>("reference", Self::VT_REFERENCE, false)?
      .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<LocalizedString>>>>("names", Self::VT_NAMES, false)?
      .finish();
     Ok(())
   }
@@ -309,6 +452,7 @@ impl flatbuffers::Verifiable for CardType<'_> {
 pub struct CardTypeArgs<'a> {
     pub reference: Option<flatbuffers::WIPOffset<&'a str>>,
     pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub names: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<LocalizedString<'a>>>>>,
 }
 impl<'a> Default for CardTypeArgs<'a> {
   #[inline]
@@ -316,6 +460,7 @@ impl<'a> Default for CardTypeArgs<'a> {
     CardTypeArgs {
       reference: None,
       name: None,
+      names: None,
     }
   }
 }
@@ -334,6 +479,10 @@ impl<'a: 'b, 'b> CardTypeBuilder<'a, 'b> {
     self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CardType::VT_NAME, name);
   }
   #[inline]
+  pub fn add_names(&mut self, names: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<LocalizedString<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CardType::VT_NAMES, names);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> CardTypeBuilder<'a, 'b> {
     let start = _fbb.start_table();
     CardTypeBuilder {
@@ -353,231 +502,184 @@ impl core::fmt::Debug for CardType<'_> {
     let mut ds = f.debug_struct("CardType");
       ds.field("reference", &self.reference());
       ds.field("name", &self.name());
+      ds.field("names", &self.names());
       ds.finish()
   }
 }
-pub enum PowerStatsOffset {}
+pub enum CardSetOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct PowerStats<'a> {
+pub struct CardSet<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for PowerStats<'a> {
-  type Inner = PowerStats<'a>;
+impl<'a> flatbuffers::Follow<'a> for CardSet<'a> {
+  type Inner = CardSet<'a>;
   #[inline]
   fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table { buf, loc } }
   }
 }
 
-impl<'a> PowerStats<'a> {
-  pub const VT_MOUNTAIN: flatbuffers::VOffsetT = 4;
-  pub const VT_OCEAN: flatbuffers::VOffsetT = 6;
-  pub const VT_FOREST: flatbuffers::VOffsetT = 8;
+impl<'a> CardSet<'a> {
+  pub const VT_REFERENCE: flatbuffers::VOffsetT = 4;
+  pub const VT_NAME: flatbuffers::VOffsetT = 6;
+  pub const VT_NAMES: flatbuffers::VOffsetT = 8;
 
   #[inline]
   pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    PowerStats { _tab: table }
+    CardSet { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-    args: &'args PowerStatsArgs
-  ) -> flatbuffers::WIPOffset<PowerStats<'bldr>> {
-    let mut builder = PowerStatsBuilder::new(_fbb);
-    builder.add_forest(args.forest);
-    builder.add_ocean(args.ocean);
-    builder.add_mountain(args.mountain);
+    args: &'args CardSetArgs<'args>
+  ) -> flatbuffers::WIPOffset<CardSet<'bldr>> {
+    let mut builder = CardSetBuilder::new(_fbb);
+    if let Some(x) = args.names { builder.add_names(x); }
+    if let Some(x) = args.name { builder.add_name(x); }
+    if let Some(x) = args.reference { builder.add_reference(x); }
     builder.finish()
   }
 
 
   #[inline]
-  pub fn mountain(&self) -> u8 {
-    self._tab.get::<u8>(PowerStats::VT_MOUNTAIN, Some(0)).unwrap()
+  pub fn reference(&self) -> Option<&'a str> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(CardSet::VT_REFERENCE, None)
   }
   #[inline]
-  pub fn ocean(&self) -> u8 {
-    self._tab.get::<u8>(PowerStats::VT_OCEAN, Some(0)).unwrap()
+  pub fn name(&self) -> Option<&'a str> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(CardSet::VT_NAME, None)
   }
   #[inline]
-  pub fn forest(&self) -> u8 {
-    self._tab.get::<u8>(PowerStats::VT_FOREST, Some(0)).unwrap()
+  pub fn names(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<LocalizedString<'a>>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<LocalizedString>>>>(CardSet::VT_NAMES, None)
   }
 }
 
-impl flatbuffers::Verifiable for PowerStats<'_> {
+impl flatbuffers::Verifiable for CardSet<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<u8>("mountain", Self::VT_MOUNTAIN, false)?
-     .visit_field::<u8>("ocean", Self::VT_OCEAN, false)?
-     .visit_field::<u8>("forest", Self::VT_FOREST, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("reference", Self::VT_REFERENCE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<LocalizedString>>>>("names", Self::VT_NAMES, false)?
      .finish();
     Ok(())
   }
 }
-pub struct PowerStatsArgs {
-    pub mountain: u8,
-    pub ocean: u8,
-    pub forest: u8,
+pub struct CardSetArgs<'a> {
+    pub reference: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub names: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<LocalizedString<'a>>>>>,
 }
-impl<'a> Default for PowerStatsArgs {
+impl<'a> Default for CardSetArgs<'a> {
   #[inline]
   fn default() -> Self {
-    PowerStatsArgs {
-      mountain: 0,
-      ocean: 0,
-      forest: 0,
+    CardSetArgs {
+      reference: None,
+      name: None,
+      names: None,
     }
   }
 }
 
-pub struct PowerStatsBuilder<'a: 'b, 'b> {
+pub struct CardSetBuilder<'a: 'b, 'b> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> PowerStatsBuilder<'a, 'b> {
+impl<'a: 'b, 'b> CardSetBuilder<'a, 'b> {
   #[inline]
-  pub fn add_mountain(&mut self, mountain: u8) {
-    self.fbb_.push_slot::<u8>(PowerStats::VT_MOUNTAIN, mountain, 0);
+  pub fn add_reference(&mut self, reference: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CardSet::VT_REFERENCE, reference);
   }
   #[inline]
-  pub fn add_ocean(&mut self, ocean: u8) {
-    self.fbb_.push_slot::<u8>(PowerStats::VT_OCEAN, ocean, 0);
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CardSet::VT_NAME, name);
   }
   #[inline]
-  pub fn add_forest(&mut self, forest: u8) {
-    self.fbb_.push_slot::<u8>(PowerStats::VT_FOREST, forest, 0);
+  pub fn add_names(&mut self, names: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<LocalizedString<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CardSet::VT_NAMES, names);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PowerStatsBuilder<'a, 'b> {
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> CardSetBuilder<'a, 'b> {
     let start = _fbb.start_table();
-    PowerStatsBuilder {
+    CardSetBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<PowerStats<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<CardSet<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for PowerStats<'_> {
+impl core::fmt::Debug for CardSet<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("PowerStats");
-      ds.field("mountain", &self.mountain());
-      ds.field("ocean", &self.ocean());
-      ds.field("forest", &self.forest());
+    let mut ds = f.debug_struct("CardSet");
+      ds.field("reference", &self.reference());
+      ds.field("name", &self.name());
+      ds.field("names", &self.names());
       ds.finish()
   }
 }
-pub enum CardOffset {}
+pub enum CardSubTypeOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct Card<'a> {
+pub struct CardSubType<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for Card<'a> {
-  type Inner = Card<'a>;
+impl<'a> flatbuffers::Follow<'a> for CardSubType<'a> {
+  type Inner = CardSubType<'a>;
   #[inline]
   fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table { buf, loc } }
   }
 }
 
-impl<'a> Card<'a> {
+impl<'a> CardSubType<'a> {
   pub const VT_REFERENCE: flatbuffers::VOffsetT = 4;
   pub const VT_NAME: flatbuffers::VOffsetT = 6;
-  pub const VT_FACTION_IDX: flatbuffers::VOffsetT = 8;
-  pub const VT_RARITY_IDX: flatbuffers::VOffsetT = 10;
-  pub const VT_CARD_TYPE_IDX: flatbuffers::VOffsetT = 12;
-  pub const VT_MAIN_COST: flatbuffers::VOffsetT = 14;
-  pub const VT_RECALL_COST: flatbuffers::VOffsetT = 16;
-  pub const VT_POWER: flatbuffers::VOffsetT = 18;
-  pub const VT_IMAGE_PATH: flatbuffers::VOffsetT = 20;
-  pub const VT_QR_URL: flatbuffers::VOffsetT = 22;
-  pub const VT_IS_SUSPENDED: flatbuffers::VOffsetT = 24;
+  pub const VT_NAMES: flatbuffers::VOffsetT = 8;
 
   #[inline]
   pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    Card { _tab: table }
+    CardSubType { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-    args: &'args CardArgs<'args>
-  ) -> flatbuffers::WIPOffset<Card<'bldr>> {
-    let mut builder = CardBuilder::new(_fbb);
-    if let Some(x) = args.qr_url { builder.add_qr_url(x); }
-    if let Some(x) = args.image_path { builder.add_image_path(x); }
-    if let Some(x) = args.power { builder.add_power(x); }
+    args: &'args CardSubTypeArgs<'args>
+  ) -> flatbuffers::WIPOffset<CardSubType<'bldr>> {
+    let mut builder = CardSubTypeBuilder::new(_fbb);
+    if let Some(x) = args.names { builder.add_names(x); }
     if let Some(x) = args.name { builder.add_name(x); }
     if let Some(x) = args.reference { builder.add_reference(x); }
-    builder.add_is_suspended(args.is_suspended);
-    builder.add_recall_cost(args.recall_cost);
-    builder.add_main_cost(args.main_cost);
-    builder.add_card_type_idx(args.card_type_idx);
-    builder.add_rarity_idx(args.rarity_idx);
-    builder.add_faction_idx(args.faction_idx);
     builder.finish()
   }
 
 
   #[inline]
   pub fn reference(&self) -> Option<&'a str> {
-    self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Card::VT_REFERENCE, None)
+    self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(CardSubType::VT_REFERENCE, None)
   }
   #[inline]
   pub fn name(&self) -> Option<&'a str> {
-    self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Card::VT_NAME, None)
-  }
-  #[inline]
-  pub fn faction_idx(&self) -> u8 {
-    self._tab.get::<u8>(Card::VT_FACTION_IDX, Some(0)).unwrap()
-  }
-  #[inline]
-  pub fn rarity_idx(&self) -> u8 {
-    self._tab.get::<u8>(Card::VT_RARITY_IDX, Some(0)).unwrap()
-  }
-  #[inline]
-  pub fn card_type_idx(&self) -> u8 {
-    self._tab.get::<u8>(Card::VT_CARD_TYPE_IDX, Some(0)).unwrap()
-  }
-  #[inline]
-  pub fn main_cost(&self) -> u8 {
-    self._tab.get::<u8>(Card::VT_MAIN_COST, Some(0)).unwrap()
-  }
-  #[inline]
-  pub fn recall_cost(&self) -> u8 {
-    self._tab.get::<u8>(Card::VT_RECALL_COST, Some(0)).unwrap()
-  }
-  #[inline]
-  pub fn power(&self) -> Option<PowerStats<'a>> {
-    self._tab.get::<flatbuffers::ForwardsUOffset<PowerStats>>(Card::VT_POWER, None)
-  }
-  #[inline]
-  pub fn image_path(&self) -> Option<&'a str> {
-    self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Card::VT_IMAGE_PATH, None)
-  }
-  #[inline]
-  pub fn qr_url(&self) -> Option<&'a str> {
-    self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Card::VT_QR_URL, None)
+    self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(CardSubType::VT_NAME, None)
   }
   #[inline]
-  pub fn is_suspended(&self) -> bool {
-    self._tab.get::<bool>(Card::VT_IS_SUSPENDED, Some(false)).unwrap()
+  pub fn names(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<LocalizedString<'a>>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<LocalizedString>>>>(CardSubType::VT_NAMES, None)
   }
 }
 
-impl flatbuffers::Verifiable for Card<'_> {
+impl flatbuffers::Verifiable for CardSubType<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
@@ -586,129 +688,1625 @@ impl flatbuffers::Verifiable for Card<'_> {
     v.visit_table(pos)?
      .visit_field::<flatbuffers::ForwardsUOffset<&str>>("reference", Self::VT_REFERENCE, false)?
      .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
-     .visit_field::<u8>("faction_idx", Self::VT_FACTION_IDX, false)?
-     .visit_field::<u8>("rarity_idx", Self::VT_RARITY_IDX, false)?
-     .visit_field::<u8>("card_type_idx", Self::VT_CARD_TYPE_IDX, false)?
-     .visit_field::<u8>("main_cost", Self::VT_MAIN_COST, false)?
-     .visit_field::<u8>("recall_cost", Self::VT_RECALL_COST, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<PowerStats>>("power", Self::VT_POWER, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("image_path", Self::VT_IMAGE_PATH, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("qr_url", Self::VT_QR_URL, false)?
-     .visit_field::<bool>("is_suspended", Self::VT_IS_SUSPENDED, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<LocalizedString>>>>("names", Self::VT_NAMES, false)?
      .finish();
     Ok(())
   }
 }
-pub struct CardArgs<'a> {
+pub struct CardSubTypeArgs<'a> {
     pub reference: Option<flatbuffers::WIPOffset<&'a str>>,
     pub name: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub faction_idx: u8,
-    pub rarity_idx: u8,
-    pub card_type_idx: u8,
-    pub main_cost: u8,
-    pub recall_cost: u8,
-    pub power: Option<flatbuffers::WIPOffset<PowerStats<'a>>>,
-    pub image_path: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub qr_url: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub is_suspended: bool,
+    pub names: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<LocalizedString<'a>>>>>,
 }
-impl<'a> Default for CardArgs<'a> {
+impl<'a> Default for CardSubTypeArgs<'a> {
   #[inline]
   fn default() -> Self {
-    CardArgs {
+    CardSubTypeArgs {
       reference: None,
       name: None,
-      faction_idx: 0,
-      rarity_idx: 0,
-      card_type_idx: 0,
-      main_cost: 0,
-      recall_cost: 0,
-      power: None,
-      image_path: None,
-      qr_url: None,
-      is_suspended: false,
+      names: None,
     }
   }
 }
 
-pub struct CardBuilder<'a: 'b, 'b> {
+pub struct CardSubTypeBuilder<'a: 'b, 'b> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> CardBuilder<'a, 'b> {
+impl<'a: 'b, 'b> CardSubTypeBuilder<'a, 'b> {
   #[inline]
   pub fn add_reference(&mut self, reference: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Card::VT_REFERENCE, reference);
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CardSubType::VT_REFERENCE, reference);
   }
   #[inline]
   pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Card::VT_NAME, name);
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CardSubType::VT_NAME, name);
   }
   #[inline]
-  pub fn add_faction_idx(&mut self, faction_idx: u8) {
-    self.fbb_.push_slot::<u8>(Card::VT_FACTION_IDX, faction_idx, 0);
+  pub fn add_names(&mut self, names: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<LocalizedString<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CardSubType::VT_NAMES, names);
   }
   #[inline]
-  pub fn add_rarity_idx(&mut self, rarity_idx: u8) {
-    self.fbb_.push_slot::<u8>(Card::VT_RARITY_IDX, rarity_idx, 0);
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> CardSubTypeBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    CardSubTypeBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
   }
   #[inline]
-  pub fn add_card_type_idx(&mut self, card_type_idx: u8) {
-    self.fbb_.push_slot::<u8>(Card::VT_CARD_TYPE_IDX, card_type_idx, 0);
+  pub fn finish(self) -> flatbuffers::WIPOffset<CardSubType<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
   }
-  #[inline]
+}
+
+impl core::fmt::Debug for CardSubType<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("CardSubType");
+      ds.field("reference", &self.reference());
+      ds.field("name", &self.name());
+      ds.field("names", &self.names());
+      ds.finish()
+  }
+}
+pub enum CardAssetOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct CardAsset<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for CardAsset<'a> {
+  type Inner = CardAsset<'a>;
+  #[inline]
+  fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table { buf, loc } }
+  }
+}
+
+impl<'a> CardAsset<'a> {
+  pub const VT_KIND: flatbuffers::VOffsetT = 4;
+  pub const VT_URLS: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    CardAsset { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args CardAssetArgs<'args>
+  ) -> flatbuffers::WIPOffset<CardAsset<'bldr>> {
+    let mut builder = CardAssetBuilder::new(_fbb);
+    if let Some(x) = args.urls { builder.add_urls(x); }
+    if let Some(x) = args.kind { builder.add_kind(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn kind(&self) -> Option<&'a str> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(CardAsset::VT_KIND, None)
+  }
+  #[inline]
+  pub fn urls(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(CardAsset::VT_URLS, None)
+  }
+}
+
+impl flatbuffers::Verifiable for CardAsset<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("kind", Self::VT_KIND, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("urls", Self::VT_URLS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct CardAssetArgs<'a> {
+    pub kind: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub urls: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+}
+impl<'a> Default for CardAssetArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    CardAssetArgs {
+      kind: None,
+      urls: None,
+    }
+  }
+}
+
+pub struct CardAssetBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> CardAssetBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_kind(&mut self, kind: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CardAsset::VT_KIND, kind);
+  }
+  #[inline]
+  pub fn add_urls(&mut self, urls: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CardAsset::VT_URLS, urls);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> CardAssetBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    CardAssetBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<CardAsset<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for CardAsset<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("CardAsset");
+      ds.field("kind", &self.kind());
+      ds.field("urls", &self.urls());
+      ds.finish()
+  }
+}
+pub enum CardFamilyMemberOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct CardFamilyMember<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for CardFamilyMember<'a> {
+  type Inner = CardFamilyMember<'a>;
+  #[inline]
+  fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table { buf, loc } }
+  }
+}
+
+impl<'a> CardFamilyMember<'a> {
+  pub const VT_CARD_IDX: flatbuffers::VOffsetT = 4;
+  pub const VT_SET: flatbuffers::VOffsetT = 6;
+  pub const VT_PRODUCT: flatbuffers::VOffsetT = 8;
+  pub const VT_VARIANT: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    CardFamilyMember { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args CardFamilyMemberArgs<'args>
+  ) -> flatbuffers::WIPOffset<CardFamilyMember<'bldr>> {
+    let mut builder = CardFamilyMemberBuilder::new(_fbb);
+    if let Some(x) = args.variant { builder.add_variant(x); }
+    if let Some(x) = args.product { builder.add_product(x); }
+    if let Some(x) = args.set { builder.add_set(x); }
+    builder.add_card_idx(args.card_idx);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn card_idx(&self) -> u32 {
+    self._tab.get::<u32>(CardFamilyMember::VT_CARD_IDX, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn set(&self) -> Option<&'a str> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(CardFamilyMember::VT_SET, None)
+  }
+  #[inline]
+  pub fn product(&self) -> Option<&'a str> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(CardFamilyMember::VT_PRODUCT, None)
+  }
+  #[inline]
+  pub fn variant(&self) -> Option<&'a str> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(CardFamilyMember::VT_VARIANT, None)
+  }
+}
+
+impl flatbuffers::Verifiable for CardFamilyMember<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("card_idx", Self::VT_CARD_IDX, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("set", Self::VT_SET, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("product", Self::VT_PRODUCT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("variant", Self::VT_VARIANT, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct CardFamilyMemberArgs<'a> {
+    pub card_idx: u32,
+    pub set: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub product: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub variant: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for CardFamilyMemberArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    CardFamilyMemberArgs {
+      card_idx: 0,
+      set: None,
+      product: None,
+      variant: None,
+    }
+  }
+}
+
+pub struct CardFamilyMemberBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> CardFamilyMemberBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_card_idx(&mut self, card_idx: u32) {
+    self.fbb_.push_slot::<u32>(CardFamilyMember::VT_CARD_IDX, card_idx, 0);
+  }
+  #[inline]
+  pub fn add_set(&mut self, set: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CardFamilyMember::VT_SET, set);
+  }
+  #[inline]
+  pub fn add_product(&mut self, product: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CardFamilyMember::VT_PRODUCT, product);
+  }
+  #[inline]
+  pub fn add_variant(&mut self, variant: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CardFamilyMember::VT_VARIANT, variant);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> CardFamilyMemberBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    CardFamilyMemberBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<CardFamilyMember<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for CardFamilyMember<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("CardFamilyMember");
+      ds.field("card_idx", &self.card_idx());
+      ds.field("set", &self.set());
+      ds.field("product", &self.product());
+      ds.field("variant", &self.variant());
+      ds.finish()
+  }
+}
+pub enum CardFamilyOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct CardFamily<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for CardFamily<'a> {
+  type Inner = CardFamily<'a>;
+  #[inline]
+  fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table { buf, loc } }
+  }
+}
+
+impl<'a> CardFamily<'a> {
+  pub const VT_REFERENCE: flatbuffers::VOffsetT = 4;
+  pub const VT_SET: flatbuffers::VOffsetT = 6;
+  pub const VT_FACTION: flatbuffers::VOffsetT = 8;
+  pub const VT_NUMBER: flatbuffers::VOffsetT = 10;
+  pub const VT_MEMBERS: flatbuffers::VOffsetT = 12;
+
+  #[inline]
+  pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    CardFamily { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args CardFamilyArgs<'args>
+  ) -> flatbuffers::WIPOffset<CardFamily<'bldr>> {
+    let mut builder = CardFamilyBuilder::new(_fbb);
+    if let Some(x) = args.members { builder.add_members(x); }
+    if let Some(x) = args.number { builder.add_number(x); }
+    if let Some(x) = args.faction { builder.add_faction(x); }
+    if let Some(x) = args.set { builder.add_set(x); }
+    if let Some(x) = args.reference { builder.add_reference(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn reference(&self) -> Option<&'a str> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(CardFamily::VT_REFERENCE, None)
+  }
+  #[inline]
+  pub fn set(&self) -> Option<&'a str> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(CardFamily::VT_SET, None)
+  }
+  #[inline]
+  pub fn faction(&self) -> Option<&'a str> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(CardFamily::VT_FACTION, None)
+  }
+  #[inline]
+  pub fn number(&self) -> Option<&'a str> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(CardFamily::VT_NUMBER, None)
+  }
+  #[inline]
+  pub fn members(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CardFamilyMember<'a>>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CardFamilyMember>>>>(CardFamily::VT_MEMBERS, None)
+  }
+}
+
+impl flatbuffers::Verifiable for CardFamily<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("reference", Self::VT_REFERENCE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("set", Self::VT_SET, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("faction", Self::VT_FACTION, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("number", Self::VT_NUMBER, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<CardFamilyMember>>>>("members", Self::VT_MEMBERS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct CardFamilyArgs<'a> {
+    pub reference: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub set: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub faction: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub number: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub members: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CardFamilyMember<'a>>>>>,
+}
+impl<'a> Default for CardFamilyArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    CardFamilyArgs {
+      reference: None,
+      set: None,
+      faction: None,
+      number: None,
+      members: None,
+    }
+  }
+}
+
+pub struct CardFamilyBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> CardFamilyBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_reference(&mut self, reference: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CardFamily::VT_REFERENCE, reference);
+  }
+  #[inline]
+  pub fn add_set(&mut self, set: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CardFamily::VT_SET, set);
+  }
+  #[inline]
+  pub fn add_faction(&mut self, faction: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CardFamily::VT_FACTION, faction);
+  }
+  #[inline]
+  pub fn add_number(&mut self, number: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CardFamily::VT_NUMBER, number);
+  }
+  #[inline]
+  pub fn add_members(&mut self, members: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<CardFamilyMember<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CardFamily::VT_MEMBERS, members);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> CardFamilyBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    CardFamilyBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<CardFamily<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for CardFamily<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("CardFamily");
+      ds.field("reference", &self.reference());
+      ds.field("set", &self.set());
+      ds.field("faction", &self.faction());
+      ds.field("number", &self.number());
+      ds.field("members", &self.members());
+      ds.finish()
+  }
+}
+pub enum CardTextOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct CardText<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for CardText<'a> {
+  type Inner = CardText<'a>;
+  #[inline]
+  fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table { buf, loc } }
+  }
+}
+
+impl<'a> CardText<'a> {
+  pub const VT_LOCALE: flatbuffers::VOffsetT = 4;
+  pub const VT_MAIN_EFFECT: flatbuffers::VOffsetT = 6;
+  pub const VT_ECHO_EFFECT: flatbuffers::VOffsetT = 8;
+  pub const VT_FLAVOR: flatbuffers::VOffsetT = 10;
+  pub const VT_KEYWORDS: flatbuffers::VOffsetT = 12;
+
+  #[inline]
+  pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    CardText { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args CardTextArgs<'args>
+  ) -> flatbuffers::WIPOffset<CardText<'bldr>> {
+    let mut builder = CardTextBuilder::new(_fbb);
+    if let Some(x) = args.keywords { builder.add_keywords(x); }
+    if let Some(x) = args.flavor { builder.add_flavor(x); }
+    if let Some(x) = args.echo_effect { builder.add_echo_effect(x); }
+    if let Some(x) = args.main_effect { builder.add_main_effect(x); }
+    if let Some(x) = args.locale { builder.add_locale(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn locale(&self) -> Option<&'a str> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(CardText::VT_LOCALE, None)
+  }
+  #[inline]
+  pub fn main_effect(&self) -> Option<&'a str> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(CardText::VT_MAIN_EFFECT, None)
+  }
+  #[inline]
+  pub fn echo_effect(&self) -> Option<&'a str> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(CardText::VT_ECHO_EFFECT, None)
+  }
+  #[inline]
+  pub fn flavor(&self) -> Option<&'a str> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(CardText::VT_FLAVOR, None)
+  }
+  #[inline]
+  pub fn keywords(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(CardText::VT_KEYWORDS, None)
+  }
+}
+
+impl flatbuffers::Verifiable for CardText<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("locale", Self::VT_LOCALE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("main_effect", Self::VT_MAIN_EFFECT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("echo_effect", Self::VT_ECHO_EFFECT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("flavor", Self::VT_FLAVOR, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("keywords", Self::VT_KEYWORDS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct CardTextArgs<'a> {
+    pub locale: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub main_effect: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub echo_effect: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub flavor: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub keywords: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+}
+impl<'a> Default for CardTextArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    CardTextArgs {
+      locale: None,
+      main_effect: None,
+      echo_effect: None,
+      flavor: None,
+      keywords: None,
+    }
+  }
+}
+
+pub struct CardTextBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> CardTextBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_locale(&mut self, locale: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CardText::VT_LOCALE, locale);
+  }
+  #[inline]
+  pub fn add_main_effect(&mut self, main_effect: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CardText::VT_MAIN_EFFECT, main_effect);
+  }
+  #[inline]
+  pub fn add_echo_effect(&mut self, echo_effect: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CardText::VT_ECHO_EFFECT, echo_effect);
+  }
+  #[inline]
+  pub fn add_flavor(&mut self, flavor: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CardText::VT_FLAVOR, flavor);
+  }
+  #[inline]
+  pub fn add_keywords(&mut self, keywords: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CardText::VT_KEYWORDS, keywords);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> CardTextBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    CardTextBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<CardText<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for CardText<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("CardText");
+      ds.field("locale", &self.locale());
+      ds.field("main_effect", &self.main_effect());
+      ds.field("echo_effect", &self.echo_effect());
+      ds.field("flavor", &self.flavor());
+      ds.field("keywords", &self.keywords());
+      ds.finish()
+  }
+}
+pub enum CardFileOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct CardFile<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for CardFile<'a> {
+  type Inner = CardFile<'a>;
+  #[inline]
+  fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table { buf, loc } }
+  }
+}
+
+impl<'a> CardFile<'a> {
+  pub const VT_URL: flatbuffers::VOffsetT = 4;
+  pub const VT_PATH: flatbuffers::VOffsetT = 6;
+  pub const VT_SHA256: flatbuffers::VOffsetT = 8;
+  pub const VT_SIZE: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    CardFile { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args CardFileArgs<'args>
+  ) -> flatbuffers::WIPOffset<CardFile<'bldr>> {
+    let mut builder = CardFileBuilder::new(_fbb);
+    builder.add_size(args.size);
+    if let Some(x) = args.sha256 { builder.add_sha256(x); }
+    if let Some(x) = args.path { builder.add_path(x); }
+    if let Some(x) = args.url { builder.add_url(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn url(&self) -> Option<&'a str> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(CardFile::VT_URL, None)
+  }
+  #[inline]
+  pub fn path(&self) -> Option<&'a str> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(CardFile::VT_PATH, None)
+  }
+  #[inline]
+  pub fn sha256(&self) -> Option<&'a str> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(CardFile::VT_SHA256, None)
+  }
+  #[inline]
+  pub fn size(&self) -> u64 {
+    self._tab.get::<u64>(CardFile::VT_SIZE, Some(0)).unwrap()
+  }
+}
+
+impl flatbuffers::Verifiable for CardFile<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("url", Self::VT_URL, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("path", Self::VT_PATH, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("sha256", Self::VT_SHA256, false)?
+     .visit_field::<u64>("size", Self::VT_SIZE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct CardFileArgs<'a> {
+    pub url: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub path: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub sha256: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub size: u64,
+}
+impl<'a> Default for CardFileArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    CardFileArgs {
+      url: None,
+      path: None,
+      sha256: None,
+      size: 0,
+    }
+  }
+}
+
+pub struct CardFileBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> CardFileBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_url(&mut self, url: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CardFile::VT_URL, url);
+  }
+  #[inline]
+  pub fn add_path(&mut self, path: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CardFile::VT_PATH, path);
+  }
+  #[inline]
+  pub fn add_sha256(&mut self, sha256: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CardFile::VT_SHA256, sha256);
+  }
+  #[inline]
+  pub fn add_size(&mut self, size: u64) {
+    self.fbb_.push_slot::<u64>(CardFile::VT_SIZE, size, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> CardFileBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    CardFileBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<CardFile<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for CardFile<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("CardFile");
+      ds.field("url", &self.url());
+      ds.field("path", &self.path());
+      ds.field("sha256", &self.sha256());
+      ds.field("size", &self.size());
+      ds.finish()
+  }
+}
+pub enum PowerStatsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct PowerStats<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PowerStats<'a> {
+  type Inner = PowerStats<'a>;
+  #[inline]
+  fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table { buf, loc } }
+  }
+}
+
+impl<'a> PowerStats<'a> {
+  pub const VT_MOUNTAIN: flatbuffers::VOffsetT = 4;
+  pub const VT_OCEAN: flatbuffers::VOffsetT = 6;
+  pub const VT_FOREST: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    PowerStats { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args PowerStatsArgs
+  ) -> flatbuffers::WIPOffset<PowerStats<'bldr>> {
+    let mut builder = PowerStatsBuilder::new(_fbb);
+    builder.add_forest(args.forest);
+    builder.add_ocean(args.ocean);
+    builder.add_mountain(args.mountain);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn mountain(&self) -> u8 {
+    self._tab.get::<u8>(PowerStats::VT_MOUNTAIN, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn ocean(&self) -> u8 {
+    self._tab.get::<u8>(PowerStats::VT_OCEAN, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn forest(&self) -> u8 {
+    self._tab.get::<u8>(PowerStats::VT_FOREST, Some(0)).unwrap()
+  }
+}
+
+impl flatbuffers::Verifiable for PowerStats<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u8>("mountain", Self::VT_MOUNTAIN, false)?
+     .visit_field::<u8>("ocean", Self::VT_OCEAN, false)?
+     .visit_field::<u8>("forest", Self::VT_FOREST, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PowerStatsArgs {
+    pub mountain: u8,
+    pub ocean: u8,
+    pub forest: u8,
+}
+impl<'a> Default for PowerStatsArgs {
+  #[inline]
+  fn default() -> Self {
+    PowerStatsArgs {
+      mountain: 0,
+      ocean: 0,
+      forest: 0,
+    }
+  }
+}
+
+pub struct PowerStatsBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> PowerStatsBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_mountain(&mut self, mountain: u8) {
+    self.fbb_.push_slot::<u8>(PowerStats::VT_MOUNTAIN, mountain, 0);
+  }
+  #[inline]
+  pub fn add_ocean(&mut self, ocean: u8) {
+    self.fbb_.push_slot::<u8>(PowerStats::VT_OCEAN, ocean, 0);
+  }
+  #[inline]
+  pub fn add_forest(&mut self, forest: u8) {
+    self.fbb_.push_slot::<u8>(PowerStats::VT_FOREST, forest, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PowerStatsBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    PowerStatsBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<PowerStats<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for PowerStats<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("PowerStats");
+      ds.field("mountain", &self.mountain());
+      ds.field("ocean", &self.ocean());
+      ds.field("forest", &self.forest());
+      ds.finish()
+  }
+}
+pub enum CardOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Card<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Card<'a> {
+  type Inner = Card<'a>;
+  #[inline]
+  fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table { buf, loc } }
+  }
+}
+
+impl<'a> Card<'a> {
+  pub const VT_REFERENCE: flatbuffers::VOffsetT = 4;
+  pub const VT_NAME: flatbuffers::VOffsetT = 6;
+  pub const VT_FACTION_IDX: flatbuffers::VOffsetT = 8;
+  pub const VT_RARITY_IDX: flatbuffers::VOffsetT = 10;
+  pub const VT_CARD_TYPE_IDX: flatbuffers::VOffsetT = 12;
+  pub const VT_MAIN_COST: flatbuffers::VOffsetT = 14;
+  pub const VT_RECALL_COST: flatbuffers::VOffsetT = 16;
+  pub const VT_POWER: flatbuffers::VOffsetT = 18;
+  pub const VT_IMAGE_PATH: flatbuffers::VOffsetT = 20;
+  pub const VT_QR_URL: flatbuffers::VOffsetT = 22;
+  pub const VT_IS_SUSPENDED: flatbuffers::VOffsetT = 24;
+  pub const VT_NAMES: flatbuffers::VOffsetT = 26;
+  pub const VT_IMAGE_PATHS: flatbuffers::VOffsetT = 28;
+  pub const VT_ID: flatbuffers::VOffsetT = 30;
+  pub const VT_CARD_SET_IDX: flatbuffers::VOffsetT = 32;
+  pub const VT_SUB_TYPE_IDXS: flatbuffers::VOffsetT = 34;
+  pub const VT_ASSETS: flatbuffers::VOffsetT = 36;
+  pub const VT_RAW_IS_SUSPENDED: flatbuffers::VOffsetT = 38;
+  pub const VT_FAMILY_IDX: flatbuffers::VOffsetT = 40;
+  pub const VT_TEXTS: flatbuffers::VOffsetT = 42;
+  pub const VT_LOCAL_FILES: flatbuffers::VOffsetT = 44;
+  pub const VT_SUSPENDED_SINCE: flatbuffers::VOffsetT = 46;
+
+  #[inline]
+  pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Card { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args CardArgs<'args>
+  ) -> flatbuffers::WIPOffset<Card<'bldr>> {
+    let mut builder = CardBuilder::new(_fbb);
+    if let Some(x) = args.suspended_since { builder.add_suspended_since(x); }
+    if let Some(x) = args.local_files { builder.add_local_files(x); }
+    if let Some(x) = args.texts { builder.add_texts(x); }
+    builder.add_family_idx(args.family_idx);
+    if let Some(x) = args.assets { builder.add_assets(x); }
+    if let Some(x) = args.sub_type_idxs { builder.add_sub_type_idxs(x); }
+    if let Some(x) = args.id { builder.add_id(x); }
+    if let Some(x) = args.image_paths { builder.add_image_paths(x); }
+    if let Some(x) = args.names { builder.add_names(x); }
+    if let Some(x) = args.qr_url { builder.add_qr_url(x); }
+    if let Some(x) = args.image_path { builder.add_image_path(x); }
+    if let Some(x) = args.power { builder.add_power(x); }
+    if let Some(x) = args.name { builder.add_name(x); }
+    if let Some(x) = args.reference { builder.add_reference(x); }
+    builder.add_raw_is_suspended(args.raw_is_suspended);
+    builder.add_card_set_idx(args.card_set_idx);
+    builder.add_is_suspended(args.is_suspended);
+    builder.add_recall_cost(args.recall_cost);
+    builder.add_main_cost(args.main_cost);
+    builder.add_card_type_idx(args.card_type_idx);
+    builder.add_rarity_idx(args.rarity_idx);
+    builder.add_faction_idx(args.faction_idx);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn reference(&self) -> &'a str {
+    self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Card::VT_REFERENCE, None).unwrap()
+  }
+  #[inline]
+  pub fn key_compare_less_than(&self, o: &Card) -> bool {
+    self.reference() < o.reference()
+  }
+
+  #[inline]
+  pub fn key_compare_with_value(&self, val: & str) -> ::core::cmp::Ordering {
+    let key = self.reference();
+    key.cmp(val)
+  }
+  #[inline]
+  pub fn name(&self) -> Option<&'a str> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Card::VT_NAME, None)
+  }
+  #[inline]
+  pub fn faction_idx(&self) -> u8 {
+    self._tab.get::<u8>(Card::VT_FACTION_IDX, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn rarity_idx(&self) -> u8 {
+    self._tab.get::<u8>(Card::VT_RARITY_IDX, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn card_type_idx(&self) -> u8 {
+    self._tab.get::<u8>(Card::VT_CARD_TYPE_IDX, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn main_cost(&self) -> u8 {
+    self._tab.get::<u8>(Card::VT_MAIN_COST, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn recall_cost(&self) -> u8 {
+    self._tab.get::<u8>(Card::VT_RECALL_COST, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn power(&self) -> Option<PowerStats<'a>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<PowerStats>>(Card::VT_POWER, None)
+  }
+  #[inline]
+  pub fn image_path(&self) -> Option<&'a str> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Card::VT_IMAGE_PATH, None)
+  }
+  #[inline]
+  pub fn qr_url(&self) -> Option<&'a str> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Card::VT_QR_URL, None)
+  }
+  #[inline]
+  pub fn is_suspended(&self) -> bool {
+    self._tab.get::<bool>(Card::VT_IS_SUSPENDED, Some(false)).unwrap()
+  }
+  #[inline]
+  pub fn names(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<LocalizedString<'a>>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<LocalizedString>>>>(Card::VT_NAMES, None)
+  }
+  #[inline]
+  pub fn image_paths(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<LocalizedString<'a>>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<LocalizedString>>>>(Card::VT_IMAGE_PATHS, None)
+  }
+  #[inline]
+  pub fn id(&self) -> Option<&'a str> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Card::VT_ID, None)
+  }
+  #[inline]
+  pub fn card_set_idx(&self) -> u8 {
+    self._tab.get::<u8>(Card::VT_CARD_SET_IDX, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn sub_type_idxs(&self) -> Option<flatbuffers::Vector<'a, u16>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u16>>>(Card::VT_SUB_TYPE_IDXS, None)
+  }
+  #[inline]
+  pub fn assets(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CardAsset<'a>>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CardAsset>>>>(Card::VT_ASSETS, None)
+  }
+  #[inline]
+  pub fn raw_is_suspended(&self) -> bool {
+    self._tab.get::<bool>(Card::VT_RAW_IS_SUSPENDED, Some(false)).unwrap()
+  }
+  #[inline]
+  pub fn family_idx(&self) -> u32 {
+    self._tab.get::<u32>(Card::VT_FAMILY_IDX, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn texts(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CardText<'a>>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CardText>>>>(Card::VT_TEXTS, None)
+  }
+  #[inline]
+  pub fn local_files(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CardFile<'a>>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CardFile>>>>(Card::VT_LOCAL_FILES, None)
+  }
+  #[inline]
+  pub fn suspended_since(&self) -> Option<&'a str> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Card::VT_SUSPENDED_SINCE, None)
+  }
+}
+
+impl flatbuffers::Verifiable for Card<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("reference", Self::VT_REFERENCE, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+     .visit_field::<u8>("faction_idx", Self::VT_FACTION_IDX, false)?
+     .visit_field::<u8>("rarity_idx", Self::VT_RARITY_IDX, false)?
+     .visit_field::<u8>("card_type_idx", Self::VT_CARD_TYPE_IDX, false)?
+     .visit_field::<u8>("main_cost", Self::VT_MAIN_COST, false)?
+     .visit_field::<u8>("recall_cost", Self::VT_RECALL_COST, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<PowerStats>>("power", Self::VT_POWER, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("image_path", Self::VT_IMAGE_PATH, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("qr_url", Self::VT_QR_URL, false)?
+     .visit_field::<bool>("is_suspended", Self::VT_IS_SUSPENDED, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<LocalizedString>>>>("names", Self::VT_NAMES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<LocalizedString>>>>("image_paths", Self::VT_IMAGE_PATHS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("id", Self::VT_ID, false)?
+     .visit_field::<u8>("card_set_idx", Self::VT_CARD_SET_IDX, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u16>>>("sub_type_idxs", Self::VT_SUB_TYPE_IDXS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<CardAsset>>>>("assets", Self::VT_ASSETS, false)?
+     .visit_field::<bool>("raw_is_suspended", Self::VT_RAW_IS_SUSPENDED, false)?
+     .visit_field::<u32>("family_idx", Self::VT_FAMILY_IDX, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<CardText>>>>("texts", Self::VT_TEXTS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<CardFile>>>>("local_files", Self::VT_LOCAL_FILES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("suspended_since", Self::VT_SUSPENDED_SINCE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct CardArgs<'a> {
+    pub reference: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub faction_idx: u8,
+    pub rarity_idx: u8,
+    pub card_type_idx: u8,
+    pub main_cost: u8,
+    pub recall_cost: u8,
+    pub power: Option<flatbuffers::WIPOffset<PowerStats<'a>>>,
+    pub image_path: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub qr_url: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub is_suspended: bool,
+    pub names: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<LocalizedString<'a>>>>>,
+    pub image_paths: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<LocalizedString<'a>>>>>,
+    pub id: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub card_set_idx: u8,
+    pub sub_type_idxs: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u16>>>,
+    pub assets: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CardAsset<'a>>>>>,
+    pub raw_is_suspended: bool,
+    pub family_idx: u32,
+    pub texts: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CardText<'a>>>>>,
+    pub local_files: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CardFile<'a>>>>>,
+    pub suspended_since: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for CardArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    CardArgs {
+      reference: None, // required field
+      name: None,
+      faction_idx: 0,
+      rarity_idx: 0,
+      card_type_idx: 0,
+      main_cost: 0,
+      recall_cost: 0,
+      power: None,
+      image_path: None,
+      qr_url: None,
+      is_suspended: false,
+      names: None,
+      image_paths: None,
+      id: None,
+      card_set_idx: 0,
+      sub_type_idxs: None,
+      assets: None,
+      raw_is_suspended: false,
+      family_idx: 0,
+      texts: None,
+      local_files: None,
+      suspended_since: None,
+    }
+  }
+}
+
+pub struct CardBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> CardBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_reference(&mut self, reference: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Card::VT_REFERENCE, reference);
+  }
+  #[inline]
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Card::VT_NAME, name);
+  }
+  #[inline]
+  pub fn add_faction_idx(&mut self, faction_idx: u8) {
+    self.fbb_.push_slot::<u8>(Card::VT_FACTION_IDX, faction_idx, 0);
+  }
+  #[inline]
+  pub fn add_rarity_idx(&mut self, rarity_idx: u8) {
+    self.fbb_.push_slot::<u8>(Card::VT_RARITY_IDX, rarity_idx, 0);
+  }
+  #[inline]
+  pub fn add_card_type_idx(&mut self, card_type_idx: u8) {
+    self.fbb_.push_slot::<u8>(Card::VT_CARD_TYPE_IDX, card_type_idx, 0);
+  }
+  #[inline]
   pub fn add_main_cost(&mut self, main_cost: u8) {
     self.fbb_.push_slot::<u8>(Card::VT_MAIN_COST, main_cost, 0);
   }
   #[inline]
-  pub fn add_recall_cost(&mut self, recall_cost: u8) {
-    self.fbb_.push_slot::<u8>(Card::VT_RECALL_COST, recall_cost, 0);
+  pub fn add_recall_cost(&mut self, recall_cost: u8) {
+    self.fbb_.push_slot::<u8>(Card::VT_RECALL_COST, recall_cost, 0);
+  }
+  #[inline]
+  pub fn add_power(&mut self, power: flatbuffers::WIPOffset<PowerStats<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<PowerStats>>(Card::VT_POWER, power);
+  }
+  #[inline]
+  pub fn add_image_path(&mut self, image_path: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Card::VT_IMAGE_PATH, image_path);
+  }
+  #[inline]
+  pub fn add_qr_url(&mut self, qr_url: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Card::VT_QR_URL, qr_url);
+  }
+  #[inline]
+  pub fn add_is_suspended(&mut self, is_suspended: bool) {
+    self.fbb_.push_slot::<bool>(Card::VT_IS_SUSPENDED, is_suspended, false);
+  }
+  #[inline]
+  pub fn add_names(&mut self, names: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<LocalizedString<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Card::VT_NAMES, names);
+  }
+  #[inline]
+  pub fn add_image_paths(&mut self, image_paths: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<LocalizedString<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Card::VT_IMAGE_PATHS, image_paths);
+  }
+  #[inline]
+  pub fn add_id(&mut self, id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Card::VT_ID, id);
+  }
+  #[inline]
+  pub fn add_card_set_idx(&mut self, card_set_idx: u8) {
+    self.fbb_.push_slot::<u8>(Card::VT_CARD_SET_IDX, card_set_idx, 0);
+  }
+  #[inline]
+  pub fn add_sub_type_idxs(&mut self, sub_type_idxs: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u16>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Card::VT_SUB_TYPE_IDXS, sub_type_idxs);
+  }
+  #[inline]
+  pub fn add_assets(&mut self, assets: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<CardAsset<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Card::VT_ASSETS, assets);
+  }
+  #[inline]
+  pub fn add_raw_is_suspended(&mut self, raw_is_suspended: bool) {
+    self.fbb_.push_slot::<bool>(Card::VT_RAW_IS_SUSPENDED, raw_is_suspended, false);
+  }
+  #[inline]
+  pub fn add_family_idx(&mut self, family_idx: u32) {
+    self.fbb_.push_slot::<u32>(Card::VT_FAMILY_IDX, family_idx, 0);
+  }
+  #[inline]
+  pub fn add_texts(&mut self, texts: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<CardText<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Card::VT_TEXTS, texts);
+  }
+  #[inline]
+  pub fn add_local_files(&mut self, local_files: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<CardFile<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Card::VT_LOCAL_FILES, local_files);
+  }
+  #[inline]
+  pub fn add_suspended_since(&mut self, suspended_since: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Card::VT_SUSPENDED_SINCE, suspended_since);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> CardBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    CardBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Card<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, Card::VT_REFERENCE,"reference");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Card<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Card");
+      ds.field("reference", &self.reference());
+      ds.field("name", &self.name());
+      ds.field("faction_idx", &self.faction_idx());
+      ds.field("rarity_idx", &self.rarity_idx());
+      ds.field("card_type_idx", &self.card_type_idx());
+      ds.field("main_cost", &self.main_cost());
+      ds.field("recall_cost", &self.recall_cost());
+      ds.field("power", &self.power());
+      ds.field("image_path", &self.image_path());
+      ds.field("qr_url", &self.qr_url());
+      ds.field("is_suspended", &self.is_suspended());
+      ds.field("names", &self.names());
+      ds.field("image_paths", &self.image_paths());
+      ds.field("id", &self.id());
+      ds.field("card_set_idx", &self.card_set_idx());
+      ds.field("sub_type_idxs", &self.sub_type_idxs());
+      ds.field("assets", &self.assets());
+      ds.field("raw_is_suspended", &self.raw_is_suspended());
+      ds.field("family_idx", &self.family_idx());
+      ds.field("texts", &self.texts());
+      ds.field("local_files", &self.local_files());
+      ds.field("suspended_since", &self.suspended_since());
+      ds.finish()
+  }
+}
+pub enum SuspensionEventOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SuspensionEvent<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SuspensionEvent<'a> {
+  type Inner = SuspensionEvent<'a>;
+  #[inline]
+  fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table { buf, loc } }
+  }
+}
+
+impl<'a> SuspensionEvent<'a> {
+  pub const VT_REFERENCE: flatbuffers::VOffsetT = 4;
+  pub const VT_SUSPENDED: flatbuffers::VOffsetT = 6;
+  pub const VT_DATE: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SuspensionEvent { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args SuspensionEventArgs<'args>
+  ) -> flatbuffers::WIPOffset<SuspensionEvent<'bldr>> {
+    let mut builder = SuspensionEventBuilder::new(_fbb);
+    if let Some(x) = args.date { builder.add_date(x); }
+    if let Some(x) = args.reference { builder.add_reference(x); }
+    builder.add_suspended(args.suspended);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn reference(&self) -> Option<&'a str> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SuspensionEvent::VT_REFERENCE, None)
   }
   #[inline]
-  pub fn add_power(&mut self, power: flatbuffers::WIPOffset<PowerStats<'b >>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<PowerStats>>(Card::VT_POWER, power);
+  pub fn suspended(&self) -> bool {
+    self._tab.get::<bool>(SuspensionEvent::VT_SUSPENDED, Some(false)).unwrap()
   }
   #[inline]
-  pub fn add_image_path(&mut self, image_path: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Card::VT_IMAGE_PATH, image_path);
+  pub fn date(&self) -> Option<&'a str> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SuspensionEvent::VT_DATE, None)
   }
+}
+
+impl flatbuffers::Verifiable for SuspensionEvent<'_> {
   #[inline]
-  pub fn add_qr_url(&mut self, qr_url: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Card::VT_QR_URL, qr_url);
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("reference", Self::VT_REFERENCE, false)?
+     .visit_field::<bool>("suspended", Self::VT_SUSPENDED, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("date", Self::VT_DATE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SuspensionEventArgs<'a> {
+    pub reference: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub suspended: bool,
+    pub date: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for SuspensionEventArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    SuspensionEventArgs {
+      reference: None,
+      suspended: false,
+      date: None,
+    }
+  }
+}
+
+pub struct SuspensionEventBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> SuspensionEventBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_reference(&mut self, reference: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SuspensionEvent::VT_REFERENCE, reference);
   }
   #[inline]
-  pub fn add_is_suspended(&mut self, is_suspended: bool) {
-    self.fbb_.push_slot::<bool>(Card::VT_IS_SUSPENDED, is_suspended, false);
+  pub fn add_suspended(&mut self, suspended: bool) {
+    self.fbb_.push_slot::<bool>(SuspensionEvent::VT_SUSPENDED, suspended, false);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> CardBuilder<'a, 'b> {
+  pub fn add_date(&mut self, date: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SuspensionEvent::VT_DATE, date);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SuspensionEventBuilder<'a, 'b> {
     let start = _fbb.start_table();
-    CardBuilder {
+    SuspensionEventBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<Card<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<SuspensionEvent<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for Card<'_> {
+impl core::fmt::Debug for SuspensionEvent<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("Card");
+    let mut ds = f.debug_struct("SuspensionEvent");
       ds.field("reference", &self.reference());
-      ds.field("name", &self.name());
-      ds.field("faction_idx", &self.faction_idx());
-      ds.field("rarity_idx", &self.rarity_idx());
-      ds.field("card_type_idx", &self.card_type_idx());
-      ds.field("main_cost", &self.main_cost());
-      ds.field("recall_cost", &self.recall_cost());
-      ds.field("power", &self.power());
-      ds.field("image_path", &self.image_path());
-      ds.field("qr_url", &self.qr_url());
-      ds.field("is_suspended", &self.is_suspended());
+      ds.field("suspended", &self.suspended());
+      ds.field("date", &self.date());
+      ds.finish()
+  }
+}
+pub enum PostingListOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct PostingList<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PostingList<'a> {
+  type Inner = PostingList<'a>;
+  #[inline]
+  fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table { buf, loc } }
+  }
+}
+
+impl<'a> PostingList<'a> {
+  pub const VT_VALUE: flatbuffers::VOffsetT = 4;
+  pub const VT_CARDS: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    PostingList { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args PostingListArgs<'args>
+  ) -> flatbuffers::WIPOffset<PostingList<'bldr>> {
+    let mut builder = PostingListBuilder::new(_fbb);
+    if let Some(x) = args.cards { builder.add_cards(x); }
+    builder.add_value(args.value);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn value(&self) -> u32 {
+    self._tab.get::<u32>(PostingList::VT_VALUE, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn key_compare_less_than(&self, o: &PostingList) -> bool {
+    self.value() < o.value()
+  }
+
+  #[inline]
+  pub fn key_compare_with_value(&self, val: u32) -> ::core::cmp::Ordering {
+    let key = self.value();
+    key.cmp(&val)
+  }
+  #[inline]
+  pub fn cards(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(PostingList::VT_CARDS, None)
+  }
+}
+
+impl flatbuffers::Verifiable for PostingList<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("value", Self::VT_VALUE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>("cards", Self::VT_CARDS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PostingListArgs<'a> {
+    pub value: u32,
+    pub cards: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+}
+impl<'a> Default for PostingListArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    PostingListArgs {
+      value: 0,
+      cards: None,
+    }
+  }
+}
+
+pub struct PostingListBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> PostingListBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_value(&mut self, value: u32) {
+    self.fbb_.push_slot::<u32>(PostingList::VT_VALUE, value, 0);
+  }
+  #[inline]
+  pub fn add_cards(&mut self, cards: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u32>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PostingList::VT_CARDS, cards);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PostingListBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    PostingListBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<PostingList<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for PostingList<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("PostingList");
+      ds.field("value", &self.value());
+      ds.field("cards", &self.cards());
+      ds.finish()
+  }
+}
+pub enum NameGramOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct NameGram<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for NameGram<'a> {
+  type Inner = NameGram<'a>;
+  #[inline]
+  fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table { buf, loc } }
+  }
+}
+
+impl<'a> NameGram<'a> {
+  pub const VT_GRAM: flatbuffers::VOffsetT = 4;
+  pub const VT_CARDS: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    NameGram { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args NameGramArgs<'args>
+  ) -> flatbuffers::WIPOffset<NameGram<'bldr>> {
+    let mut builder = NameGramBuilder::new(_fbb);
+    if let Some(x) = args.cards { builder.add_cards(x); }
+    if let Some(x) = args.gram { builder.add_gram(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn gram(&self) -> &'a str {
+    self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(NameGram::VT_GRAM, None).unwrap()
+  }
+  #[inline]
+  pub fn key_compare_less_than(&self, o: &NameGram) -> bool {
+    self.gram() < o.gram()
+  }
+
+  #[inline]
+  pub fn key_compare_with_value(&self, val: & str) -> ::core::cmp::Ordering {
+    let key = self.gram();
+    key.cmp(val)
+  }
+  #[inline]
+  pub fn cards(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(NameGram::VT_CARDS, None)
+  }
+}
+
+impl flatbuffers::Verifiable for NameGram<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("gram", Self::VT_GRAM, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>("cards", Self::VT_CARDS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct NameGramArgs<'a> {
+    pub gram: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub cards: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+}
+impl<'a> Default for NameGramArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    NameGramArgs {
+      gram: None, // required field
+      cards: None,
+    }
+  }
+}
+
+pub struct NameGramBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> NameGramBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_gram(&mut self, gram: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(NameGram::VT_GRAM, gram);
+  }
+  #[inline]
+  pub fn add_cards(&mut self, cards: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u32>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(NameGram::VT_CARDS, cards);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> NameGramBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    NameGramBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<NameGram<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, NameGram::VT_GRAM,"gram");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for NameGram<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("NameGram");
+      ds.field("gram", &self.gram());
+      ds.field("cards", &self.cards());
       ds.finish()
   }
 }
@@ -735,6 +2333,16 @@ impl<'a> CardDatabase<'a> {
   pub const VT_GENERATED_AT_UTC: flatbuffers::VOffsetT = 12;
   pub const VT_SCRIPT_VERSION: flatbuffers::VOffsetT = 14;
   pub const VT_TOTAL_CARDS: flatbuffers::VOffsetT = 16;
+  pub const VT_CARD_SETS: flatbuffers::VOffsetT = 18;
+  pub const VT_CARD_SUB_TYPES: flatbuffers::VOffsetT = 20;
+  pub const VT_CARD_FAMILIES: flatbuffers::VOffsetT = 22;
+  pub const VT_SUSPENSION_EVENTS: flatbuffers::VOffsetT = 24;
+  pub const VT_CARDS_BY_FACTION: flatbuffers::VOffsetT = 26;
+  pub const VT_CARDS_BY_CARD_TYPE: flatbuffers::VOffsetT = 28;
+  pub const VT_CARDS_BY_RARITY: flatbuffers::VOffsetT = 30;
+  pub const VT_CARDS_BY_MAIN_COST: flatbuffers::VOffsetT = 32;
+  pub const VT_SUSPENDED_CARDS: flatbuffers::VOffsetT = 34;
+  pub const VT_NAME_GRAMS: flatbuffers::VOffsetT = 36;
 
   #[inline]
   pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -746,6 +2354,16 @@ impl<'a> CardDatabase<'a> {
     args: &'args CardDatabaseArgs<'args>
   ) -> flatbuffers::WIPOffset<CardDatabase<'bldr>> {
     let mut builder = CardDatabaseBuilder::new(_fbb);
+    if let Some(x) = args.name_grams { builder.add_name_grams(x); }
+    if let Some(x) = args.suspended_cards { builder.add_suspended_cards(x); }
+    if let Some(x) = args.cards_by_main_cost { builder.add_cards_by_main_cost(x); }
+    if let Some(x) = args.cards_by_rarity { builder.add_cards_by_rarity(x); }
+    if let Some(x) = args.cards_by_card_type { builder.add_cards_by_card_type(x); }
+    if let Some(x) = args.cards_by_faction { builder.add_cards_by_faction(x); }
+    if let Some(x) = args.suspension_events { builder.add_suspension_events(x); }
+    if let Some(x) = args.card_families { builder.add_card_families(x); }
+    if let Some(x) = args.card_sub_types { builder.add_card_sub_types(x); }
+    if let Some(x) = args.card_sets { builder.add_card_sets(x); }
     builder.add_total_cards(args.total_cards);
     if let Some(x) = args.script_version { builder.add_script_version(x); }
     if let Some(x) = args.generated_at_utc { builder.add_generated_at_utc(x); }
@@ -785,6 +2403,46 @@ impl<'a> CardDatabase<'a> {
   pub fn total_cards(&self) -> u32 {
     self._tab.get::<u32>(CardDatabase::VT_TOTAL_CARDS, Some(0)).unwrap()
   }
+  #[inline]
+  pub fn card_sets(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CardSet<'a>>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CardSet>>>>(CardDatabase::VT_CARD_SETS, None)
+  }
+  #[inline]
+  pub fn card_sub_types(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CardSubType<'a>>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CardSubType>>>>(CardDatabase::VT_CARD_SUB_TYPES, None)
+  }
+  #[inline]
+  pub fn card_families(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CardFamily<'a>>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CardFamily>>>>(CardDatabase::VT_CARD_FAMILIES, None)
+  }
+  #[inline]
+  pub fn suspension_events(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SuspensionEvent<'a>>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SuspensionEvent>>>>(CardDatabase::VT_SUSPENSION_EVENTS, None)
+  }
+  #[inline]
+  pub fn cards_by_faction(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<PostingList<'a>>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<PostingList>>>>(CardDatabase::VT_CARDS_BY_FACTION, None)
+  }
+  #[inline]
+  pub fn cards_by_card_type(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<PostingList<'a>>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<PostingList>>>>(CardDatabase::VT_CARDS_BY_CARD_TYPE, None)
+  }
+  #[inline]
+  pub fn cards_by_rarity(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<PostingList<'a>>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<PostingList>>>>(CardDatabase::VT_CARDS_BY_RARITY, None)
+  }
+  #[inline]
+  pub fn cards_by_main_cost(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<PostingList<'a>>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<PostingList>>>>(CardDatabase::VT_CARDS_BY_MAIN_COST, None)
+  }
+  #[inline]
+  pub fn suspended_cards(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(CardDatabase::VT_SUSPENDED_CARDS, None)
+  }
+  #[inline]
+  pub fn name_grams(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<NameGram<'a>>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<NameGram>>>>(CardDatabase::VT_NAME_GRAMS, None)
+  }
 }
 
 impl flatbuffers::Verifiable for CardDatabase<'_> {
@@ -801,6 +2459,16 @@ impl flatbuffers::Verifiable for CardDatabase<'_> {
      .visit_field::<flatbuffers::ForwardsUOffset<&str>>("generated_at_utc", Self::VT_GENERATED_AT_UTC, false)?
      .visit_field::<flatbuffers::ForwardsUOffset<&str>>("script_version", Self::VT_SCRIPT_VERSION, false)?
      .visit_field::<u32>("total_cards", Self::VT_TOTAL_CARDS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<CardSet>>>>("card_sets", Self::VT_CARD_SETS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<CardSubType>>>>("card_sub_types", Self::VT_CARD_SUB_TYPES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<CardFamily>>>>("card_families", Self::VT_CARD_FAMILIES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<SuspensionEvent>>>>("suspension_events", Self::VT_SUSPENSION_EVENTS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<PostingList>>>>("cards_by_faction", Self::VT_CARDS_BY_FACTION, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<PostingList>>>>("cards_by_card_type", Self::VT_CARDS_BY_CARD_TYPE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<PostingList>>>>("cards_by_rarity", Self::VT_CARDS_BY_RARITY, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<PostingList>>>>("cards_by_main_cost", Self::VT_CARDS_BY_MAIN_COST, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>("suspended_cards", Self::VT_SUSPENDED_CARDS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<NameGram>>>>("name_grams", Self::VT_NAME_GRAMS, false)?
      .finish();
     Ok(())
   }
@@ -813,6 +2481,16 @@ pub struct CardDatabaseArgs<'a> {
     pub generated_at_utc: Option<flatbuffers::WIPOffset<&'a str>>,
     pub script_version: Option<flatbuffers::WIPOffset<&'a str>>,
     pub total_cards: u32,
+    pub card_sets: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CardSet<'a>>>>>,
+    pub card_sub_types: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CardSubType<'a>>>>>,
+    pub card_families: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CardFamily<'a>>>>>,
+    pub suspension_events: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SuspensionEvent<'a>>>>>,
+    pub cards_by_faction: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<PostingList<'a>>>>>,
+    pub cards_by_card_type: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<PostingList<'a>>>>>,
+    pub cards_by_rarity: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<PostingList<'a>>>>>,
+    pub cards_by_main_cost: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<PostingList<'a>>>>>,
+    pub suspended_cards: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub name_grams: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<NameGram<'a>>>>>,
 }
 impl<'a> Default for CardDatabaseArgs<'a> {
   #[inline]
@@ -825,6 +2503,16 @@ impl<'a> Default for CardDatabaseArgs<'a> {
       generated_at_utc: None,
       script_version: None,
       total_cards: 0,
+      card_sets: None,
+      card_sub_types: None,
+      card_families: None,
+      suspension_events: None,
+      cards_by_faction: None,
+      cards_by_card_type: None,
+      cards_by_rarity: None,
+      cards_by_main_cost: None,
+      suspended_cards: None,
+      name_grams: None,
     }
   }
 }
@@ -863,6 +2551,46 @@ impl<'a: 'b, 'b> CardDatabaseBuilder<'a, 'b> {
     self.fbb_.push_slot::<u32>(CardDatabase::VT_TOTAL_CARDS, total_cards, 0);
   }
   #[inline]
+  pub fn add_card_sets(&mut self, card_sets: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<CardSet<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CardDatabase::VT_CARD_SETS, card_sets);
+  }
+  #[inline]
+  pub fn add_card_sub_types(&mut self, card_sub_types: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<CardSubType<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CardDatabase::VT_CARD_SUB_TYPES, card_sub_types);
+  }
+  #[inline]
+  pub fn add_card_families(&mut self, card_families: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<CardFamily<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CardDatabase::VT_CARD_FAMILIES, card_families);
+  }
+  #[inline]
+  pub fn add_suspension_events(&mut self, suspension_events: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<SuspensionEvent<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CardDatabase::VT_SUSPENSION_EVENTS, suspension_events);
+  }
+  #[inline]
+  pub fn add_cards_by_faction(&mut self, cards_by_faction: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<PostingList<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CardDatabase::VT_CARDS_BY_FACTION, cards_by_faction);
+  }
+  #[inline]
+  pub fn add_cards_by_card_type(&mut self, cards_by_card_type: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<PostingList<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CardDatabase::VT_CARDS_BY_CARD_TYPE, cards_by_card_type);
+  }
+  #[inline]
+  pub fn add_cards_by_rarity(&mut self, cards_by_rarity: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<PostingList<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CardDatabase::VT_CARDS_BY_RARITY, cards_by_rarity);
+  }
+  #[inline]
+  pub fn add_cards_by_main_cost(&mut self, cards_by_main_cost: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<PostingList<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CardDatabase::VT_CARDS_BY_MAIN_COST, cards_by_main_cost);
+  }
+  #[inline]
+  pub fn add_suspended_cards(&mut self, suspended_cards: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u32>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CardDatabase::VT_SUSPENDED_CARDS, suspended_cards);
+  }
+  #[inline]
+  pub fn add_name_grams(&mut self, name_grams: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<NameGram<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CardDatabase::VT_NAME_GRAMS, name_grams);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> CardDatabaseBuilder<'a, 'b> {
     let start = _fbb.start_table();
     CardDatabaseBuilder {
@@ -887,6 +2615,16 @@ impl core::fmt::Debug for CardDatabase<'_> {
       ds.field("generated_at_utc", &self.generated_at_utc());
       ds.field("script_version", &self.script_version());
       ds.field("total_cards", &self.total_cards());
+      ds.field("card_sets", &self.card_sets());
+      ds.field("card_sub_types", &self.card_sub_types());
+      ds.field("card_families", &self.card_families());
+      ds.field("suspension_events", &self.suspension_events());
+      ds.field("cards_by_faction", &self.cards_by_faction());
+      ds.field("cards_by_card_type", &self.cards_by_card_type());
+      ds.field("cards_by_rarity", &self.cards_by_rarity());
+      ds.field("cards_by_main_cost", &self.cards_by_main_cost());
+      ds.field("suspended_cards", &self.suspended_cards());
+      ds.field("name_grams", &self.name_grams());
       ds.finish()
   }
 }
@@ -0,0 +1,1239 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+
+// @generated
+
+use core::mem;
+use core::cmp::Ordering;
+
+extern crate flatbuffers;
+use self::flatbuffers::{EndianScalar, Follow};
+
+#[allow(unused_imports, dead_code)]
+pub mod altered_cards {
+
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+
+pub enum StringPoolOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct StringPool<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for StringPool<'a> {
+  type Inner = StringPool<'a>;
+  #[inline]
+  fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table { buf, loc } }
+  }
+}
+
+impl<'a> StringPool<'a> {
+  pub const VT_STRINGS: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    StringPool { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args StringPoolArgs<'args>
+  ) -> flatbuffers::WIPOffset<StringPool<'bldr>> {
+    let mut builder = StringPoolBuilder::new(_fbb);
+    if let Some(x) = args.strings { builder.add_strings(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn strings(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(StringPool::VT_STRINGS, None)
+  }
+}
+
+impl flatbuffers::Verifiable for StringPool<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("strings", Self::VT_STRINGS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct StringPoolArgs<'a> {
+    pub strings: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+}
+impl<'a> Default for StringPoolArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    StringPoolArgs {
+      strings: None,
+    }
+  }
+}
+
+pub struct StringPoolBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> StringPoolBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_strings(&mut self, strings: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(StringPool::VT_STRINGS, strings);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> StringPoolBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    StringPoolBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<StringPool<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for StringPool<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("StringPool");
+      ds.field("strings", &self.strings());
+      ds.finish()
+  }
+}
+pub enum FactionOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Faction<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Faction<'a> {
+  type Inner = Faction<'a>;
+  #[inline]
+  fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table { buf, loc } }
+  }
+}
+
+impl<'a> Faction<'a> {
+  pub const VT_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_NAME_IDX: flatbuffers::VOffsetT = 6;
+  pub const VT_COLOR_IDX: flatbuffers::VOffsetT = 8;
+  pub const VT_REFERENCE_IDX: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Faction { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args FactionArgs
+  ) -> flatbuffers::WIPOffset<Faction<'bldr>> {
+    let mut builder = FactionBuilder::new(_fbb);
+    builder.add_reference_idx(args.reference_idx);
+    builder.add_color_idx(args.color_idx);
+    builder.add_name_idx(args.name_idx);
+    builder.add_id(args.id);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn id(&self) -> u16 {
+    self._tab.get::<u16>(Faction::VT_ID, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn name_idx(&self) -> u32 {
+    self._tab.get::<u32>(Faction::VT_NAME_IDX, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn color_idx(&self) -> u32 {
+    self._tab.get::<u32>(Faction::VT_COLOR_IDX, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn reference_idx(&self) -> u32 {
+    self._tab.get::<u32>(Faction::VT_REFERENCE_IDX, Some(0)).unwrap()
+  }
+}
+
+impl flatbuffers::Verifiable for Faction<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u16>("id", Self::VT_ID, false)?
+     .visit_field::<u32>("name_idx", Self::VT_NAME_IDX, false)?
+     .visit_field::<u32>("color_idx", Self::VT_COLOR_IDX, false)?
+     .visit_field::<u32>("reference_idx", Self::VT_REFERENCE_IDX, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct FactionArgs {
+    pub id: u16,
+    pub name_idx: u32,
+    pub color_idx: u32,
+    pub reference_idx: u32,
+}
+impl<'a> Default for FactionArgs {
+  #[inline]
+  fn default() -> Self {
+    FactionArgs {
+      id: 0,
+      name_idx: 0,
+      color_idx: 0,
+      reference_idx: 0,
+    }
+  }
+}
+
+pub struct FactionBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> FactionBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_id(&mut self, id: u16) {
+    self.fbb_.push_slot::<u16>(Faction::VT_ID, id, 0);
+  }
+  #[inline]
+  pub fn add_name_idx(&mut self, name_idx: u32) {
+    self.fbb_.push_slot::<u32>(Faction::VT_NAME_IDX, name_idx, 0);
+  }
+  #[inline]
+  pub fn add_color_idx(&mut self, color_idx: u32) {
+    self.fbb_.push_slot::<u32>(Faction::VT_COLOR_IDX, color_idx, 0);
+  }
+  #[inline]
+  pub fn add_reference_idx(&mut self, reference_idx: u32) {
+    self.fbb_.push_slot::<u32>(Faction::VT_REFERENCE_IDX, reference_idx, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> FactionBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    FactionBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Faction<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Faction<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Faction");
+      ds.field("id", &self.id());
+      ds.field("name_idx", &self.name_idx());
+      ds.field("color_idx", &self.color_idx());
+      ds.field("reference_idx", &self.reference_idx());
+      ds.finish()
+  }
+}
+pub enum RarityOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Rarity<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Rarity<'a> {
+  type Inner = Rarity<'a>;
+  #[inline]
+  fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table { buf, loc } }
+  }
+}
+
+impl<'a> Rarity<'a> {
+  pub const VT_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_NAME_IDX: flatbuffers::VOffsetT = 6;
+  pub const VT_REFERENCE_IDX: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Rarity { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args RarityArgs
+  ) -> flatbuffers::WIPOffset<Rarity<'bldr>> {
+    let mut builder = RarityBuilder::new(_fbb);
+    builder.add_reference_idx(args.reference_idx);
+    builder.add_name_idx(args.name_idx);
+    builder.add_id(args.id);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn id(&self) -> u16 {
+    self._tab.get::<u16>(Rarity::VT_ID, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn name_idx(&self) -> u32 {
+    self._tab.get::<u32>(Rarity::VT_NAME_IDX, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn reference_idx(&self) -> u32 {
+    self._tab.get::<u32>(Rarity::VT_REFERENCE_IDX, Some(0)).unwrap()
+  }
+}
+
+impl flatbuffers::Verifiable for Rarity<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u16>("id", Self::VT_ID, false)?
+     .visit_field::<u32>("name_idx", Self::VT_NAME_IDX, false)?
+     .visit_field::<u32>("reference_idx", Self::VT_REFERENCE_IDX, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct RarityArgs {
+    pub id: u16,
+    pub name_idx: u32,
+    pub reference_idx: u32,
+}
+impl<'a> Default for RarityArgs {
+  #[inline]
+  fn default() -> Self {
+    RarityArgs {
+      id: 0,
+      name_idx: 0,
+      reference_idx: 0,
+    }
+  }
+}
+
+pub struct RarityBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> RarityBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_id(&mut self, id: u16) {
+    self.fbb_.push_slot::<u16>(Rarity::VT_ID, id, 0);
+  }
+  #[inline]
+  pub fn add_name_idx(&mut self, name_idx: u32) {
+    self.fbb_.push_slot::<u32>(Rarity::VT_NAME_IDX, name_idx, 0);
+  }
+  #[inline]
+  pub fn add_reference_idx(&mut self, reference_idx: u32) {
+    self.fbb_.push_slot::<u32>(Rarity::VT_REFERENCE_IDX, reference_idx, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> RarityBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    RarityBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Rarity<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Rarity<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Rarity");
+      ds.field("id", &self.id());
+      ds.field("name_idx", &self.name_idx());
+      ds.field("reference_idx", &self.reference_idx());
+      ds.finish()
+  }
+}
+pub enum CardTypeOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct CardType<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for CardType<'a> {
+  type Inner = CardType<'a>;
+  #[inline]
+  fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table { buf, loc } }
+  }
+}
+
+impl<'a> CardType<'a> {
+  pub const VT_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_NAME_IDX: flatbuffers::VOffsetT = 6;
+  pub const VT_REFERENCE_IDX: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    CardType { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args CardTypeArgs
+  ) -> flatbuffers::WIPOffset<CardType<'bldr>> {
+    let mut builder = CardTypeBuilder::new(_fbb);
+    builder.add_reference_idx(args.reference_idx);
+    builder.add_name_idx(args.name_idx);
+    builder.add_id(args.id);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn id(&self) -> u16 {
+    self._tab.get::<u16>(CardType::VT_ID, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn name_idx(&self) -> u32 {
+    self._tab.get::<u32>(CardType::VT_NAME_IDX, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn reference_idx(&self) -> u32 {
+    self._tab.get::<u32>(CardType::VT_REFERENCE_IDX, Some(0)).unwrap()
+  }
+}
+
+impl flatbuffers::Verifiable for CardType<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u16>("id", Self::VT_ID, false)?
+     .visit_field::<u32>("name_idx", Self::VT_NAME_IDX, false)?
+     .visit_field::<u32>("reference_idx", Self::VT_REFERENCE_IDX, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct CardTypeArgs {
+    pub id: u16,
+    pub name_idx: u32,
+    pub reference_idx: u32,
+}
+impl<'a> Default for CardTypeArgs {
+  #[inline]
+  fn default() -> Self {
+    CardTypeArgs {
+      id: 0,
+      name_idx: 0,
+      reference_idx: 0,
+    }
+  }
+}
+
+pub struct CardTypeBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> CardTypeBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_id(&mut self, id: u16) {
+    self.fbb_.push_slot::<u16>(CardType::VT_ID, id, 0);
+  }
+  #[inline]
+  pub fn add_name_idx(&mut self, name_idx: u32) {
+    self.fbb_.push_slot::<u32>(CardType::VT_NAME_IDX, name_idx, 0);
+  }
+  #[inline]
+  pub fn add_reference_idx(&mut self, reference_idx: u32) {
+    self.fbb_.push_slot::<u32>(CardType::VT_REFERENCE_IDX, reference_idx, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> CardTypeBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    CardTypeBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<CardType<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for CardType<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("CardType");
+      ds.field("id", &self.id());
+      ds.field("name_idx", &self.name_idx());
+      ds.field("reference_idx", &self.reference_idx());
+      ds.finish()
+  }
+}
+pub enum CardOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Card<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Card<'a> {
+  type Inner = Card<'a>;
+  #[inline]
+  fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table { buf, loc } }
+  }
+}
+
+impl<'a> Card<'a> {
+  pub const VT_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_REFERENCE_IDX: flatbuffers::VOffsetT = 6;
+  pub const VT_NAME_IDX: flatbuffers::VOffsetT = 8;
+  pub const VT_FACTION_ID: flatbuffers::VOffsetT = 10;
+  pub const VT_RARITY_ID: flatbuffers::VOffsetT = 12;
+  pub const VT_CARD_TYPE_ID: flatbuffers::VOffsetT = 14;
+  pub const VT_MAIN_COST: flatbuffers::VOffsetT = 16;
+  pub const VT_RECALL_COST: flatbuffers::VOffsetT = 18;
+  pub const VT_POWER_PACKED: flatbuffers::VOffsetT = 20;
+  pub const VT_IMAGE_PATH_IDX: flatbuffers::VOffsetT = 22;
+  pub const VT_QR_URL_IDX: flatbuffers::VOffsetT = 24;
+  pub const VT_FLAGS: flatbuffers::VOffsetT = 26;
+
+  #[inline]
+  pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Card { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args CardArgs
+  ) -> flatbuffers::WIPOffset<Card<'bldr>> {
+    let mut builder = CardBuilder::new(_fbb);
+    builder.add_qr_url_idx(args.qr_url_idx);
+    builder.add_image_path_idx(args.image_path_idx);
+    builder.add_power_packed(args.power_packed);
+    builder.add_name_idx(args.name_idx);
+    builder.add_reference_idx(args.reference_idx);
+    builder.add_id(args.id);
+    builder.add_card_type_id(args.card_type_id);
+    builder.add_rarity_id(args.rarity_id);
+    builder.add_faction_id(args.faction_id);
+    builder.add_flags(args.flags);
+    builder.add_recall_cost(args.recall_cost);
+    builder.add_main_cost(args.main_cost);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn id(&self) -> u32 {
+    self._tab.get::<u32>(Card::VT_ID, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn reference_idx(&self) -> u32 {
+    self._tab.get::<u32>(Card::VT_REFERENCE_IDX, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn name_idx(&self) -> u32 {
+    self._tab.get::<u32>(Card::VT_NAME_IDX, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn faction_id(&self) -> u16 {
+    self._tab.get::<u16>(Card::VT_FACTION_ID, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn rarity_id(&self) -> u16 {
+    self._tab.get::<u16>(Card::VT_RARITY_ID, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn card_type_id(&self) -> u16 {
+    self._tab.get::<u16>(Card::VT_CARD_TYPE_ID, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn main_cost(&self) -> u8 {
+    self._tab.get::<u8>(Card::VT_MAIN_COST, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn recall_cost(&self) -> u8 {
+    self._tab.get::<u8>(Card::VT_RECALL_COST, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn power_packed(&self) -> u32 {
+    self._tab.get::<u32>(Card::VT_POWER_PACKED, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn image_path_idx(&self) -> u32 {
+    self._tab.get::<u32>(Card::VT_IMAGE_PATH_IDX, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn qr_url_idx(&self) -> u32 {
+    self._tab.get::<u32>(Card::VT_QR_URL_IDX, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn flags(&self) -> u8 {
+    self._tab.get::<u8>(Card::VT_FLAGS, Some(0)).unwrap()
+  }
+}
+
+impl flatbuffers::Verifiable for Card<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("id", Self::VT_ID, false)?
+     .visit_field::<u32>("reference_idx", Self::VT_REFERENCE_IDX, false)?
+     .visit_field::<u32>("name_idx", Self::VT_NAME_IDX, false)?
+     .visit_field::<u16>("faction_id", Self::VT_FACTION_ID, false)?
+     .visit_field::<u16>("rarity_id", Self::VT_RARITY_ID, false)?
+     .visit_field::<u16>("card_type_id", Self::VT_CARD_TYPE_ID, false)?
+     .visit_field::<u8>("main_cost", Self::VT_MAIN_COST, false)?
+     .visit_field::<u8>("recall_cost", Self::VT_RECALL_COST, false)?
+     .visit_field::<u32>("power_packed", Self::VT_POWER_PACKED, false)?
+     .visit_field::<u32>("image_path_idx", Self::VT_IMAGE_PATH_IDX, false)?
+     .visit_field::<u32>("qr_url_idx", Self::VT_QR_URL_IDX, false)?
+     .visit_field::<u8>("flags", Self::VT_FLAGS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct CardArgs {
+    pub id: u32,
+    pub reference_idx: u32,
+    pub name_idx: u32,
+    pub faction_id: u16,
+    pub rarity_id: u16,
+    pub card_type_id: u16,
+    pub main_cost: u8,
+    pub recall_cost: u8,
+    pub power_packed: u32,
+    pub image_path_idx: u32,
+    pub qr_url_idx: u32,
+    pub flags: u8,
+}
+impl<'a> Default for CardArgs {
+  #[inline]
+  fn default() -> Self {
+    CardArgs {
+      id: 0,
+      reference_idx: 0,
+      name_idx: 0,
+      faction_id: 0,
+      rarity_id: 0,
+      card_type_id: 0,
+      main_cost: 0,
+      recall_cost: 0,
+      power_packed: 0,
+      image_path_idx: 0,
+      qr_url_idx: 0,
+      flags: 0,
+    }
+  }
+}
+
+pub struct CardBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> CardBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_id(&mut self, id: u32) {
+    self.fbb_.push_slot::<u32>(Card::VT_ID, id, 0);
+  }
+  #[inline]
+  pub fn add_reference_idx(&mut self, reference_idx: u32) {
+    self.fbb_.push_slot::<u32>(Card::VT_REFERENCE_IDX, reference_idx, 0);
+  }
+  #[inline]
+  pub fn add_name_idx(&mut self, name_idx: u32) {
+    self.fbb_.push_slot::<u32>(Card::VT_NAME_IDX, name_idx, 0);
+  }
+  #[inline]
+  pub fn add_faction_id(&mut self, faction_id: u16) {
+    self.fbb_.push_slot::<u16>(Card::VT_FACTION_ID, faction_id, 0);
+  }
+  #[inline]
+  pub fn add_rarity_id(&mut self, rarity_id: u16) {
+    self.fbb_.push_slot::<u16>(Card::VT_RARITY_ID, rarity_id, 0);
+  }
+  #[inline]
+  pub fn add_card_type_id(&mut self, card_type_id: u16) {
+    self.fbb_.push_slot::<u16>(Card::VT_CARD_TYPE_ID, card_type_id, 0);
+  }
+  #[inline]
+  pub fn add_main_cost(&mut self, main_cost: u8) {
+    self.fbb_.push_slot::<u8>(Card::VT_MAIN_COST, main_cost, 0);
+  }
+  #[inline]
+  pub fn add_recall_cost(&mut self, recall_cost: u8) {
+    self.fbb_.push_slot::<u8>(Card::VT_RECALL_COST, recall_cost, 0);
+  }
+  #[inline]
+  pub fn add_power_packed(&mut self, power_packed: u32) {
+    self.fbb_.push_slot::<u32>(Card::VT_POWER_PACKED, power_packed, 0);
+  }
+  #[inline]
+  pub fn add_image_path_idx(&mut self, image_path_idx: u32) {
+    self.fbb_.push_slot::<u32>(Card::VT_IMAGE_PATH_IDX, image_path_idx, 0);
+  }
+  #[inline]
+  pub fn add_qr_url_idx(&mut self, qr_url_idx: u32) {
+    self.fbb_.push_slot::<u32>(Card::VT_QR_URL_IDX, qr_url_idx, 0);
+  }
+  #[inline]
+  pub fn add_flags(&mut self, flags: u8) {
+    self.fbb_.push_slot::<u8>(Card::VT_FLAGS, flags, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> CardBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    CardBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Card<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Card<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Card");
+      ds.field("id", &self.id());
+      ds.field("reference_idx", &self.reference_idx());
+      ds.field("name_idx", &self.name_idx());
+      ds.field("faction_id", &self.faction_id());
+      ds.field("rarity_id", &self.rarity_id());
+      ds.field("card_type_id", &self.card_type_id());
+      ds.field("main_cost", &self.main_cost());
+      ds.field("recall_cost", &self.recall_cost());
+      ds.field("power_packed", &self.power_packed());
+      ds.field("image_path_idx", &self.image_path_idx());
+      ds.field("qr_url_idx", &self.qr_url_idx());
+      ds.field("flags", &self.flags());
+      ds.finish()
+  }
+}
+pub enum CardDeltaOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct CardDelta<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for CardDelta<'a> {
+  type Inner = CardDelta<'a>;
+  #[inline]
+  fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table { buf, loc } }
+  }
+}
+
+impl<'a> CardDelta<'a> {
+  pub const VT_ADDED_CARDS: flatbuffers::VOffsetT = 4;
+  pub const VT_MODIFIED_CARDS: flatbuffers::VOffsetT = 6;
+  pub const VT_REMOVED_CARD_IDS: flatbuffers::VOffsetT = 8;
+  pub const VT_DELTA_VERSION: flatbuffers::VOffsetT = 10;
+  pub const VT_BASE_VERSION: flatbuffers::VOffsetT = 12;
+
+  #[inline]
+  pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    CardDelta { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args CardDeltaArgs<'args>
+  ) -> flatbuffers::WIPOffset<CardDelta<'bldr>> {
+    let mut builder = CardDeltaBuilder::new(_fbb);
+    builder.add_base_version(args.base_version);
+    builder.add_delta_version(args.delta_version);
+    if let Some(x) = args.removed_card_ids { builder.add_removed_card_ids(x); }
+    if let Some(x) = args.modified_cards { builder.add_modified_cards(x); }
+    if let Some(x) = args.added_cards { builder.add_added_cards(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn added_cards(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Card<'a>>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Card>>>>(CardDelta::VT_ADDED_CARDS, None)
+  }
+  #[inline]
+  pub fn modified_cards(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Card<'a>>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Card>>>>(CardDelta::VT_MODIFIED_CARDS, None)
+  }
+  #[inline]
+  pub fn removed_card_ids(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(CardDelta::VT_REMOVED_CARD_IDS, None)
+  }
+  #[inline]
+  pub fn delta_version(&self) -> u32 {
+    self._tab.get::<u32>(CardDelta::VT_DELTA_VERSION, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn base_version(&self) -> u32 {
+    self._tab.get::<u32>(CardDelta::VT_BASE_VERSION, Some(0)).unwrap()
+  }
+}
+
+impl flatbuffers::Verifiable for CardDelta<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Card>>>>("added_cards", Self::VT_ADDED_CARDS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Card>>>>("modified_cards", Self::VT_MODIFIED_CARDS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>("removed_card_ids", Self::VT_REMOVED_CARD_IDS, false)?
+     .visit_field::<u32>("delta_version", Self::VT_DELTA_VERSION, false)?
+     .visit_field::<u32>("base_version", Self::VT_BASE_VERSION, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct CardDeltaArgs<'a> {
+    pub added_cards: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Card<'a>>>>>,
+    pub modified_cards: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Card<'a>>>>>,
+    pub removed_card_ids: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub delta_version: u32,
+    pub base_version: u32,
+}
+impl<'a> Default for CardDeltaArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    CardDeltaArgs {
+      added_cards: None,
+      modified_cards: None,
+      removed_card_ids: None,
+      delta_version: 0,
+      base_version: 0,
+    }
+  }
+}
+
+pub struct CardDeltaBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> CardDeltaBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_added_cards(&mut self, added_cards: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Card<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CardDelta::VT_ADDED_CARDS, added_cards);
+  }
+  #[inline]
+  pub fn add_modified_cards(&mut self, modified_cards: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Card<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CardDelta::VT_MODIFIED_CARDS, modified_cards);
+  }
+  #[inline]
+  pub fn add_removed_card_ids(&mut self, removed_card_ids: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u32>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CardDelta::VT_REMOVED_CARD_IDS, removed_card_ids);
+  }
+  #[inline]
+  pub fn add_delta_version(&mut self, delta_version: u32) {
+    self.fbb_.push_slot::<u32>(CardDelta::VT_DELTA_VERSION, delta_version, 0);
+  }
+  #[inline]
+  pub fn add_base_version(&mut self, base_version: u32) {
+    self.fbb_.push_slot::<u32>(CardDelta::VT_BASE_VERSION, base_version, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> CardDeltaBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    CardDeltaBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<CardDelta<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for CardDelta<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("CardDelta");
+      ds.field("added_cards", &self.added_cards());
+      ds.field("modified_cards", &self.modified_cards());
+      ds.field("removed_card_ids", &self.removed_card_ids());
+      ds.field("delta_version", &self.delta_version());
+      ds.field("base_version", &self.base_version());
+      ds.finish()
+  }
+}
+pub enum OptimizedCardDatabaseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct OptimizedCardDatabase<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for OptimizedCardDatabase<'a> {
+  type Inner = OptimizedCardDatabase<'a>;
+  #[inline]
+  fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table { buf, loc } }
+  }
+}
+
+impl<'a> OptimizedCardDatabase<'a> {
+  pub const VT_STRING_POOL: flatbuffers::VOffsetT = 4;
+  pub const VT_FACTIONS: flatbuffers::VOffsetT = 6;
+  pub const VT_RARITIES: flatbuffers::VOffsetT = 8;
+  pub const VT_CARD_TYPES: flatbuffers::VOffsetT = 10;
+  pub const VT_CARDS: flatbuffers::VOffsetT = 12;
+  pub const VT_GENERATED_AT_UTC_IDX: flatbuffers::VOffsetT = 14;
+  pub const VT_SCRIPT_VERSION_IDX: flatbuffers::VOffsetT = 16;
+  pub const VT_TOTAL_CARDS: flatbuffers::VOffsetT = 18;
+  pub const VT_DATABASE_VERSION: flatbuffers::VOffsetT = 20;
+  pub const VT_COMPRESSION_TYPE: flatbuffers::VOffsetT = 22;
+  pub const VT_ORIGINAL_SIZE: flatbuffers::VOffsetT = 24;
+  pub const VT_COMPRESSED_SIZE: flatbuffers::VOffsetT = 26;
+  pub const VT_ID_INDEX: flatbuffers::VOffsetT = 28;
+
+  #[inline]
+  pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    OptimizedCardDatabase { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args OptimizedCardDatabaseArgs<'args>
+  ) -> flatbuffers::WIPOffset<OptimizedCardDatabase<'bldr>> {
+    let mut builder = OptimizedCardDatabaseBuilder::new(_fbb);
+    if let Some(x) = args.id_index { builder.add_id_index(x); }
+    builder.add_compressed_size(args.compressed_size);
+    builder.add_original_size(args.original_size);
+    builder.add_database_version(args.database_version);
+    builder.add_total_cards(args.total_cards);
+    builder.add_script_version_idx(args.script_version_idx);
+    builder.add_generated_at_utc_idx(args.generated_at_utc_idx);
+    if let Some(x) = args.cards { builder.add_cards(x); }
+    if let Some(x) = args.card_types { builder.add_card_types(x); }
+    if let Some(x) = args.rarities { builder.add_rarities(x); }
+    if let Some(x) = args.factions { builder.add_factions(x); }
+    if let Some(x) = args.string_pool { builder.add_string_pool(x); }
+    builder.add_compression_type(args.compression_type);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn string_pool(&self) -> Option<StringPool<'a>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<StringPool>>(OptimizedCardDatabase::VT_STRING_POOL, None)
+  }
+  #[inline]
+  pub fn factions(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Faction<'a>>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Faction>>>>(OptimizedCardDatabase::VT_FACTIONS, None)
+  }
+  #[inline]
+  pub fn rarities(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Rarity<'a>>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Rarity>>>>(OptimizedCardDatabase::VT_RARITIES, None)
+  }
+  #[inline]
+  pub fn card_types(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CardType<'a>>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CardType>>>>(OptimizedCardDatabase::VT_CARD_TYPES, None)
+  }
+  #[inline]
+  pub fn cards(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Card<'a>>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Card>>>>(OptimizedCardDatabase::VT_CARDS, None)
+  }
+  #[inline]
+  pub fn generated_at_utc_idx(&self) -> u32 {
+    self._tab.get::<u32>(OptimizedCardDatabase::VT_GENERATED_AT_UTC_IDX, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn script_version_idx(&self) -> u32 {
+    self._tab.get::<u32>(OptimizedCardDatabase::VT_SCRIPT_VERSION_IDX, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn total_cards(&self) -> u32 {
+    self._tab.get::<u32>(OptimizedCardDatabase::VT_TOTAL_CARDS, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn database_version(&self) -> u32 {
+    self._tab.get::<u32>(OptimizedCardDatabase::VT_DATABASE_VERSION, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn compression_type(&self) -> u8 {
+    self._tab.get::<u8>(OptimizedCardDatabase::VT_COMPRESSION_TYPE, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn original_size(&self) -> u32 {
+    self._tab.get::<u32>(OptimizedCardDatabase::VT_ORIGINAL_SIZE, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn compressed_size(&self) -> u32 {
+    self._tab.get::<u32>(OptimizedCardDatabase::VT_COMPRESSED_SIZE, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn id_index(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(OptimizedCardDatabase::VT_ID_INDEX, None)
+  }
+}
+
+impl flatbuffers::Verifiable for OptimizedCardDatabase<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<StringPool>>("string_pool", Self::VT_STRING_POOL, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Faction>>>>("factions", Self::VT_FACTIONS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Rarity>>>>("rarities", Self::VT_RARITIES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<CardType>>>>("card_types", Self::VT_CARD_TYPES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Card>>>>("cards", Self::VT_CARDS, false)?
+     .visit_field::<u32>("generated_at_utc_idx", Self::VT_GENERATED_AT_UTC_IDX, false)?
+     .visit_field::<u32>("script_version_idx", Self::VT_SCRIPT_VERSION_IDX, false)?
+     .visit_field::<u32>("total_cards", Self::VT_TOTAL_CARDS, false)?
+     .visit_field::<u32>("database_version", Self::VT_DATABASE_VERSION, false)?
+     .visit_field::<u8>("compression_type", Self::VT_COMPRESSION_TYPE, false)?
+     .visit_field::<u32>("original_size", Self::VT_ORIGINAL_SIZE, false)?
+     .visit_field::<u32>("compressed_size", Self::VT_COMPRESSED_SIZE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>("id_index", Self::VT_ID_INDEX, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct OptimizedCardDatabaseArgs<'a> {
+    pub string_pool: Option<flatbuffers::WIPOffset<StringPool<'a>>>,
+    pub factions: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Faction<'a>>>>>,
+    pub rarities: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Rarity<'a>>>>>,
+    pub card_types: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CardType<'a>>>>>,
+    pub cards: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Card<'a>>>>>,
+    pub generated_at_utc_idx: u32,
+    pub script_version_idx: u32,
+    pub total_cards: u32,
+    pub database_version: u32,
+    pub compression_type: u8,
+    pub original_size: u32,
+    pub compressed_size: u32,
+    pub id_index: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+}
+impl<'a> Default for OptimizedCardDatabaseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    OptimizedCardDatabaseArgs {
+      string_pool: None,
+      factions: None,
+      rarities: None,
+      card_types: None,
+      cards: None,
+      generated_at_utc_idx: 0,
+      script_version_idx: 0,
+      total_cards: 0,
+      database_version: 0,
+      compression_type: 0,
+      original_size: 0,
+      compressed_size: 0,
+      id_index: None,
+    }
+  }
+}
+
+pub struct OptimizedCardDatabaseBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> OptimizedCardDatabaseBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_string_pool(&mut self, string_pool: flatbuffers::WIPOffset<StringPool<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<StringPool>>(OptimizedCardDatabase::VT_STRING_POOL, string_pool);
+  }
+  #[inline]
+  pub fn add_factions(&mut self, factions: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Faction<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(OptimizedCardDatabase::VT_FACTIONS, factions);
+  }
+  #[inline]
+  pub fn add_rarities(&mut self, rarities: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Rarity<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(OptimizedCardDatabase::VT_RARITIES, rarities);
+  }
+  #[inline]
+  pub fn add_card_types(&mut self, card_types: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<CardType<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(OptimizedCardDatabase::VT_CARD_TYPES, card_types);
+  }
+  #[inline]
+  pub fn add_cards(&mut self, cards: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Card<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(OptimizedCardDatabase::VT_CARDS, cards);
+  }
+  #[inline]
+  pub fn add_generated_at_utc_idx(&mut self, generated_at_utc_idx: u32) {
+    self.fbb_.push_slot::<u32>(OptimizedCardDatabase::VT_GENERATED_AT_UTC_IDX, generated_at_utc_idx, 0);
+  }
+  #[inline]
+  pub fn add_script_version_idx(&mut self, script_version_idx: u32) {
+    self.fbb_.push_slot::<u32>(OptimizedCardDatabase::VT_SCRIPT_VERSION_IDX, script_version_idx, 0);
+  }
+  #[inline]
+  pub fn add_total_cards(&mut self, total_cards: u32) {
+    self.fbb_.push_slot::<u32>(OptimizedCardDatabase::VT_TOTAL_CARDS, total_cards, 0);
+  }
+  #[inline]
+  pub fn add_database_version(&mut self, database_version: u32) {
+    self.fbb_.push_slot::<u32>(OptimizedCardDatabase::VT_DATABASE_VERSION, database_version, 0);
+  }
+  #[inline]
+  pub fn add_compression_type(&mut self, compression_type: u8) {
+    self.fbb_.push_slot::<u8>(OptimizedCardDatabase::VT_COMPRESSION_TYPE, compression_type, 0);
+  }
+  #[inline]
+  pub fn add_original_size(&mut self, original_size: u32) {
+    self.fbb_.push_slot::<u32>(OptimizedCardDatabase::VT_ORIGINAL_SIZE, original_size, 0);
+  }
+  #[inline]
+  pub fn add_compressed_size(&mut self, compressed_size: u32) {
+    self.fbb_.push_slot::<u32>(OptimizedCardDatabase::VT_COMPRESSED_SIZE, compressed_size, 0);
+  }
+  #[inline]
+  pub fn add_id_index(&mut self, id_index: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u32>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(OptimizedCardDatabase::VT_ID_INDEX, id_index);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> OptimizedCardDatabaseBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    OptimizedCardDatabaseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<OptimizedCardDatabase<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for OptimizedCardDatabase<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("OptimizedCardDatabase");
+      ds.field("string_pool", &self.string_pool());
+      ds.field("factions", &self.factions());
+      ds.field("rarities", &self.rarities());
+      ds.field("card_types", &self.card_types());
+      ds.field("cards", &self.cards());
+      ds.field("generated_at_utc_idx", &self.generated_at_utc_idx());
+      ds.field("script_version_idx", &self.script_version_idx());
+      ds.field("total_cards", &self.total_cards());
+      ds.field("database_version", &self.database_version());
+      ds.field("compression_type", &self.compression_type());
+      ds.field("original_size", &self.original_size());
+      ds.field("compressed_size", &self.compressed_size());
+      ds.field("id_index", &self.id_index());
+      ds.finish()
+  }
+}
+#[inline]
+#[deprecated(since="2.0.0", note="Deprecated in favor of `root_as...` methods.")]
+pub fn get_root_as_optimized_card_database<'a>(buf: &'a [u8]) -> OptimizedCardDatabase<'a> {
+  unsafe { flatbuffers::root_unchecked::<OptimizedCardDatabase<'a>>(buf) }
+}
+
+#[inline]
+#[deprecated(since="2.0.0", note="Deprecated in favor of `root_as...` methods.")]
+pub fn get_size_prefixed_root_as_optimized_card_database<'a>(buf: &'a [u8]) -> OptimizedCardDatabase<'a> {
+  unsafe { flatbuffers::size_prefixed_root_unchecked::<OptimizedCardDatabase<'a>>(buf) }
+}
+
+#[inline]
+/// Verifies that a buffer of bytes contains a `OptimizedCardDatabase`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_optimized_card_database_unchecked`.
+pub fn root_as_optimized_card_database(buf: &[u8]) -> Result<OptimizedCardDatabase, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<OptimizedCardDatabase>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `OptimizedCardDatabase` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_optimized_card_database_unchecked`.
+pub fn size_prefixed_root_as_optimized_card_database(buf: &[u8]) -> Result<OptimizedCardDatabase, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<OptimizedCardDatabase>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `OptimizedCardDatabase` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_optimized_card_database_unchecked`.
+pub fn root_as_optimized_card_database_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<OptimizedCardDatabase<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<OptimizedCardDatabase<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `OptimizedCardDatabase` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_optimized_card_database_unchecked`.
+pub fn size_prefixed_root_as_optimized_card_database_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<OptimizedCardDatabase<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<OptimizedCardDatabase<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a OptimizedCardDatabase and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `OptimizedCardDatabase`.
+pub unsafe fn root_as_optimized_card_database_unchecked(buf: &[u8]) -> OptimizedCardDatabase {
+  flatbuffers::root_unchecked::<OptimizedCardDatabase>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed OptimizedCardDatabase and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `OptimizedCardDatabase`.
+pub unsafe fn size_prefixed_root_as_optimized_card_database_unchecked(buf: &[u8]) -> OptimizedCardDatabase {
+  flatbuffers::size_prefixed_root_unchecked::<OptimizedCardDatabase>(buf)
+}
+#[inline]
+pub fn finish_optimized_card_database_buffer<'a, 'b>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    root: flatbuffers::WIPOffset<OptimizedCardDatabase<'a>>) {
+  fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_optimized_card_database_buffer<'a, 'b>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>, root: flatbuffers::WIPOffset<OptimizedCardDatabase<'a>>) {
+  fbb.finish_size_prefixed(root, None);
+}
+}  // pub mod AlteredCards
+
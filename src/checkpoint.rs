@@ -0,0 +1,164 @@
+// On-disk page checkpoints for resumable harvests.
+// Every fetched page is written under `<checkpoint_dir>/<query key>/page_NNNN.json`
+// together with the absolute URL of the next page, so a re-run after a crash
+// replays the saved pages and continues from the last completed one. Single
+// resources such as card details are saved under `<checkpoint_dir>/resources`.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+/// A single fetched page of a collection query.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PageCheckpoint {
+    pub url: String,
+    pub members: Vec<serde_json::Value>,
+    /// Absolute URL of the next page, `None` on the last page.
+    pub next: Option<String>,
+    #[serde(default = "Utc::now")]
+    pub fetched_at_utc: DateTime<Utc>,
+}
+
+pub struct CheckpointStore {
+    dir: PathBuf,
+}
+
+/// FNV-1a hash. Unlike `DefaultHasher` its output is stable across Rust
+/// releases, so it is safe to use for names of files that outlive a run.
+pub fn stable_hash(value: &str) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in value.bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+impl CheckpointStore {
+    /// Opens the checkpoint directory, wiping it first when `fresh` is set.
+    pub fn open(dir: &str, fresh: bool) -> Result<Self, Box<dyn std::error::Error>> {
+        let dir = PathBuf::from(dir);
+        if fresh && dir.exists() {
+            println!("   > Discarding checkpoints in '{}'", dir.display());
+            fs::remove_dir_all(&dir)?;
+        }
+        fs::create_dir_all(&dir)?;
+        Ok(CheckpointStore { dir })
+    }
+
+    fn query_dir(&self, query_url: &str) -> PathBuf {
+        self.dir.join(format!("{:016x}", stable_hash(query_url)))
+    }
+
+    /// Loads the consecutive pages already saved for a query, in page order.
+    pub fn load_pages(&self, query_url: &str) -> Result<Vec<PageCheckpoint>, Box<dyn std::error::Error>> {
+        let query_dir = self.query_dir(query_url);
+        let mut pages = Vec::new();
+        loop {
+            let path = page_path(&query_dir, pages.len() + 1);
+            if !path.exists() {
+                break;
+            }
+            let page: PageCheckpoint = serde_json::from_reader(BufReader::new(File::open(&path)?))
+                .map_err(|e| format!("corrupt checkpoint '{}': {}", path.display(), e))?;
+            pages.push(page);
+        }
+        Ok(pages)
+    }
+
+    /// Saves a page.
+    pub fn save_page(
+        &self,
+        query_url: &str,
+        page_number: usize,
+        page: &PageCheckpoint,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let query_dir = self.query_dir(query_url);
+        fs::create_dir_all(&query_dir)?;
+        write_atomically(&page_path(&query_dir, page_number), page)
+    }
+
+    fn resource_path(&self, url: &str) -> PathBuf {
+        self.dir
+            .join("resources")
+            .join(format!("{:016x}.json", stable_hash(url)))
+    }
+
+    /// Loads the saved response of a single-resource request, if any.
+    pub fn load_resource(&self, url: &str) -> Result<Option<serde_json::Value>, Box<dyn std::error::Error>> {
+        let path = self.resource_path(url);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let value = serde_json::from_reader(BufReader::new(File::open(&path)?))
+            .map_err(|e| format!("corrupt checkpoint '{}': {}", path.display(), e))?;
+        Ok(Some(value))
+    }
+
+    /// Saves the response of a single-resource request, atomically like pages.
+    pub fn save_resource(&self, url: &str, value: &serde_json::Value) -> Result<(), Box<dyn std::error::Error>> {
+        let path = self.resource_path(url);
+        fs::create_dir_all(self.dir.join("resources"))?;
+        write_atomically(&path, value)
+    }
+
+    /// Removes every checkpoint once the harvest has been saved.
+    pub fn clear(&self) -> Result<(), Box<dyn std::error::Error>> {
+        if self.dir.exists() {
+            fs::remove_dir_all(&self.dir)?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes under a temporary name and renames, so that a crash mid-write never
+/// leaves a truncated checkpoint behind.
+fn write_atomically<T: Serialize>(path: &Path, value: &T) -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_path = path.with_extension("json.tmp");
+    let mut writer = BufWriter::new(File::create(&tmp_path)?);
+    serde_json::to_writer(&mut writer, value)?;
+    writer.flush()?;
+    fs::rename(&tmp_path, path)?;
+    Ok(())
+}
+
+fn page_path(query_dir: &Path, page_number: usize) -> PathBuf {
+    query_dir.join(format!("page_{:04}.json", page_number))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(url: &str, next: Option<&str>) -> PageCheckpoint {
+        PageCheckpoint {
+            url: url.to_string(),
+            members: vec![serde_json::json!({ "reference": url })],
+            next: next.map(str::to_string),
+            fetched_at_utc: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn resumes_from_the_last_consecutive_page() {
+        let dir = std::env::temp_dir().join("altered_harvester_checkpoint_resume");
+        let dir = dir.to_str().unwrap();
+        let store = CheckpointStore::open(dir, true).unwrap();
+        store.save_page("query", 1, &page("p1", Some("p2"))).unwrap();
+        store.save_page("query", 2, &page("p2", Some("p3"))).unwrap();
+        // Page 4 without page 3 is not part of the resumable run.
+        store.save_page("query", 4, &page("p4", None)).unwrap();
+
+        let pages = CheckpointStore::open(dir, false).unwrap().load_pages("query").unwrap();
+        let urls: Vec<&str> = pages.iter().map(|p| p.url.as_str()).collect();
+        assert_eq!(urls, ["p1", "p2"]);
+        assert_eq!(pages[1].next.as_deref(), Some("p3"));
+        assert!(store.load_pages("other query").unwrap().is_empty());
+
+        let fresh = CheckpointStore::open(dir, true).unwrap();
+        assert!(fresh.load_pages("query").unwrap().is_empty());
+        fresh.clear().unwrap();
+    }
+}
@@ -0,0 +1,165 @@
+// Command-line interface for the Altered data pipeline.
+// Each pipeline stage can be run on its own so that we can, for example,
+// re-run the optimizer against an existing raw harvest without hitting the API.
+
+use chrono::{DateTime, Utc};
+use clap::{Args, Parser, Subcommand};
+use std::path::PathBuf;
+
+use crate::{
+    FLATBUFFER_OUTPUT_FILENAME, OPTIMIZED_OUTPUT_FILENAME, RAW_OUTPUT_FILENAME,
+};
+
+#[derive(Parser, Debug)]
+#[command(
+    name = "altered_harvester",
+    version,
+    about = "Harvest and optimize card data for the game Altered"
+)]
+pub struct Cli {
+    /// Configuration file (TOML or JSON). Defaults to `harvester.toml` when present.
+    #[arg(short, long, global = true)]
+    pub config: Option<PathBuf>,
+
+    /// Pipeline stage to run. Runs the full pipeline when omitted.
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Run every stage in sequence: harvest, validate, optimize, assets and
+    /// derivatives (when enabled) and flatbuffer.
+    Run {
+        /// Ignore checkpoints left by an interrupted harvest and start over.
+        #[arg(long)]
+        fresh: bool,
+    },
+    /// Fetch all cards from the API and save the raw harvest.
+    Harvest {
+        /// Where to write the raw harvest. The URLs of the queries run are
+        /// saved next to it, with a `.sources.json` extension.
+        #[arg(short, long, default_value = RAW_OUTPUT_FILENAME)]
+        output: PathBuf,
+        /// Ignore checkpoints left by an interrupted harvest and start over.
+        #[arg(long)]
+        fresh: bool,
+    },
+    /// Check a raw harvest and write the validation report.
+    Validate {
+        /// Raw harvest produced by `harvest`.
+        #[arg(short, long, default_value = RAW_OUTPUT_FILENAME)]
+        input: PathBuf,
+        /// Where to write the report. Defaults to `validation.report_path`.
+        #[arg(short, long)]
+        report: Option<PathBuf>,
+    },
+    /// Validate and optimize an existing raw harvest into the lookup-table JSON format.
+    Optimize {
+        /// Raw harvest produced by `harvest`.
+        #[arg(short, long, default_value = RAW_OUTPUT_FILENAME)]
+        input: PathBuf,
+        /// Where to write the optimized JSON.
+        #[arg(short, long, default_value = OPTIMIZED_OUTPUT_FILENAME)]
+        output: PathBuf,
+        /// Leave the suspension ledger and history files untouched, e.g. when
+        /// re-optimizing an older harvest.
+        #[arg(long)]
+        no_ledger: bool,
+        /// Date of a harvest saved before its records were dated, e.g.
+        /// `2025-06-01T12:00:00Z`. Without it such a harvest leaves the
+        /// suspension ledger unchanged.
+        #[arg(long)]
+        harvested_at: Option<DateTime<Utc>>,
+    },
+    /// Download card images and assets into the local store and record them
+    /// in an optimized JSON file.
+    Assets {
+        /// Optimized JSON produced by `optimize`, updated in place.
+        #[arg(short, long, default_value = OPTIMIZED_OUTPUT_FILENAME)]
+        input: PathBuf,
+    },
+    /// Generate thumbnails and other formats of the downloaded card images and
+    /// reference their manifest from an optimized JSON file.
+    Derivatives {
+        /// Optimized JSON updated by `assets`, updated in place.
+        #[arg(short, long, default_value = OPTIMIZED_OUTPUT_FILENAME)]
+        input: PathBuf,
+    },
+    /// Convert optimized JSON into the FlatBuffer formats.
+    Flatbuffer {
+        /// Optimized JSON produced by `optimize`.
+        #[arg(short, long, default_value = OPTIMIZED_OUTPUT_FILENAME)]
+        input: PathBuf,
+        /// Where to write the FlatBuffer database.
+        #[arg(short, long, default_value = FLATBUFFER_OUTPUT_FILENAME)]
+        output: PathBuf,
+        /// Skip the v2 optimized and compressed formats.
+        #[arg(long)]
+        skip_advanced: bool,
+    },
+    /// Rebuild the suspension history from optimized JSON files of earlier runs.
+    SuspensionHistory {
+        /// Optimized JSON snapshots, in any order; their `generated_at_utc`
+        /// dates the changes.
+        #[arg(required = true)]
+        snapshots: Vec<PathBuf>,
+        /// Where to write the history.
+        #[arg(short, long, default_value = "suspensions_history.json")]
+        output: PathBuf,
+    },
+    /// List the card sets offered by the API and which ones would be harvested.
+    Sets,
+    /// Create or apply incremental delta updates.
+    #[command(subcommand)]
+    Delta(DeltaCommand),
+    /// Print a summary of a raw, optimized or FlatBuffer file.
+    Inspect(InspectArgs),
+}
+
+#[derive(Subcommand, Debug)]
+pub enum DeltaCommand {
+    /// Compute the delta between two optimized JSON files.
+    Create {
+        /// Optimized JSON of the base version.
+        #[arg(long)]
+        old: PathBuf,
+        /// Optimized JSON of the target version.
+        #[arg(long)]
+        new: PathBuf,
+        /// Version label of the base database.
+        #[arg(long)]
+        base_version: String,
+        /// Version label of the target database.
+        #[arg(long)]
+        target_version: String,
+        /// Where to write the delta package (a `.gz` copy is written alongside).
+        #[arg(short, long)]
+        output: PathBuf,
+    },
+    /// Apply a delta package to an optimized JSON file.
+    Apply {
+        /// Optimized JSON to update.
+        #[arg(long)]
+        base: PathBuf,
+        /// Delta package produced by `delta create`.
+        #[arg(long)]
+        delta: PathBuf,
+        /// Where to write the updated optimized JSON.
+        #[arg(short, long)]
+        output: PathBuf,
+    },
+}
+
+#[derive(Args, Debug)]
+pub struct InspectArgs {
+    /// File to inspect. `.fb` files are read as FlatBuffers of either layout,
+    /// anything else as JSON.
+    pub path: PathBuf,
+    /// Print the details of a single card.
+    #[arg(long)]
+    pub card: Option<String>,
+    /// Print the suspension history instead, restricted to `--card` if given.
+    #[arg(long)]
+    pub suspensions: bool,
+}
@@ -0,0 +1,583 @@
+// Pipeline configuration loaded at startup.
+// Describes which sets, rarities and locales the harvester queries so that a new
+// set can be picked up by editing `harvester.toml` instead of recompiling.
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+use crate::derivatives::DerivativeFormat;
+use crate::raw_card::RawCardMode;
+
+pub const DEFAULT_CONFIG_FILENAME: &str = "harvester.toml";
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub harvest: HarvestConfig,
+    pub validation: ValidationConfig,
+    pub assets: AssetConfig,
+    pub derivatives: DerivativeConfig,
+    pub suspensions: SuspensionConfig,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct HarvestConfig {
+    /// Root of the API, without a trailing slash.
+    pub api_base_url: String,
+    /// Locales to harvest, e.g. `fr-fr`, `en-us`. The first one is the primary
+    /// locale whose names fill the single-language fields of the outputs.
+    pub locales: Vec<String>,
+    pub items_per_page: u32,
+    /// Discover the available card sets from the API instead of relying on `sets`.
+    pub discover_sets: bool,
+    /// Card set references to harvest, one query per set. When discovery is
+    /// enabled this is an include filter; empty keeps every discovered set.
+    pub sets: Vec<String>,
+    /// Card set references never harvested, discovered or not.
+    pub exclude_sets: Vec<String>,
+    /// Rarity references to harvest. Empty harvests every rarity listed by
+    /// the `/rarities` endpoint. `UNIQUE` is subject to the `uniques` limits.
+    pub rarities: Vec<String>,
+    pub uniques: UniqueConfig,
+    pub details: DetailConfig,
+    /// Extra `key=value` filters appended to every query.
+    pub extra_filters: Vec<String>,
+    /// Whether to run the extra query that flags suspended cards.
+    pub include_suspended: bool,
+    /// Queries fetched in parallel.
+    pub workers: usize,
+    pub rate_limit: RateLimitConfig,
+    pub user_agent: String,
+    /// Directory holding page checkpoints of an unfinished harvest.
+    pub checkpoint_dir: String,
+    /// Whether card objects with fields unknown to `RawCard` are rejected
+    /// (`strict`) or kept as they are (`lenient`).
+    pub raw_card_mode: RawCardMode,
+    pub retry: RetryConfig,
+    pub cache: CacheConfig,
+}
+
+/// Global request ceiling shared by every worker.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct RateLimitConfig {
+    /// Sustained request rate; 0 disables the limit.
+    pub requests_per_second: f64,
+    /// Requests that may be sent back to back after an idle period.
+    pub burst: u32,
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        RateLimitConfig {
+            requests_per_second: 1.0,
+            burst: 1,
+        }
+    }
+}
+
+/// Retry budget applied to every request made by the harvester.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct RetryConfig {
+    /// Retries after the first attempt; 0 disables retrying.
+    pub max_retries: u32,
+    pub initial_backoff_ms: u64,
+    /// Longest computed backoff between attempts.
+    pub max_backoff_ms: u64,
+    /// Longest `Retry-After` honored; a server asking for more fails the
+    /// request rather than being retried early. `None`, the default, waits
+    /// as long as the server asks.
+    pub max_retry_after_ms: Option<u64>,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        RetryConfig {
+            max_retries: 5,
+            initial_backoff_ms: 1000,
+            max_backoff_ms: 60_000,
+            max_retry_after_ms: None,
+        }
+    }
+}
+
+impl Default for HarvestConfig {
+    fn default() -> Self {
+        HarvestConfig {
+            api_base_url: "https://api.altered.gg".to_string(),
+            locales: vec!["fr-fr".to_string()],
+            items_per_page: 36,
+            discover_sets: true,
+            sets: Vec::new(),
+            exclude_sets: Vec::new(),
+            rarities: vec!["COMMON".to_string(), "RARE".to_string()],
+            uniques: UniqueConfig::default(),
+            details: DetailConfig::default(),
+            extra_filters: Vec::new(),
+            include_suspended: true,
+            workers: 4,
+            rate_limit: RateLimitConfig::default(),
+            user_agent: "AlteredDataPipeline/1.0-Rust (for personal project)".to_string(),
+            checkpoint_dir: ".harvest_checkpoints".to_string(),
+            raw_card_mode: RawCardMode::Lenient,
+            retry: RetryConfig::default(),
+            cache: CacheConfig::default(),
+        }
+    }
+}
+
+/// Limits applied to unique cards, which are far too numerous to harvest in full.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct UniqueConfig {
+    /// Uniques kept per set and locale; `None`, the default, keeps them all.
+    pub max_per_set: Option<usize>,
+    /// Keep one unique out of `sample_rate`, chosen by a hash of the
+    /// reference so that the same uniques are kept on every run.
+    pub sample_rate: u64,
+}
+
+impl Default for UniqueConfig {
+    fn default() -> Self {
+        UniqueConfig {
+            max_per_set: None,
+            sample_rate: 1,
+        }
+    }
+}
+
+/// Optional pass fetching every card's detail resource for its effect text.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct DetailConfig {
+    pub enabled: bool,
+    /// Also fetch the details of uniques, one request per unique.
+    pub include_uniques: bool,
+}
+
+/// Response cache used for conditional requests across runs.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct CacheConfig {
+    pub enabled: bool,
+    pub dir: String,
+    /// Entries not revalidated for this long are dropped and fetched in full.
+    pub max_age_hours: u64,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        CacheConfig {
+            enabled: true,
+            dir: ".http_cache".to_string(),
+            max_age_hours: 24 * 7,
+        }
+    }
+}
+
+/// Optional stage downloading card images and assets into a local store.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct AssetConfig {
+    /// Run the stage as part of the full pipeline.
+    pub enabled: bool,
+    /// Root of the content-addressed store.
+    pub dir: String,
+    /// Also download the URLs listed in `assets`, not only `imagePath`.
+    pub include_assets: bool,
+    /// Downloads made in parallel.
+    pub workers: usize,
+    /// Request ceiling for the image host, separate from the API's.
+    pub rate_limit: RateLimitConfig,
+}
+
+impl Default for AssetConfig {
+    fn default() -> Self {
+        AssetConfig {
+            enabled: false,
+            dir: "card_assets".to_string(),
+            include_assets: true,
+            workers: 4,
+            rate_limit: RateLimitConfig {
+                requests_per_second: 10.0,
+                burst: 10,
+            },
+        }
+    }
+}
+
+/// Optional stage resizing and re-encoding the downloaded card images.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct DerivativeConfig {
+    /// Run the stage as part of the full pipeline. Needs the asset stage.
+    pub enabled: bool,
+    /// Where derivatives and the manifest are written.
+    pub dir: String,
+    /// Target widths in pixels. The aspect ratio is kept, and widths larger
+    /// than the source image are skipped rather than upscaled.
+    pub widths: Vec<u32>,
+    pub formats: Vec<DerivativeFormat>,
+    /// Images processed in parallel.
+    pub workers: usize,
+}
+
+impl Default for DerivativeConfig {
+    fn default() -> Self {
+        DerivativeConfig {
+            enabled: false,
+            dir: "card_derivatives".to_string(),
+            widths: vec![160, 320, 640],
+            formats: vec![DerivativeFormat::Webp],
+            workers: 4,
+        }
+    }
+}
+
+/// Suspension tracking across runs.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct SuspensionConfig {
+    /// Ledger remembering since when each card has been suspended.
+    pub ledger_path: String,
+    /// Where the timeline of every status change is published.
+    pub history_path: String,
+}
+
+impl Default for SuspensionConfig {
+    fn default() -> Self {
+        SuspensionConfig {
+            ledger_path: "suspension_ledger.json".to_string(),
+            history_path: "suspensions_history.json".to_string(),
+        }
+    }
+}
+
+/// Checks run on the raw harvest before it is optimized.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct ValidationConfig {
+    /// Where the JSON report is written.
+    pub report_path: String,
+    /// The pipeline stops when more errors than this are found.
+    pub max_errors: usize,
+    /// The pipeline stops when more warnings than this are found; `None`
+    /// never stops on warnings.
+    pub max_warnings: Option<usize>,
+    /// Costs above this are reported as suspicious.
+    pub max_cost: i64,
+    /// Powers above this are reported as suspicious.
+    pub max_power: i64,
+    /// Known references. Anything else is reported; empty disables the check.
+    pub factions: Vec<String>,
+    pub rarities: Vec<String>,
+    pub card_types: Vec<String>,
+}
+
+impl Default for ValidationConfig {
+    fn default() -> Self {
+        let strings = |values: &[&str]| values.iter().map(|v| v.to_string()).collect();
+        ValidationConfig {
+            report_path: "altered_validation_report.json".to_string(),
+            max_errors: 0,
+            max_warnings: None,
+            max_cost: 10,
+            max_power: 10,
+            factions: strings(&["AX", "BR", "LY", "MU", "OR", "YZ", "NE"]),
+            rarities: strings(&["COMMON", "RARE", "EXALTED", "UNIQUE"]),
+            card_types: strings(&[
+                "CHARACTER",
+                "HERO",
+                "SPELL",
+                "PERMANENT",
+                "LANDMARK_PERMANENT",
+                "EXPEDITION_PERMANENT",
+                "TOKEN",
+                "TOKEN_MANA",
+                "FOILER",
+            ]),
+        }
+    }
+}
+
+/// A single paginated collection query run by the harvester.
+#[derive(Debug, Clone)]
+pub struct HarvestQuery {
+    pub label: String,
+    pub url: String,
+    pub locale: String,
+    pub is_suspended: bool,
+    /// Stop paging once this many cards have been kept.
+    pub max_cards: Option<usize>,
+    /// Keep one card out of `sample_rate` (1 keeps every card).
+    pub sample_rate: u64,
+}
+
+pub const UNIQUE_RARITY: &str = "UNIQUE";
+
+impl Config {
+    /// Loads the configuration from `path`, or from `harvester.toml` in the
+    /// working directory when no path is given. Falls back to the built-in
+    /// defaults when no file is found. `.json` files are read as JSON, any
+    /// other as TOML. Missing keys take their default; unknown keys are
+    /// rejected so that a misspelt one is not silently ignored.
+    pub fn load(path: Option<&Path>) -> Result<Self, Box<dyn std::error::Error>> {
+        let path = match path {
+            Some(path) => path,
+            None => {
+                let default_path = Path::new(DEFAULT_CONFIG_FILENAME);
+                if !default_path.exists() {
+                    return Ok(Config::default());
+                }
+                default_path
+            }
+        };
+
+        let contents = fs::read_to_string(path)
+            .map_err(|e| format!("cannot read config '{}': {}", path.display(), e))?;
+        let config = if path.extension().is_some_and(|ext| ext == "json") {
+            serde_json::from_str(&contents)
+                .map_err(|e| format!("invalid config '{}': {}", path.display(), e))?
+        } else {
+            toml::from_str(&contents)
+                .map_err(|e| format!("invalid config '{}': {}", path.display(), e))?
+        };
+        println!("   > Loaded configuration from '{}'", path.display());
+        Ok(config)
+    }
+}
+
+impl HarvestConfig {
+    pub fn primary_locale(&self) -> &str {
+        self.locales.first().map_or("fr-fr", |l| l.as_str())
+    }
+
+    /// Applies the include and exclude filters to the discovered set references.
+    pub fn select_sets(&self, discovered: &[String]) -> Vec<String> {
+        discovered
+            .iter()
+            .filter(|set| self.sets.is_empty() || self.sets.contains(set))
+            .filter(|set| !self.exclude_sets.contains(set))
+            .cloned()
+            .collect()
+    }
+
+    /// Rarities to harvest out of those listed by the API: the configured
+    /// ones, or all of them when none is configured.
+    pub fn select_rarities(&self, available: &[String]) -> Vec<String> {
+        if self.rarities.is_empty() {
+            available.to_vec()
+        } else {
+            self.rarities.clone()
+        }
+    }
+
+    /// Listing endpoint used for rarity discovery.
+    pub fn rarities_url(&self) -> String {
+        format!(
+            "{}/rarities?locale={}",
+            self.api_base_url,
+            self.primary_locale()
+        )
+    }
+
+    /// URL of a card's detail resource, given its `@id` path.
+    pub fn detail_url(&self, path: &str, locale: &str) -> String {
+        format!("{}{}?locale={}", self.api_base_url, path, locale)
+    }
+
+    /// Listing endpoint used for set discovery.
+    pub fn card_sets_url(&self) -> String {
+        format!(
+            "{}/card_sets?locale={}",
+            self.api_base_url,
+            self.primary_locale()
+        )
+    }
+
+    /// Builds the list of collection queries to run for the given sets and
+    /// rarities, one per set and locale. Uniques get a query of their own so
+    /// that the `uniques` limits only apply to them. Suspension does not
+    /// depend on the locale, so the suspended-cards query only runs for the
+    /// primary locale. It comes last, but its position does not matter:
+    /// `collect_evidence` ORs the `listed` and `flagged` evidence of every
+    /// record of a card, whatever their order.
+    pub fn queries(&self, sets: &[String], rarities: &[String]) -> Vec<HarvestQuery> {
+        let (uniques, others): (Vec<String>, Vec<String>) = rarities
+            .iter()
+            .cloned()
+            .partition(|rarity| rarity == UNIQUE_RARITY);
+
+        let mut queries = Vec::new();
+        for locale in &self.locales {
+            for set in sets {
+                let set_filter = format!("cardSet[]={}", set);
+                if !others.is_empty() {
+                    queries.push(HarvestQuery {
+                        label: format!("{} [{}]", set, locale),
+                        url: self.query_url(locale, std::slice::from_ref(&set_filter), &others),
+                        locale: locale.clone(),
+                        is_suspended: false,
+                        max_cards: None,
+                        sample_rate: 1,
+                    });
+                }
+                if !uniques.is_empty() {
+                    queries.push(HarvestQuery {
+                        label: format!("{} uniques [{}]", set, locale),
+                        url: self.query_url(locale, &[set_filter], &uniques),
+                        locale: locale.clone(),
+                        is_suspended: false,
+                        max_cards: self.uniques.max_per_set,
+                        sample_rate: self.uniques.sample_rate.max(1),
+                    });
+                }
+            }
+        }
+
+        if self.include_suspended {
+            let locale = self.primary_locale();
+            queries.push(HarvestQuery {
+                label: format!("suspended [{}]", locale),
+                url: self.query_url(locale, &["isSuspended=true".to_string()], rarities),
+                locale: locale.to_string(),
+                is_suspended: true,
+                max_cards: None,
+                sample_rate: 1,
+            });
+        }
+        queries
+    }
+
+    fn query_url(&self, locale: &str, filters: &[String], rarities: &[String]) -> String {
+        let mut url = format!(
+            "{}/cards?itemsPerPage={}&locale={}",
+            self.api_base_url, self.items_per_page, locale
+        );
+        for filter in filters {
+            url.push('&');
+            url.push_str(filter);
+        }
+        for rarity in rarities {
+            url.push_str("&rarity[]=");
+            url.push_str(rarity);
+        }
+        for filter in &self.extra_filters {
+            url.push('&');
+            url.push_str(filter);
+        }
+        url
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    /// Writes `contents` to `name` in a scratch directory of this test run.
+    fn config_file(name: &str, contents: &str) -> PathBuf {
+        let dir = std::env::temp_dir()
+            .join("altered_harvester_tests")
+            .join(format!("config_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(name);
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn loads_the_shipped_configuration() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(DEFAULT_CONFIG_FILENAME);
+        let config = Config::load(Some(&path)).unwrap();
+        assert!(!config.harvest.locales.is_empty());
+        assert!(config.harvest.api_base_url.starts_with("https://"));
+    }
+
+    #[test]
+    fn missing_keys_take_their_default() {
+        let path = config_file(
+            "partial.toml",
+            "[harvest]\nlocales = [\"en-us\", \"fr-fr\"]\nsets = [\"CORE\"]\n",
+        );
+        let config = Config::load(Some(&path)).unwrap();
+        let defaults = Config::default();
+
+        assert_eq!(config.harvest.locales, ["en-us", "fr-fr"]);
+        assert_eq!(config.harvest.primary_locale(), "en-us");
+        assert_eq!(config.harvest.sets, ["CORE"]);
+        assert_eq!(config.harvest.api_base_url, defaults.harvest.api_base_url);
+        assert_eq!(config.harvest.items_per_page, defaults.harvest.items_per_page);
+        assert_eq!(config.harvest.rate_limit.burst, defaults.harvest.rate_limit.burst);
+        assert_eq!(config.suspensions.ledger_path, defaults.suspensions.ledger_path);
+    }
+
+    #[test]
+    fn rejects_unknown_keys() {
+        let path = config_file("typo.toml", "[harvest]\nlocale = \"en-us\"\n");
+        let error = Config::load(Some(&path)).unwrap_err().to_string();
+        assert!(error.contains("locale"), "{}", error);
+
+        let path = config_file("section.toml", "[harvests]\nsets = [\"CORE\"]\n");
+        assert!(Config::load(Some(&path)).is_err());
+    }
+
+    #[test]
+    fn uniques_are_uncapped_unless_configured() {
+        let path = config_file("uniques.toml", "[harvest.uniques]\nsample_rate = 4\n");
+        let config = Config::load(Some(&path)).unwrap();
+        assert_eq!(config.harvest.uniques.max_per_set, None);
+        assert_eq!(config.harvest.uniques.sample_rate, 4);
+
+        let path = config_file("capped.toml", "[harvest.uniques]\nmax_per_set = 10\n");
+        let config = Config::load(Some(&path)).unwrap();
+        assert_eq!(config.harvest.uniques.max_per_set, Some(10));
+    }
+
+    #[test]
+    fn uniques_get_a_limited_query_of_their_own() {
+        let config = HarvestConfig {
+            locales: vec!["fr-fr".to_string()],
+            include_suspended: false,
+            uniques: UniqueConfig {
+                max_per_set: Some(10),
+                sample_rate: 3,
+            },
+            ..HarvestConfig::default()
+        };
+        let sets = ["CORE".to_string(), "ALIZE".to_string()];
+        let rarities = ["COMMON".to_string(), "RARE".to_string(), UNIQUE_RARITY.to_string()];
+        let queries = config.queries(&sets, &rarities);
+
+        assert_eq!(queries.len(), 4);
+        let (uniques, others): (Vec<_>, Vec<_>) =
+            queries.iter().partition(|query| query.url.contains("rarity[]=UNIQUE"));
+        assert_eq!(uniques.len(), 2);
+        for query in uniques {
+            assert!(!query.url.contains("rarity[]=COMMON"), "{}", query.url);
+            assert_eq!(query.max_cards, Some(10));
+            assert_eq!(query.sample_rate, 3);
+        }
+        for query in others {
+            assert!(query.url.contains("rarity[]=COMMON&rarity[]=RARE"), "{}", query.url);
+            assert_eq!(query.max_cards, None);
+            assert_eq!(query.sample_rate, 1);
+        }
+    }
+
+    #[test]
+    fn reads_json_by_extension() {
+        let json = r#"{"harvest": {"sets": ["ALIZE"], "items_per_page": 50}}"#;
+        let config = Config::load(Some(&config_file("harvester.json", json))).unwrap();
+        assert_eq!(config.harvest.sets, ["ALIZE"]);
+        assert_eq!(config.harvest.items_per_page, 50);
+
+        // Anything but `.json` is TOML.
+        assert!(Config::load(Some(&config_file("harvester.conf", json))).is_err());
+        let toml = "[harvest]\nsets = [\"ALIZE\"]\n";
+        assert!(Config::load(Some(&config_file("harvester.conf", toml))).is_ok());
+    }
+}
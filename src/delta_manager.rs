@@ -3,19 +3,17 @@
 
 use std::collections::{HashMap, HashSet};
 use std::fs::{File, metadata};
-use std::io::{BufWriter, Write, BufReader, Read};
-use std::path::Path;
+use std::io::{BufWriter, BufReader};
 use serde::{Serialize, Deserialize};
 use chrono::{DateTime, Utc};
 
-use crate::{OptimizedData, OptimizedCard};
-use crate::optimizer_v2::{generate_numeric_id, OptimizedCard as OptimizedCardV2};
+use altered_harvester::optimizer::{LookupTables, OptimizedCard, OptimizedData};
 
 // Delta operation types
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum DeltaOperation {
-    Add(OptimizedCard),
-    Modify(OptimizedCard),
+    Add { reference: String, card: OptimizedCard },
+    Modify { reference: String, card: OptimizedCard },
     Remove(String), // Card reference
 }
 
@@ -26,6 +24,9 @@ pub struct DeltaPackage {
     pub target_version: String,
     pub generated_at: DateTime<Utc>,
     pub operations: Vec<DeltaOperation>,
+    // Lookup tables of the target version; absent from older deltas
+    #[serde(default)]
+    pub lookup_tables: Option<LookupTables>,
     pub checksum: String,
     pub statistics: DeltaStatistics,
 }
@@ -94,7 +95,10 @@ impl DeltaManager {
         // Find added cards
         for reference in new_keys.difference(&old_keys) {
             if let Some(card) = new_cards.get(*reference) {
-                operations.push(DeltaOperation::Add((*card).clone()));
+                operations.push(DeltaOperation::Add {
+                    reference: (*reference).clone(),
+                    card: (*card).clone(),
+                });
                 stats.cards_added += 1;
             }
         }
@@ -109,7 +113,10 @@ impl DeltaManager {
         for reference in old_keys.intersection(&new_keys) {
             if let (Some(old_card), Some(new_card)) = (old_cards.get(*reference), new_cards.get(*reference)) {
                 if self.card_differs(old_card, new_card) {
-                    operations.push(DeltaOperation::Modify((*new_card).clone()));
+                    operations.push(DeltaOperation::Modify {
+                        reference: (*reference).clone(),
+                        card: (*new_card).clone(),
+                    });
                     stats.cards_modified += 1;
                 }
             }
@@ -127,6 +134,7 @@ impl DeltaManager {
             target_version: target_version.to_string(),
             generated_at: Utc::now(),
             operations,
+            lookup_tables: Some(new_data.lookup_tables.clone()),
             checksum,
             statistics: stats,
         };
@@ -147,10 +155,22 @@ impl DeltaManager {
         old_card.power.o != new_card.power.o ||
         old_card.power.f != new_card.power.f ||
         old_card.image_path != new_card.image_path ||
-        old_card.qr_url != new_card.qr_url
+        old_card.qr_url != new_card.qr_url ||
+        old_card.names != new_card.names ||
+        old_card.image_paths != new_card.image_paths ||
+        old_card.id != new_card.id ||
+        old_card.set_ref != new_card.set_ref ||
+        old_card.sub_type_refs != new_card.sub_type_refs ||
+        old_card.assets != new_card.assets ||
+        old_card.raw_is_suspended != new_card.raw_is_suspended ||
+        old_card.suspended_since != new_card.suspended_since ||
+        old_card.family_ref != new_card.family_ref ||
+        old_card.texts != new_card.texts ||
+        old_card.local_files != new_card.local_files
     }
 
-    // Apply delta to existing database
+    // Apply delta to existing database. The lookup tables of the target
+    // version replace the base ones; deltas without them keep the base tables.
     pub fn apply_delta(
         &self,
         base_data: &mut OptimizedData,
@@ -159,17 +179,17 @@ impl DeltaManager {
         
         for operation in &delta.operations {
             match operation {
-                DeltaOperation::Add(card) => {
-                    base_data.cards.insert(self.get_card_reference(card), card.clone());
-                }
-                DeltaOperation::Modify(card) => {
-                    base_data.cards.insert(self.get_card_reference(card), card.clone());
+                DeltaOperation::Add { reference, card } | DeltaOperation::Modify { reference, card } => {
+                    base_data.cards.insert(reference.clone(), card.clone());
                 }
                 DeltaOperation::Remove(reference) => {
                     base_data.cards.remove(reference);
                 }
             }
         }
+        if let Some(lookup_tables) = &delta.lookup_tables {
+            base_data.lookup_tables = lookup_tables.clone();
+        }
 
         // Update metadata
         base_data.meta.total_cards = base_data.cards.len();
@@ -178,13 +198,6 @@ impl DeltaManager {
         Ok(())
     }
 
-    // Helper to extract card reference (would need to be implemented based on card structure)
-    fn get_card_reference(&self, card: &OptimizedCard) -> String {
-        // This is a placeholder - in a real implementation, you'd need to store
-        // the reference in the OptimizedCard struct or calculate it consistently
-        format!("CARD_{}", generate_numeric_id(&card.name))
-    }
-
     // Save delta package to file
     pub fn save_delta(
         &self,
@@ -267,37 +280,3 @@ pub fn calculate_delta_size_reduction(
         1.0 - (delta_size as f64 / full_database_size as f64)
     }
 }
-
-// Create sample delta for demonstration
-pub fn create_sample_delta() -> DeltaPackage {
-    use crate::{OptimizedCard, LocalPowerStats};
-    
-    let sample_card = OptimizedCard {
-        name: "Sample New Card".to_string(),
-        type_ref: "HERO".to_string(),
-        faction_ref: "AX".to_string(),
-        rarity_ref: "COMMON".to_string(),
-        image_path: "/path/to/image.jpg".to_string(),
-        qr_url: "https://example.com/qr".to_string(),
-        main_cost: 3,
-        recall_cost: 1,
-        is_suspended: false,
-        power: LocalPowerStats { m: 2, o: 1, f: 3 },
-    };
-
-    DeltaPackage {
-        base_version: "2.0.0".to_string(),
-        target_version: "2.0.1".to_string(),
-        generated_at: Utc::now(),
-        operations: vec![DeltaOperation::Add(sample_card)],
-        checksum: "sample_checksum".to_string(),
-        statistics: DeltaStatistics {
-            total_operations: 1,
-            cards_added: 1,
-            cards_modified: 0,
-            cards_removed: 0,
-            size_bytes: 1024,
-            compression_ratio: 0.75,
-        },
-    }
-}
\ No newline at end of file
@@ -0,0 +1,228 @@
+// Image derivative stage.
+// Resizes the locally stored image of every card to the configured widths and
+// encodes each size in the configured formats, e.g. WebP thumbnails for the
+// mobile app. Outputs go to `<dir>/<card reference>/<width>w.<ext>` and are
+// listed in `<dir>/manifest.json`. Cards whose source image is unchanged since
+// the previous manifest are not processed again.
+
+use chrono::{DateTime, Utc};
+use image::imageops::FilterType;
+use image::{DynamicImage, GenericImageView, ImageFormat};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use crate::asset_store::LocalFile;
+use crate::config::DerivativeConfig;
+use crate::harvester::run_workers;
+
+pub const MANIFEST_FILENAME: &str = "manifest.json";
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum DerivativeFormat {
+    /// Lossless WebP.
+    Webp,
+    Avif,
+    Jpeg,
+    Png,
+}
+
+impl DerivativeFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            DerivativeFormat::Webp => "webp",
+            DerivativeFormat::Avif => "avif",
+            DerivativeFormat::Jpeg => "jpg",
+            DerivativeFormat::Png => "png",
+        }
+    }
+
+    fn image_format(self) -> ImageFormat {
+        match self {
+            DerivativeFormat::Webp => ImageFormat::WebP,
+            DerivativeFormat::Avif => ImageFormat::Avif,
+            DerivativeFormat::Jpeg => ImageFormat::Jpeg,
+            DerivativeFormat::Png => ImageFormat::Png,
+        }
+    }
+}
+
+/// One resized and encoded copy of a card image.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Derivative {
+    pub width: u32,
+    pub height: u32,
+    pub format: DerivativeFormat,
+    /// Path relative to the derivatives directory, with `/` separators.
+    pub path: String,
+    pub size: u64,
+}
+
+/// Derivatives of one card image.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CardDerivatives {
+    pub image_path: String,
+    /// Hash of the source file, used to skip unchanged images.
+    pub source_sha256: String,
+    pub derivatives: Vec<Derivative>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DerivativeManifest {
+    pub generated_at_utc: DateTime<Utc>,
+    pub widths: Vec<u32>,
+    pub formats: Vec<DerivativeFormat>,
+    /// Derivatives keyed by card reference.
+    pub cards: BTreeMap<String, CardDerivatives>,
+}
+
+impl DerivativeManifest {
+    pub fn load(path: &Path) -> Result<DerivativeManifest, Box<dyn std::error::Error>> {
+        let file = File::open(path)
+            .map_err(|e| format!("cannot open derivative manifest '{}': {}", path.display(), e))?;
+        Ok(serde_json::from_reader(BufReader::new(file))?)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        serde_json::to_writer_pretty(&mut writer, self)?;
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+/// A card image to process: the card reference, its `image_path` and the
+/// downloaded copy of that image in the asset store.
+pub struct SourceImage {
+    pub reference: String,
+    pub image_path: String,
+    pub file: LocalFile,
+}
+
+/// Generates the derivatives of every source image found in `store_dir`,
+/// writes the manifest and returns its path.
+pub fn generate_derivatives(
+    config: &DerivativeConfig,
+    store_dir: &Path,
+    sources: &[SourceImage],
+) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let dir = PathBuf::from(&config.dir);
+    fs::create_dir_all(&dir)?;
+    let manifest_path = dir.join(MANIFEST_FILENAME);
+    let previous = if manifest_path.exists() {
+        Some(DerivativeManifest::load(&manifest_path)?)
+    } else {
+        None
+    };
+    let is_up_to_date = |source: &SourceImage| -> Option<CardDerivatives> {
+        let previous = previous.as_ref()?;
+        if previous.widths != config.widths || previous.formats != config.formats {
+            return None;
+        }
+        let entry = previous.cards.get(&source.reference)?;
+        let unchanged = entry.source_sha256 == source.file.sha256
+            && entry.image_path == source.image_path
+            && entry.derivatives.iter().all(|d| dir.join(&d.path).is_file());
+        unchanged.then(|| entry.clone())
+    };
+
+    let pending: Vec<&SourceImage> = sources.iter().filter(|s| is_up_to_date(s).is_none()).collect();
+    println!(
+        "   > {} card image(s), {} up to date, {} to process",
+        sources.len(),
+        sources.len() - pending.len(),
+        pending.len()
+    );
+    let worker_count = config.workers.clamp(1, pending.len().max(1));
+    let processed = run_workers(worker_count, &pending, |source| {
+        derive_image(config, &dir, store_dir, source)
+    })?;
+
+    let mut cards = BTreeMap::new();
+    for source in sources {
+        if let Some(entry) = is_up_to_date(source) {
+            cards.insert(source.reference.clone(), entry);
+        }
+    }
+    let mut skipped = 0;
+    for (source, entry) in pending.iter().zip(processed) {
+        match entry {
+            Some(entry) => {
+                cards.insert(source.reference.clone(), entry);
+            }
+            None => skipped += 1,
+        }
+    }
+    if skipped > 0 {
+        println!("   > ⚠️ {} image(s) could not be decoded and were skipped", skipped);
+    }
+
+    let manifest = DerivativeManifest {
+        generated_at_utc: Utc::now(),
+        widths: config.widths.clone(),
+        formats: config.formats.clone(),
+        cards,
+    };
+    manifest.save(&manifest_path)?;
+    println!(
+        "   > ✅ Derivatives of {} card(s) listed in '{}'",
+        manifest.cards.len(),
+        manifest_path.display()
+    );
+    Ok(manifest_path)
+}
+
+/// Resizes and encodes one image. An image that cannot be decoded is
+/// reported and yields `None` instead of failing the whole stage.
+fn derive_image(
+    config: &DerivativeConfig,
+    dir: &Path,
+    store_dir: &Path,
+    source: &SourceImage,
+) -> Result<Option<CardDerivatives>, Box<dyn std::error::Error>> {
+    let image = match image::open(store_dir.join(&source.file.path)) {
+        Ok(image) => image,
+        Err(e) => {
+            eprintln!("     ! Cannot decode the image of {}: {}", source.reference, e);
+            return Ok(None);
+        }
+    };
+    let card_dir = dir.join(&source.reference);
+    fs::create_dir_all(&card_dir)?;
+
+    let mut derivatives = Vec::new();
+    for &width in &config.widths {
+        if width == 0 || width > image.width() {
+            continue;
+        }
+        let resized = image.resize(width, u32::MAX, FilterType::Lanczos3);
+        for &format in &config.formats {
+            // JPEG has no alpha channel.
+            let encoded = match format {
+                DerivativeFormat::Jpeg => DynamicImage::ImageRgb8(resized.to_rgb8()),
+                _ => resized.clone(),
+            };
+            let file_name = format!("{}w.{}", width, format.extension());
+            let path = card_dir.join(&file_name);
+            encoded
+                .save_with_format(&path, format.image_format())
+                .map_err(|e| format!("cannot write '{}': {}", path.display(), e))?;
+            let (width, height) = encoded.dimensions();
+            derivatives.push(Derivative {
+                width,
+                height,
+                format,
+                path: format!("{}/{}", source.reference, file_name),
+                size: fs::metadata(&path)?.len(),
+            });
+        }
+    }
+    Ok(Some(CardDerivatives {
+        image_path: source.image_path.clone(),
+        source_sha256: source.file.sha256.clone(),
+        derivatives,
+    }))
+}
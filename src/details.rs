@@ -0,0 +1,154 @@
+// Detail pass of the harvester.
+// The collection endpoint only returns stats, so when enabled every harvested
+// card's detail resource (`/cards/ALT_...`) is fetched once per locale for its
+// effect text, keywords and flavor. Requests go through the same fetcher as
+// the pages, sharing its rate limit and response cache, and every response is
+// checkpointed so an interrupted pass resumes where it stopped.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+use crate::checkpoint::CheckpointStore;
+use crate::config::{HarvestConfig, UNIQUE_RARITY};
+use crate::fetcher::Fetcher;
+use crate::harvester::{run_workers, HarvestedCard};
+use crate::raw_card::{RawCard, RawCardMode};
+
+/// Text of a card in one locale.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct CardDetail {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub main_effect: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub echo_effect: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub flavor: Option<String>,
+    /// Keywords marked up as `[[Keyword]]` in the effects, in order of appearance.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub keywords: Vec<String>,
+}
+
+impl CardDetail {
+    pub fn from_raw(card: &RawCard) -> CardDetail {
+        let elements = &card.elements;
+        let mut keywords = Vec::new();
+        for effect in [&elements.main_effect, &elements.echo_effect].into_iter().flatten() {
+            for keyword in extract_keywords(effect) {
+                if !keywords.contains(&keyword) {
+                    keywords.push(keyword);
+                }
+            }
+        }
+        CardDetail {
+            main_effect: non_empty(&elements.main_effect),
+            echo_effect: non_empty(&elements.echo_effect),
+            flavor: non_empty(&elements.flavor_text),
+            keywords,
+        }
+    }
+}
+
+fn non_empty(text: &Option<String>) -> Option<String> {
+    text.as_ref()
+        .map(|t| t.trim())
+        .filter(|t| !t.is_empty())
+        .map(str::to_string)
+}
+
+/// Returns the `[[...]]` keywords of an effect text.
+pub fn extract_keywords(text: &str) -> Vec<String> {
+    let mut keywords = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find("[[") {
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("]]") else {
+            break;
+        };
+        let keyword = after[..end].trim();
+        if !keyword.is_empty() {
+            keywords.push(keyword.to_string());
+        }
+        rest = &after[end + 2..];
+    }
+    keywords
+}
+
+/// A detail resource to fetch.
+struct DetailTarget {
+    reference: String,
+    locale: String,
+    url: String,
+}
+
+/// Fetches the details of every harvested card and attaches them to the cards.
+pub fn harvest_details(
+    config: &HarvestConfig,
+    fetcher: &Fetcher,
+    checkpoints: &CheckpointStore,
+    cards: &mut [HarvestedCard],
+) -> Result<(), Box<dyn std::error::Error>> {
+    // A card returned by several queries is fetched once per locale.
+    let mut seen = HashSet::new();
+    let mut targets = Vec::new();
+    for card in cards.iter() {
+        let raw_card = &card.card_data;
+        if raw_card.rarity.reference == UNIQUE_RARITY && !config.details.include_uniques {
+            continue;
+        }
+        if seen.insert((raw_card.reference.as_str(), card.locale.as_str())) {
+            let path = raw_card
+                .iri
+                .clone()
+                .unwrap_or_else(|| format!("/cards/{}", raw_card.reference));
+            targets.push(DetailTarget {
+                reference: raw_card.reference.clone(),
+                locale: card.locale.clone(),
+                url: config.detail_url(&path, &card.locale),
+            });
+        }
+    }
+
+    let worker_count = config.workers.clamp(1, targets.len().max(1));
+    println!(
+        "   > Fetching {} card details with {} worker(s)",
+        targets.len(),
+        worker_count
+    );
+    let details = run_workers(worker_count, &targets, |target| {
+        fetch_detail(fetcher, checkpoints, target)
+    })?;
+
+    let by_card: HashMap<(&str, &str), &CardDetail> = targets
+        .iter()
+        .zip(&details)
+        .map(|(target, detail)| ((target.reference.as_str(), target.locale.as_str()), detail))
+        .collect();
+    for card in cards.iter_mut() {
+        let key = (card.card_data.reference.as_str(), card.locale.as_str());
+        card.details = by_card.get(&key).map(|detail| (*detail).clone());
+    }
+    println!(
+        "   > Card details complete: {} with effect text",
+        details.iter().filter(|d| d.main_effect.is_some()).count()
+    );
+    Ok(())
+}
+
+fn fetch_detail(
+    fetcher: &Fetcher,
+    checkpoints: &CheckpointStore,
+    target: &DetailTarget,
+) -> Result<CardDetail, Box<dyn std::error::Error>> {
+    let value = match checkpoints.load_resource(&target.url)? {
+        Some(value) => value,
+        None => {
+            let (value, _): (serde_json::Value, _) = fetcher.get_json(&target.url)?;
+            checkpoints.save_resource(&target.url, &value)?;
+            value
+        }
+    };
+    // The detail resource carries more fields than the collection, so it is
+    // always read leniently.
+    let card = RawCard::parse(value, RawCardMode::Lenient)?;
+    Ok(CardDetail::from_raw(&card))
+}
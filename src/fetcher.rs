@@ -0,0 +1,335 @@
+// HTTP fetching for the harvester.
+// Wraps the blocking client with per-request retries: exponential backoff with
+// jitter for transient failures, honoring `Retry-After` on 429/503 responses
+// up to the configured maximum backoff.
+// Every attempt, retries included, first takes a token from the shared rate
+// limiter. When the response cache is enabled, requests are made conditional on the
+// cached validators and a 304 reuses the cached body.
+
+use chrono::{DateTime, Utc};
+use reqwest::blocking::{Client, Response};
+use reqwest::header::{
+    HeaderName, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED, RETRY_AFTER,
+};
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+use std::collections::hash_map::RandomState;
+use std::fmt;
+use std::hash::{BuildHasher, Hasher};
+use std::thread::sleep;
+use std::time::Duration;
+use url::Url;
+
+use crate::config::{HarvestConfig, RetryConfig};
+use crate::http_cache::{CacheEntry, HttpCache};
+use crate::rate_limiter::RateLimiter;
+
+/// Error returned once a request has failed for good.
+#[derive(Debug)]
+pub struct FetchError {
+    pub url: String,
+    pub attempts: u32,
+    pub reason: String,
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "GET {} failed after {} attempt(s): {}",
+            self.url, self.attempts, self.reason
+        )
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+/// Outcome of a single attempt.
+enum Attempt<T> {
+    Done(T),
+    /// Transient failure; retry after the given delay if the budget allows.
+    Retry { reason: String, retry_after: Option<Duration> },
+    Fail(String),
+}
+
+pub struct Fetcher {
+    client: Client,
+    retry: RetryConfig,
+    cache: Option<HttpCache>,
+    limiter: RateLimiter,
+}
+
+impl Fetcher {
+    pub fn new(config: &HarvestConfig) -> Result<Self, Box<dyn std::error::Error>> {
+        let client = Client::builder().user_agent(&config.user_agent).build()?;
+        Ok(Fetcher {
+            client,
+            retry: config.retry.clone(),
+            cache: HttpCache::open(&config.cache)?,
+            limiter: RateLimiter::new(&config.rate_limit),
+        })
+    }
+
+    /// Fetches `url` and decodes the JSON body, retrying transient failures.
+    /// Returns the decoded body with the final response URL, against which
+    /// relative `hydra:next` links must be resolved.
+    pub fn get_json<T: DeserializeOwned>(&self, url: &str) -> Result<(T, Url), FetchError> {
+        self.with_retries(url, || self.attempt(url))
+    }
+
+    /// Fetches `url` and returns the raw body, retrying transient failures.
+    /// Binary downloads bypass the response cache.
+    pub fn get_bytes(&self, url: &str) -> Result<Vec<u8>, FetchError> {
+        self.with_retries(url, || self.attempt_bytes(url))
+    }
+
+    fn with_retries<T>(&self, url: &str, attempt: impl Fn() -> Attempt<T>) -> Result<T, FetchError> {
+        let mut attempts = 0;
+        loop {
+            attempts += 1;
+            let (reason, retry_after) = match attempt() {
+                Attempt::Done(result) => return Ok(result),
+                Attempt::Fail(reason) => {
+                    return Err(FetchError {
+                        url: url.to_string(),
+                        attempts,
+                        reason,
+                    })
+                }
+                Attempt::Retry { reason, retry_after } => (reason, retry_after),
+            };
+
+            if attempts > self.retry.max_retries {
+                return Err(FetchError {
+                    url: url.to_string(),
+                    attempts,
+                    reason,
+                });
+            }
+
+            if let (Some(delay), Some(max_ms)) = (retry_after, self.retry.max_retry_after_ms) {
+                if delay > Duration::from_millis(max_ms) {
+                    return Err(FetchError {
+                        url: url.to_string(),
+                        attempts,
+                        reason: format!(
+                            "{} with Retry-After of {}s, beyond max_retry_after_ms",
+                            reason,
+                            delay.as_secs()
+                        ),
+                    });
+                }
+            }
+            let delay = retry_after.unwrap_or_else(|| self.backoff(attempts));
+            println!(
+                "     ! {} - retrying in {:.1}s ({}/{})",
+                reason,
+                delay.as_secs_f64(),
+                attempts,
+                self.retry.max_retries
+            );
+            sleep(delay);
+        }
+    }
+
+    fn attempt<T: DeserializeOwned>(&self, url: &str) -> Attempt<(T, Url)> {
+        let cached = self.cache.as_ref().and_then(|cache| cache.lookup(url));
+        self.limiter.acquire();
+        let mut request = self.client.get(url);
+        if let Some(entry) = &cached {
+            if let Some(etag) = &entry.etag {
+                request = request.header(IF_NONE_MATCH, etag);
+            }
+            if let Some(last_modified) = &entry.last_modified {
+                request = request.header(IF_MODIFIED_SINCE, last_modified);
+            }
+        }
+
+        let response = match request.send() {
+            Ok(response) => response,
+            Err(e) => return Attempt::Retry { reason: e.to_string(), retry_after: None },
+        };
+
+        let status = response.status();
+        let response_url = response.url().clone();
+        if status == StatusCode::NOT_MODIFIED {
+            return match cached {
+                Some(mut entry) => {
+                    println!("     = Not modified, reusing cached response");
+                    entry.validated_at_utc = Utc::now();
+                    self.store_in_cache(&entry);
+                    decode(&entry.body, response_url)
+                }
+                None => Attempt::Fail("HTTP 304 for a request without cached validators".to_string()),
+            };
+        }
+        if !status.is_success() {
+            return failed_status(&response);
+        }
+
+        let header = |name: HeaderName| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_string)
+        };
+        let etag = header(ETAG);
+        let last_modified = header(LAST_MODIFIED);
+        let body = match response.text() {
+            Ok(body) => body,
+            Err(e) => return Attempt::Retry { reason: e.to_string(), retry_after: None },
+        };
+
+        let result = decode(&body, response_url);
+        if matches!(result, Attempt::Done(_)) && (etag.is_some() || last_modified.is_some()) {
+            self.store_in_cache(&CacheEntry {
+                url: url.to_string(),
+                etag,
+                last_modified,
+                validated_at_utc: Utc::now(),
+                body,
+            });
+        }
+        result
+    }
+
+    fn attempt_bytes(&self, url: &str) -> Attempt<Vec<u8>> {
+        self.limiter.acquire();
+        let response = match self.client.get(url).send() {
+            Ok(response) => response,
+            Err(e) => return Attempt::Retry { reason: e.to_string(), retry_after: None },
+        };
+        if !response.status().is_success() {
+            return failed_status(&response);
+        }
+        match response.bytes() {
+            Ok(bytes) => Attempt::Done(bytes.to_vec()),
+            Err(e) => Attempt::Retry { reason: e.to_string(), retry_after: None },
+        }
+    }
+
+    /// Cache failures only cost us a full download next time, so they are
+    /// reported but never fail the harvest.
+    fn store_in_cache(&self, entry: &CacheEntry) {
+        if let Some(cache) = &self.cache {
+            if let Err(e) = cache.store(entry) {
+                eprintln!("     ! Could not cache response for {}: {}", entry.url, e);
+            }
+        }
+    }
+
+    /// Exponential backoff for the given attempt number, with jitter in
+    /// `[delay / 2, delay]` so that parallel clients do not retry in lockstep.
+    fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(16);
+        let delay_ms = self
+            .retry
+            .initial_backoff_ms
+            .saturating_mul(1u64 << exponent)
+            .min(self.retry.max_backoff_ms);
+        let jitter = RandomState::new().build_hasher().finish() % (delay_ms / 2 + 1);
+        Duration::from_millis(delay_ms - jitter)
+    }
+}
+
+fn decode<T: DeserializeOwned>(body: &str, response_url: Url) -> Attempt<(T, Url)> {
+    match serde_json::from_str(body) {
+        Ok(value) => Attempt::Done((value, response_url)),
+        Err(e) => Attempt::Fail(format!("invalid JSON body: {}", e)),
+    }
+}
+
+fn failed_status<T>(response: &Response) -> Attempt<T> {
+    let status = response.status();
+    let reason = format!("HTTP {}", status);
+    if is_retryable(status) {
+        Attempt::Retry {
+            reason,
+            retry_after: retry_after(response),
+        }
+    } else {
+        Attempt::Fail(reason)
+    }
+}
+
+fn is_retryable(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+/// Parses a `Retry-After` header given either in seconds or as an HTTP date.
+fn retry_after(response: &Response) -> Option<Duration> {
+    let value = response.headers().get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = DateTime::parse_from_rfc2822(value).ok()?.with_timezone(&Utc);
+    (date - Utc::now()).to_std().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::CacheConfig;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// Answers one connection per response, in order, and returns the
+    /// `If-None-Match` header of every request received.
+    fn serve(responses: Vec<&'static str>) -> (String, thread::JoinHandle<Vec<Option<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/cards", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            let mut validators = Vec::new();
+            for response in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut if_none_match = None;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim_end().is_empty() {
+                        break;
+                    }
+                    if let Some((name, value)) = line.split_once(':') {
+                        if name.eq_ignore_ascii_case("if-none-match") {
+                            if_none_match = Some(value.trim().to_string());
+                        }
+                    }
+                }
+                validators.push(if_none_match);
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+            validators
+        });
+        (url, server)
+    }
+
+    #[test]
+    fn reuses_the_cached_body_on_not_modified() {
+        let (url, server) = serve(vec![
+            "HTTP/1.1 200 OK\r\nETag: \"v1\"\r\nContent-Length: 9\r\nConnection: close\r\n\r\n{\"n\": 42}",
+            "HTTP/1.1 304 Not Modified\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+        ]);
+        let cache_dir = std::env::temp_dir().join("altered_harvester_fetcher_304");
+        let _ = std::fs::remove_dir_all(&cache_dir);
+        let config = HarvestConfig {
+            cache: CacheConfig {
+                enabled: true,
+                dir: cache_dir.to_string_lossy().into_owned(),
+                ..CacheConfig::default()
+            },
+            ..HarvestConfig::default()
+        };
+        let fetcher = Fetcher::new(&config).unwrap();
+
+        let (first, _): (serde_json::Value, _) = fetcher.get_json(&url).unwrap();
+        let (second, _): (serde_json::Value, _) = fetcher.get_json(&url).unwrap();
+
+        assert_eq!(first, serde_json::json!({ "n": 42 }));
+        assert_eq!(second, first);
+        assert_eq!(server.join().unwrap(), [None, Some("\"v1\"".to_string())]);
+        std::fs::remove_dir_all(&cache_dir).unwrap();
+    }
+}
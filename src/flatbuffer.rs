@@ -0,0 +1,402 @@
+// Encoder for the card FlatBuffer (`schema/cards.fbs`).
+// Lookup tables and cards are written in reference order and cards point to
+// their faction, rarity, type, set, sub types and family by index. Posting
+// lists of card indices per faction, type, rarity, main cost and suspension
+// let readers filter by intersecting them instead of scanning every card, and
+// a trigram index over the folded names (see `name_index`) lets them search.
+
+use flatbuffers::FlatBufferBuilder;
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::asset_store::LocalFile;
+use crate::cards_generated::altered_cards::*;
+use crate::details::CardDetail;
+use crate::name_index::{fold, name_grams};
+use crate::optimizer::OptimizedData;
+use crate::optimizer_v2::to_u8;
+
+/// Converts optimized data to the FlatBuffer `CardDatabase`. Fails on costs
+/// and power values that do not fit the `ubyte` fields of the schema.
+pub fn build_card_database(data: &OptimizedData) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    let mut builder = FlatBufferBuilder::with_capacity(1024 * 1024); // 1MB initial capacity
+    
+    // Create factions vector
+    let mut faction_offsets = Vec::new();
+    for (reference, faction) in &data.lookup_tables.factions {
+        let reference_offset = builder.create_string(reference);
+        let name_offset = builder.create_string(&faction.name);
+        let color_offset = builder.create_string(&faction.color);
+        let names_offset = create_localized_strings(&mut builder, &faction.names);
+        
+        let faction_offset = Faction::create(&mut builder, &FactionArgs {
+            reference: Some(reference_offset),
+            name: Some(name_offset),
+            color: Some(color_offset),
+            names: Some(names_offset),
+        });
+        faction_offsets.push(faction_offset);
+    }
+    let factions_vector = builder.create_vector(&faction_offsets);
+    
+    // Create rarities vector
+    let mut rarity_offsets = Vec::new();
+    for (reference, rarity) in &data.lookup_tables.rarities {
+        let reference_offset = builder.create_string(reference);
+        let name_offset = builder.create_string(&rarity.name);
+        let names_offset = create_localized_strings(&mut builder, &rarity.names);
+        
+        let rarity_offset = Rarity::create(&mut builder, &RarityArgs {
+            reference: Some(reference_offset),
+            name: Some(name_offset),
+            names: Some(names_offset),
+        });
+        rarity_offsets.push(rarity_offset);
+    }
+    let rarities_vector = builder.create_vector(&rarity_offsets);
+    
+    // Create card types vector
+    let mut card_type_offsets = Vec::new();
+    for (reference, card_type) in &data.lookup_tables.card_types {
+        let reference_offset = builder.create_string(reference);
+        let name_offset = builder.create_string(&card_type.name);
+        let names_offset = create_localized_strings(&mut builder, &card_type.names);
+        
+        let card_type_offset = CardType::create(&mut builder, &CardTypeArgs {
+            reference: Some(reference_offset),
+            name: Some(name_offset),
+            names: Some(names_offset),
+        });
+        card_type_offsets.push(card_type_offset);
+    }
+    let card_types_vector = builder.create_vector(&card_type_offsets);
+    
+    // Create card sets vector
+    let mut card_set_offsets = Vec::new();
+    for (reference, card_set) in &data.lookup_tables.card_sets {
+        let reference_offset = builder.create_string(reference);
+        let name_offset = builder.create_string(&card_set.name);
+        let names_offset = create_localized_strings(&mut builder, &card_set.names);
+        
+        let card_set_offset = CardSet::create(&mut builder, &CardSetArgs {
+            reference: Some(reference_offset),
+            name: Some(name_offset),
+            names: Some(names_offset),
+        });
+        card_set_offsets.push(card_set_offset);
+    }
+    let card_sets_vector = builder.create_vector(&card_set_offsets);
+    
+    // Create card sub types vector
+    let mut card_sub_type_offsets = Vec::new();
+    for (reference, sub_type) in &data.lookup_tables.card_sub_types {
+        let reference_offset = builder.create_string(reference);
+        let name_offset = builder.create_string(&sub_type.name);
+        let names_offset = create_localized_strings(&mut builder, &sub_type.names);
+        
+        let sub_type_offset = CardSubType::create(&mut builder, &CardSubTypeArgs {
+            reference: Some(reference_offset),
+            name: Some(name_offset),
+            names: Some(names_offset),
+        });
+        card_sub_type_offsets.push(sub_type_offset);
+    }
+    let card_sub_types_vector = builder.create_vector(&card_sub_type_offsets);
+    
+    // Create lookup maps for indices
+    let faction_map: BTreeMap<String, u8> = data.lookup_tables.factions.keys()
+        .enumerate().map(|(i, k)| (k.clone(), i as u8)).collect();
+    let rarity_map: BTreeMap<String, u8> = data.lookup_tables.rarities.keys()
+        .enumerate().map(|(i, k)| (k.clone(), i as u8)).collect();
+    let card_type_map: BTreeMap<String, u8> = data.lookup_tables.card_types.keys()
+        .enumerate().map(|(i, k)| (k.clone(), i as u8)).collect();
+    let card_set_map: BTreeMap<String, u8> = data.lookup_tables.card_sets.keys()
+        .enumerate().map(|(i, k)| (k.clone(), i as u8)).collect();
+    let sub_type_map: BTreeMap<String, u16> = data.lookup_tables.card_sub_types.keys()
+        .enumerate().map(|(i, k)| (k.clone(), i as u16)).collect();
+    let family_map: BTreeMap<String, u32> = data.lookup_tables.card_families.keys()
+        .enumerate().map(|(i, k)| (k.clone(), i as u32)).collect();
+    let card_map: BTreeMap<String, u32> = data.cards.keys()
+        .enumerate().map(|(i, k)| (k.clone(), i as u32)).collect();
+    
+    // Create cards vector. `reference` is the key of `Card`: the BTreeMap yields
+    // the references in byte order, the order binary searches over it expect
+    let mut card_offsets = Vec::new();
+    for (reference, card) in &data.cards {
+        let reference_offset = builder.create_string(reference);
+        let name_offset = builder.create_string(&card.name);
+        let image_path_offset = builder.create_string(&card.image_path);
+        let qr_url_offset = builder.create_string(&card.qr_url);
+        let names_offset = create_localized_strings(&mut builder, &card.names);
+        let image_paths_offset = create_localized_strings(&mut builder, &card.image_paths);
+        let id_offset = builder.create_string(&card.id);
+        let sub_type_idxs: Vec<u16> = card.sub_type_refs.iter()
+            .filter_map(|sub_type| sub_type_map.get(sub_type).copied())
+            .collect();
+        let sub_type_idxs_offset = builder.create_vector(&sub_type_idxs);
+        let assets_offset = create_assets(&mut builder, &card.assets);
+        let texts_offset = create_card_texts(&mut builder, &card.texts);
+        let local_files_offset = create_local_files(&mut builder, &card.local_files);
+        let suspended_since_offset = card
+            .suspended_since
+            .map(|since| builder.create_string(&since.to_rfc3339()));
+        
+        let power_stats = PowerStats::create(&mut builder, &PowerStatsArgs {
+            mountain: to_u8(card.power.m, "mountain power", reference)?,
+            ocean: to_u8(card.power.o, "ocean power", reference)?,
+            forest: to_u8(card.power.f, "forest power", reference)?,
+        });
+        
+        let card_offset = Card::create(&mut builder, &CardArgs {
+            reference: Some(reference_offset),
+            name: Some(name_offset),
+            faction_idx: *faction_map.get(&card.faction_ref).unwrap_or(&0),
+            rarity_idx: *rarity_map.get(&card.rarity_ref).unwrap_or(&0),
+            card_type_idx: *card_type_map.get(&card.type_ref).unwrap_or(&0),
+            main_cost: to_u8(card.main_cost, "main cost", reference)?,
+            recall_cost: to_u8(card.recall_cost, "recall cost", reference)?,
+            power: Some(power_stats),
+            image_path: Some(image_path_offset),
+            qr_url: Some(qr_url_offset),
+            is_suspended: card.is_suspended,
+            names: Some(names_offset),
+            image_paths: Some(image_paths_offset),
+            id: Some(id_offset),
+            card_set_idx: *card_set_map.get(&card.set_ref).unwrap_or(&0),
+            sub_type_idxs: Some(sub_type_idxs_offset),
+            assets: Some(assets_offset),
+            raw_is_suspended: card.raw_is_suspended,
+            family_idx: *family_map.get(&card.family_ref).unwrap_or(&0),
+            texts: Some(texts_offset),
+            local_files: Some(local_files_offset),
+            suspended_since: suspended_since_offset,
+        });
+        card_offsets.push(card_offset);
+    }
+    let cards_vector = builder.create_vector(&card_offsets);
+    
+    // Create card families vector
+    let mut family_offsets = Vec::new();
+    for (reference, family) in &data.lookup_tables.card_families {
+        let member_offsets: Vec<_> = family.members.iter().map(|member| {
+            let set_offset = builder.create_string(&member.set);
+            let product_offset = builder.create_string(&member.product);
+            let variant_offset = builder.create_string(&member.variant);
+            CardFamilyMember::create(&mut builder, &CardFamilyMemberArgs {
+                card_idx: *card_map.get(&member.reference).unwrap_or(&0),
+                set: Some(set_offset),
+                product: Some(product_offset),
+                variant: Some(variant_offset),
+            })
+        }).collect();
+        let members_offset = builder.create_vector(&member_offsets);
+        let reference_offset = builder.create_string(reference);
+        let set_offset = builder.create_string(&family.set);
+        let faction_offset = builder.create_string(&family.faction);
+        let number_offset = builder.create_string(&family.number);
+        
+        let family_offset = CardFamily::create(&mut builder, &CardFamilyArgs {
+            reference: Some(reference_offset),
+            set: Some(set_offset),
+            faction: Some(faction_offset),
+            number: Some(number_offset),
+            members: Some(members_offset),
+        });
+        family_offsets.push(family_offset);
+    }
+    let card_families_vector = builder.create_vector(&family_offsets);
+
+    // Create suspension history vector
+    let suspension_event_offsets: Vec<_> = data.suspension_history.iter().map(|change| {
+        let reference_offset = builder.create_string(&change.reference);
+        let date_offset = builder.create_string(&change.date.to_rfc3339());
+        SuspensionEvent::create(&mut builder, &SuspensionEventArgs {
+            reference: Some(reference_offset),
+            suspended: change.suspended,
+            date: Some(date_offset),
+        })
+    }).collect();
+    let suspension_events_vector = builder.create_vector(&suspension_event_offsets);
+    
+    // Create precomputed filters over the card indices
+    let mut cards_by_faction: BTreeMap<u32, Vec<u32>> = BTreeMap::new();
+    let mut cards_by_card_type: BTreeMap<u32, Vec<u32>> = BTreeMap::new();
+    let mut cards_by_rarity: BTreeMap<u32, Vec<u32>> = BTreeMap::new();
+    let mut cards_by_main_cost: BTreeMap<u32, Vec<u32>> = BTreeMap::new();
+    let mut suspended_cards = Vec::new();
+    for (index, (reference, card)) in data.cards.iter().enumerate() {
+        let index = index as u32;
+        if let Some(&faction) = faction_map.get(&card.faction_ref) {
+            cards_by_faction.entry(faction as u32).or_default().push(index);
+        }
+        if let Some(&card_type) = card_type_map.get(&card.type_ref) {
+            cards_by_card_type.entry(card_type as u32).or_default().push(index);
+        }
+        if let Some(&rarity) = rarity_map.get(&card.rarity_ref) {
+            cards_by_rarity.entry(rarity as u32).or_default().push(index);
+        }
+        let main_cost = to_u8(card.main_cost, "main cost", reference)?;
+        cards_by_main_cost.entry(main_cost as u32).or_default().push(index);
+        if card.is_suspended {
+            suspended_cards.push(index);
+        }
+    }
+    let cards_by_faction_vector = create_posting_lists(&mut builder, &cards_by_faction);
+    let cards_by_card_type_vector = create_posting_lists(&mut builder, &cards_by_card_type);
+    let cards_by_rarity_vector = create_posting_lists(&mut builder, &cards_by_rarity);
+    let cards_by_main_cost_vector = create_posting_lists(&mut builder, &cards_by_main_cost);
+    let suspended_cards_vector = builder.create_vector(&suspended_cards);
+    
+    // Create name index over the names in every locale
+    let mut cards_by_gram: BTreeMap<String, Vec<u32>> = BTreeMap::new();
+    for (index, card) in data.cards.values().enumerate() {
+        let grams: BTreeSet<String> = std::iter::once(&card.name)
+            .chain(card.names.values())
+            .flat_map(|name| name_grams(&fold(name)))
+            .collect();
+        for gram in grams {
+            cards_by_gram.entry(gram).or_default().push(index as u32);
+        }
+    }
+    let name_gram_offsets: Vec<_> = cards_by_gram.iter().map(|(gram, cards)| {
+        let gram_offset = builder.create_string(gram);
+        let cards_offset = builder.create_vector(cards);
+        NameGram::create(&mut builder, &NameGramArgs {
+            gram: Some(gram_offset),
+            cards: Some(cards_offset),
+        })
+    }).collect();
+    let name_grams_vector = builder.create_vector(&name_gram_offsets);
+    
+    // Create metadata strings
+    let generated_at_offset = builder.create_string(&data.meta.generated_at_utc.to_rfc3339());
+    let script_version_offset = builder.create_string(&data.meta.script_version);
+    
+    // Create root table
+    let card_database = CardDatabase::create(&mut builder, &CardDatabaseArgs {
+        factions: Some(factions_vector),
+        rarities: Some(rarities_vector),
+        card_types: Some(card_types_vector),
+        cards: Some(cards_vector),
+        generated_at_utc: Some(generated_at_offset),
+        script_version: Some(script_version_offset),
+        total_cards: data.meta.total_cards as u32,
+        card_sets: Some(card_sets_vector),
+        card_sub_types: Some(card_sub_types_vector),
+        card_families: Some(card_families_vector),
+        suspension_events: Some(suspension_events_vector),
+        cards_by_faction: Some(cards_by_faction_vector),
+        cards_by_card_type: Some(cards_by_card_type_vector),
+        cards_by_rarity: Some(cards_by_rarity_vector),
+        cards_by_main_cost: Some(cards_by_main_cost_vector),
+        suspended_cards: Some(suspended_cards_vector),
+        name_grams: Some(name_grams_vector),
+    });
+    
+    builder.finish(card_database, None);
+    
+    Ok(builder.finished_data().to_vec())
+}
+
+/// Serializes a locale -> text map as a vector of `LocalizedString` tables.
+fn create_localized_strings<'a>(
+    builder: &mut FlatBufferBuilder<'a>,
+    values: &BTreeMap<String, String>,
+) -> flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<LocalizedString<'a>>>> {
+    let offsets: Vec<_> = values
+        .iter()
+        .map(|(locale, value)| {
+            let locale_offset = builder.create_string(locale);
+            let value_offset = builder.create_string(value);
+            LocalizedString::create(builder, &LocalizedStringArgs {
+                locale: Some(locale_offset),
+                value: Some(value_offset),
+            })
+        })
+        .collect();
+    builder.create_vector(&offsets)
+}
+
+/// Serializes the asset URLs of a card as a vector of `CardAsset` tables.
+fn create_assets<'a>(
+    builder: &mut FlatBufferBuilder<'a>,
+    assets: &BTreeMap<String, Vec<String>>,
+) -> flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CardAsset<'a>>>> {
+    let offsets: Vec<_> = assets
+        .iter()
+        .map(|(kind, urls)| {
+            let kind_offset = builder.create_string(kind);
+            let url_offsets: Vec<_> = urls.iter().map(|url| builder.create_string(url)).collect();
+            let urls_offset = builder.create_vector(&url_offsets);
+            CardAsset::create(builder, &CardAssetArgs {
+                kind: Some(kind_offset),
+                urls: Some(urls_offset),
+            })
+        })
+        .collect();
+    builder.create_vector(&offsets)
+}
+
+fn create_card_texts<'a>(
+    builder: &mut FlatBufferBuilder<'a>,
+    texts: &BTreeMap<String, CardDetail>,
+) -> flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CardText<'a>>>> {
+    let offsets: Vec<_> = texts
+        .iter()
+        .map(|(locale, detail)| {
+            let locale_offset = builder.create_string(locale);
+            let main_effect_offset = detail.main_effect.as_deref().map(|text| builder.create_string(text));
+            let echo_effect_offset = detail.echo_effect.as_deref().map(|text| builder.create_string(text));
+            let flavor_offset = detail.flavor.as_deref().map(|text| builder.create_string(text));
+            let keyword_offsets: Vec<_> = detail.keywords.iter().map(|keyword| builder.create_string(keyword)).collect();
+            let keywords_offset = builder.create_vector(&keyword_offsets);
+            CardText::create(builder, &CardTextArgs {
+                locale: Some(locale_offset),
+                main_effect: main_effect_offset,
+                echo_effect: echo_effect_offset,
+                flavor: flavor_offset,
+                keywords: Some(keywords_offset),
+            })
+        })
+        .collect();
+    builder.create_vector(&offsets)
+}
+
+fn create_local_files<'a>(
+    builder: &mut FlatBufferBuilder<'a>,
+    files: &BTreeMap<String, LocalFile>,
+) -> flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CardFile<'a>>>> {
+    let offsets: Vec<_> = files
+        .iter()
+        .map(|(url, file)| {
+            let url_offset = builder.create_string(url);
+            let path_offset = builder.create_string(&file.path);
+            let sha256_offset = builder.create_string(&file.sha256);
+            CardFile::create(builder, &CardFileArgs {
+                url: Some(url_offset),
+                path: Some(path_offset),
+                sha256: Some(sha256_offset),
+                size: file.size,
+            })
+        })
+        .collect();
+    builder.create_vector(&offsets)
+}
+
+/// Serializes value -> card indices lists as a vector of `PostingList` tables,
+/// sorted by value.
+fn create_posting_lists<'a>(
+    builder: &mut FlatBufferBuilder<'a>,
+    lists: &BTreeMap<u32, Vec<u32>>,
+) -> flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<PostingList<'a>>>> {
+    let offsets: Vec<_> = lists
+        .iter()
+        .map(|(&value, cards)| {
+            let cards_offset = builder.create_vector(cards);
+            PostingList::create(builder, &PostingListArgs {
+                value,
+                cards: Some(cards_offset),
+            })
+        })
+        .collect();
+    builder.create_vector(&offsets)
+}
@@ -0,0 +1,490 @@
+// Harvester: fetches every card from the paginated Hydra collection endpoint
+// for the queries described by the harvest configuration.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+use crate::checkpoint::{stable_hash, CheckpointStore, PageCheckpoint};
+use crate::config::{HarvestConfig, HarvestQuery};
+use crate::details::{harvest_details, CardDetail};
+use crate::fetcher::{FetchError, Fetcher};
+use crate::raw_card::{RawCard, RawCardMode};
+
+// --- Structs for Deserializing API Response ---
+#[derive(Deserialize, Debug)]
+struct HydraView {
+    #[serde(rename = "hydra:next")]
+    next: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+struct ApiResponse<T> {
+    #[serde(rename = "hydra:member")]
+    members: Vec<T>,
+    #[serde(rename = "hydra:view")]
+    view: Option<HydraView>,
+}
+
+/// A card set as listed by the `/card_sets` endpoint.
+#[derive(Deserialize, Debug, Clone)]
+pub struct ApiCardSet {
+    pub reference: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub id: String,
+}
+
+/// A rarity as listed by the `/rarities` endpoint.
+#[derive(Deserialize, Debug, Clone)]
+pub struct ApiRarity {
+    pub reference: String,
+    #[serde(default)]
+    pub name: String,
+}
+
+// --- Intermediate struct for processing ---
+#[derive(Serialize, Deserialize, Debug)]
+pub struct HarvestedCard {
+    pub card_data: RawCard,
+    pub is_suspended: bool,
+    /// Locale the record was fetched in.
+    #[serde(default = "legacy_locale")]
+    pub locale: String,
+    /// Effect text and keywords from the detail pass, when it ran.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<CardDetail>,
+    /// When the page holding the record was fetched. Records of raw
+    /// harvests saved without it stay undated, see `optimize --harvested-at`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub harvested_at_utc: Option<DateTime<Utc>>,
+}
+
+/// Harvests saved before multi-locale support were French only.
+fn legacy_locale() -> String {
+    "fr-fr".to_string()
+}
+
+/// Set references present in a harvest, in order of first appearance.
+pub fn harvested_sets(harvested_cards: &[HarvestedCard]) -> Vec<String> {
+    harvested_references(harvested_cards, |card| &card.card_set.reference)
+}
+
+/// Where `harvest_cards` records the URLs of the queries it ran, next to the
+/// raw harvest saved at `raw_path`.
+pub fn data_sources_path(raw_path: &Path) -> PathBuf {
+    raw_path.with_extension("sources.json")
+}
+
+/// Loads the query URLs recorded next to a raw harvest, in query order.
+/// Harvests saved before they were recorded have none.
+pub fn load_data_sources(raw_path: &Path) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    let path = data_sources_path(raw_path);
+    if !path.exists() {
+        println!(
+            "   > ⚠️ No record of the queries behind '{}'; data sources left empty",
+            raw_path.display()
+        );
+        return Ok(Vec::new());
+    }
+    let file = File::open(&path)?;
+    Ok(serde_json::from_reader(BufReader::new(file))
+        .map_err(|e| format!("invalid data sources '{}': {}", path.display(), e))?)
+}
+
+/// When the harvest was made: the fetch date of its most recent page.
+pub fn harvest_date(harvested_cards: &[HarvestedCard]) -> Option<DateTime<Utc>> {
+    harvested_cards.iter().filter_map(|card| card.harvested_at_utc).max()
+}
+
+fn harvested_references(
+    harvested_cards: &[HarvestedCard],
+    reference_of: impl Fn(&RawCard) -> &String,
+) -> Vec<String> {
+    let mut references: Vec<String> = Vec::new();
+    for card in harvested_cards {
+        let reference = reference_of(&card.card_data);
+        if !references.contains(reference) {
+            references.push(reference.clone());
+        }
+    }
+    references
+}
+
+/// A page that could not be fetched even after exhausting the retry budget.
+#[derive(Debug)]
+pub struct HarvestError {
+    pub query: String,
+    pub page: usize,
+    pub source: FetchError,
+}
+
+impl fmt::Display for HarvestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "query '{}' page {}: {}",
+            self.query, self.page, self.source
+        )
+    }
+}
+
+impl std::error::Error for HarvestError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// Lists every card set known to the API, following pagination.
+pub fn discover_card_sets(
+    config: &HarvestConfig,
+    fetcher: &Fetcher,
+) -> Result<Vec<ApiCardSet>, Box<dyn std::error::Error>> {
+    list_collection(fetcher, "card_sets", config.card_sets_url())
+}
+
+/// Lists every rarity known to the API, following pagination.
+pub fn discover_rarities(
+    config: &HarvestConfig,
+    fetcher: &Fetcher,
+) -> Result<Vec<ApiRarity>, Box<dyn std::error::Error>> {
+    list_collection(fetcher, "rarities", config.rarities_url())
+}
+
+fn list_collection<T: serde::de::DeserializeOwned>(
+    fetcher: &Fetcher,
+    label: &str,
+    url: String,
+) -> Result<Vec<T>, Box<dyn std::error::Error>> {
+    let mut members = Vec::new();
+    let mut next_page_url = Some(url);
+    let mut page_count = 0;
+
+    while let Some(url) = next_page_url {
+        page_count += 1;
+        let (api_data, response_url): (ApiResponse<T>, _) =
+            fetcher.get_json(&url).map_err(|source| HarvestError {
+                query: label.to_string(),
+                page: page_count,
+                source,
+            })?;
+        members.extend(api_data.members);
+
+        next_page_url = if let Some(view) = api_data.view.and_then(|v| v.next) {
+            Some(response_url.join(&view)?.to_string())
+        } else {
+            None
+        };
+    }
+    Ok(members)
+}
+
+/// Resolves the set references to harvest, either from discovery or from the
+/// configured list, with the exclude filter applied.
+pub fn resolve_sets(config: &HarvestConfig, fetcher: &Fetcher) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    if !config.discover_sets {
+        return Ok(config
+            .sets
+            .iter()
+            .filter(|set| !config.exclude_sets.contains(set))
+            .cloned()
+            .collect());
+    }
+
+    println!("   > Discovering card sets from '{}'", config.card_sets_url());
+    let discovered: Vec<String> = discover_card_sets(config, fetcher)?
+        .into_iter()
+        .map(|set| set.reference)
+        .collect();
+    let selected = config.select_sets(&discovered);
+    println!(
+        "   > Discovered {} sets, harvesting {}: {}",
+        discovered.len(),
+        selected.len(),
+        selected.join(", ")
+    );
+    Ok(selected)
+}
+
+/// Resolves the rarities to harvest: the configured list, or every rarity
+/// listed by the API when the list is empty.
+pub fn resolve_rarities(config: &HarvestConfig, fetcher: &Fetcher) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    if !config.rarities.is_empty() {
+        return Ok(config.rarities.clone());
+    }
+
+    println!("   > Discovering rarities from '{}'", config.rarities_url());
+    let discovered: Vec<String> = discover_rarities(config, fetcher)?
+        .into_iter()
+        .map(|rarity| rarity.reference)
+        .collect();
+    println!("   > Harvesting every rarity: {}", discovered.join(", "));
+    Ok(config.select_rarities(&discovered))
+}
+
+/// ### STEP 1: HARVESTER ###
+/// Fetches all card data and flags suspended cards, then optionally the detail
+/// resource of every card. Queries are spread over a bounded pool of workers
+/// sharing the fetcher's rate limiter; their results are merged back in query
+/// order so the output does not depend on timing.
+/// Pages are checkpointed as they arrive so an interrupted harvest resumes
+/// where it stopped, unless `fresh` is set.
+pub fn harvest_cards(
+    config: &HarvestConfig,
+    output_path: &Path,
+    fresh: bool,
+) -> Result<Vec<HarvestedCard>, Box<dyn std::error::Error>> {
+    let fetcher = Fetcher::new(config)?;
+    let checkpoints = CheckpointStore::open(&config.checkpoint_dir, fresh)?;
+    let sets = resolve_sets(config, &fetcher)?;
+    let rarities = resolve_rarities(config, &fetcher)?;
+    let queries = config.queries(&sets, &rarities);
+    let worker_count = config.workers.clamp(1, queries.len().max(1));
+    println!(
+        "\n--- Harvesting {} queries with {} worker(s) ---",
+        queries.len(),
+        worker_count
+    );
+    let (query_cards, query_rejected): (Vec<_>, Vec<_>) = run_workers(worker_count, &queries, |query| {
+        harvest_query(&fetcher, &checkpoints, query, config.raw_card_mode)
+    })?
+    .into_iter()
+    .unzip();
+    let mut all_cards: Vec<HarvestedCard> = query_cards.into_iter().flatten().collect();
+    let rejected: Vec<serde_json::Value> = query_rejected.into_iter().flatten().collect();
+
+    if config.details.enabled {
+        println!("\n--- Harvesting Card Details ---");
+        harvest_details(config, &fetcher, &checkpoints, &mut all_cards)?;
+    }
+
+    println!(
+        "\n   > ✅ Harvest complete. Found {} total card objects (pre-optimization).",
+        all_cards.len()
+    );
+    if !rejected.is_empty() {
+        println!(
+            "   > {} card object(s) did not parse and are left to validation",
+            rejected.len()
+        );
+    }
+
+    // Save the raw data (now including the suspension flag) as a backup.
+    // Records that did not parse are saved as they came, after the cards.
+    let mut records = all_cards
+        .iter()
+        .map(serde_json::to_value)
+        .collect::<Result<Vec<_>, _>>()?;
+    records.extend(rejected);
+    let raw_file = File::create(output_path)?;
+    serde_json::to_writer_pretty(BufWriter::new(raw_file), &records)?;
+    println!("   > Raw data saved to '{}'", output_path.display());
+    let sources: Vec<&str> = queries.iter().map(|query| query.url.as_str()).collect();
+    let sources_file = File::create(data_sources_path(output_path))?;
+    serde_json::to_writer_pretty(BufWriter::new(sources_file), &sources)?;
+
+    // The harvest is safely on disk, so the next run must start from scratch.
+    checkpoints.clear()?;
+
+    Ok(all_cards)
+}
+
+/// Loads the records of a raw harvest as JSON, for validation to parse.
+/// Records saved before they carried `harvested_at_utc` stay undated.
+pub fn load_raw_records(path: &Path) -> Result<Vec<serde_json::Value>, Box<dyn std::error::Error>> {
+    let file = File::open(path)
+        .map_err(|e| format!("cannot open raw harvest '{}': {}", path.display(), e))?;
+    let records: Vec<serde_json::Value> = serde_json::from_reader(BufReader::new(file))
+        .map_err(|e| format!("invalid raw harvest '{}': {}", path.display(), e))?;
+    println!(
+        "   > Loaded {} raw card objects from '{}'",
+        records.len(),
+        path.display()
+    );
+    Ok(records)
+}
+
+/// Loads a raw harvest previously saved by `harvest_cards`. Every card is
+/// checked against the `RawCard` model in the given mode.
+pub fn load_raw_cards(path: &Path, mode: RawCardMode) -> Result<Vec<HarvestedCard>, Box<dyn std::error::Error>> {
+    let file = File::open(path)
+        .map_err(|e| format!("cannot open raw harvest '{}': {}", path.display(), e))?;
+    let raw_cards: Vec<HarvestedCard> = serde_json::from_reader(BufReader::new(file))
+        .map_err(|e| format!("invalid raw harvest '{}': {}", path.display(), e))?;
+    for card in &raw_cards {
+        card.card_data.check(mode)?;
+    }
+    println!(
+        "   > Loaded {} raw card objects from '{}'",
+        raw_cards.len(),
+        path.display()
+    );
+    Ok(raw_cards)
+}
+
+/// Runs `task` on every item with a bounded pool of worker threads and
+/// returns the results in item order. Workers stop taking new items as soon
+/// as one task fails, and the first failure in item order is returned.
+pub(crate) fn run_workers<T, R, F>(
+    worker_count: usize,
+    items: &[T],
+    task: F,
+) -> Result<Vec<R>, Box<dyn std::error::Error>>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> Result<R, Box<dyn std::error::Error>> + Sync,
+{
+    // Errors are reported as strings because `Box<dyn Error>` cannot cross threads.
+    let results: Mutex<Vec<Option<Result<R, String>>>> =
+        Mutex::new(items.iter().map(|_| None).collect());
+    let next_item = AtomicUsize::new(0);
+    let failed = AtomicBool::new(false);
+
+    thread::scope(|scope| {
+        for _ in 0..worker_count.max(1) {
+            scope.spawn(|| {
+                while !failed.load(Ordering::Relaxed) {
+                    let index = next_item.fetch_add(1, Ordering::Relaxed);
+                    let Some(item) = items.get(index) else {
+                        break;
+                    };
+                    let result = task(item).map_err(|e| e.to_string());
+                    if result.is_err() {
+                        failed.store(true, Ordering::Relaxed);
+                    }
+                    results.lock().unwrap()[index] = Some(result);
+                }
+            });
+        }
+    });
+
+    let mut collected = Vec::with_capacity(items.len());
+    for result in results.into_inner().unwrap().into_iter().flatten() {
+        collected.push(result?);
+    }
+    Ok(collected)
+}
+
+/// Fetches every page of a single query, replaying checkpointed pages first.
+/// Returns the cards with the raw records of the card objects that did not
+/// parse.
+fn harvest_query(
+    fetcher: &Fetcher,
+    checkpoints: &CheckpointStore,
+    query: &HarvestQuery,
+    mode: RawCardMode,
+) -> Result<(Vec<HarvestedCard>, Vec<serde_json::Value>), Box<dyn std::error::Error>> {
+    let mut cards: Vec<HarvestedCard> = Vec::new();
+    let mut rejected = Vec::new();
+
+    // Replay pages saved by an interrupted run before fetching new ones.
+    let saved_pages = checkpoints.load_pages(&query.url)?;
+    let mut page_count = saved_pages.len();
+    let mut next_page_url = match saved_pages.last() {
+        Some(last_page) => {
+            println!(
+                "   > [{}] Resuming after {} checkpointed page(s)",
+                query.label, page_count
+            );
+            last_page.next.clone()
+        }
+        None => Some(query.url.clone()),
+    };
+    let mut kept = 0;
+    for page in saved_pages {
+        kept += push_members(&mut cards, &mut rejected, page.members, page.fetched_at_utc, query, kept, mode);
+    }
+
+    while let Some(url) = next_page_url {
+        if query.max_cards.is_some_and(|max| kept >= max) {
+            println!(
+                "   > [{}] Reached the limit of {} cards for this query",
+                query.label, kept
+            );
+            break;
+        }
+        page_count += 1;
+        println!("   > [{}] Fetching page {}: {}", query.label, page_count, url);
+        let (api_data, response_url): (ApiResponse<serde_json::Value>, _) =
+            fetcher.get_json(&url).map_err(|source| HarvestError {
+                query: query.label.clone(),
+                page: page_count,
+                source,
+            })?;
+
+        let next = if let Some(view) = api_data.view.and_then(|v| v.next) {
+            Some(response_url.join(&view)?.to_string())
+        } else {
+            None
+        };
+        let page = PageCheckpoint {
+            url,
+            members: api_data.members,
+            next,
+            fetched_at_utc: Utc::now(),
+        };
+        checkpoints.save_page(&query.url, page_count, &page)?;
+
+        next_page_url = page.next;
+        kept += push_members(&mut cards, &mut rejected, page.members, page.fetched_at_utc, query, kept, mode);
+    }
+
+    println!("   > [{}] Done: {} card(s)", query.label, cards.len());
+    Ok((cards, rejected))
+}
+
+/// Parses each card and wraps it with its suspension status and locale,
+/// applying the query's sampling and card limit. A card object that does not
+/// parse is wrapped as it came into `rejected`, for validation to report.
+/// Every record is dated with the fetch of its page. `already_kept` counts
+/// the cards kept from earlier pages; returns how many cards of this page
+/// were kept.
+fn push_members(
+    all_cards: &mut Vec<HarvestedCard>,
+    rejected: &mut Vec<serde_json::Value>,
+    members: Vec<serde_json::Value>,
+    fetched_at: DateTime<Utc>,
+    query: &HarvestQuery,
+    already_kept: usize,
+    mode: RawCardMode,
+) -> usize {
+    let mut kept = 0;
+    for member in members {
+        if query.max_cards.is_some_and(|max| already_kept + kept >= max) {
+            break;
+        }
+        let card = match RawCard::parse(member.clone(), mode) {
+            Ok(card) => card,
+            Err(e) => {
+                println!("     ! [{}] {}", query.label, e);
+                rejected.push(serde_json::json!({
+                    "card_data": member,
+                    "is_suspended": query.is_suspended,
+                    "locale": query.locale,
+                    "harvested_at_utc": fetched_at,
+                }));
+                continue;
+            }
+        };
+        if query.sample_rate > 1 && !stable_hash(&card.reference).is_multiple_of(query.sample_rate) {
+            continue;
+        }
+        all_cards.push(HarvestedCard {
+            card_data: card,
+            is_suspended: query.is_suspended,
+            locale: query.locale.clone(),
+            details: None,
+            harvested_at_utc: Some(fetched_at),
+        });
+        kept += 1;
+    }
+    kept
+}
@@ -0,0 +1,65 @@
+// On-disk HTTP response cache for the harvester.
+// Stores the body and validators (ETag / Last-Modified) of every page so the
+// next run can send a conditional request and reuse the body on a 304.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter, Write};
+use std::path::PathBuf;
+
+use crate::checkpoint::stable_hash;
+use crate::config::CacheConfig;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CacheEntry {
+    pub url: String,
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+    /// When the entry was last fetched or revalidated.
+    pub validated_at_utc: DateTime<Utc>,
+    pub body: String,
+}
+
+pub struct HttpCache {
+    dir: PathBuf,
+    max_age: Duration,
+}
+
+impl HttpCache {
+    /// Opens the cache described by the configuration, or `None` when disabled.
+    pub fn open(config: &CacheConfig) -> Result<Option<Self>, Box<dyn std::error::Error>> {
+        if !config.enabled {
+            return Ok(None);
+        }
+        let dir = PathBuf::from(&config.dir);
+        fs::create_dir_all(&dir)?;
+        Ok(Some(HttpCache {
+            dir,
+            max_age: Duration::hours(config.max_age_hours as i64),
+        }))
+    }
+
+    fn entry_path(&self, url: &str) -> PathBuf {
+        self.dir.join(format!("{:016x}.json", stable_hash(url)))
+    }
+
+    /// Returns the cached entry for `url`, ignoring entries that were not
+    /// revalidated within the configured max age.
+    pub fn lookup(&self, url: &str) -> Option<CacheEntry> {
+        let file = File::open(self.entry_path(url)).ok()?;
+        let entry: CacheEntry = serde_json::from_reader(BufReader::new(file)).ok()?;
+        let is_fresh = Utc::now() - entry.validated_at_utc <= self.max_age;
+        (entry.url == url && is_fresh).then_some(entry)
+    }
+
+    pub fn store(&self, entry: &CacheEntry) -> Result<(), Box<dyn std::error::Error>> {
+        let path = self.entry_path(&entry.url);
+        let tmp_path = path.with_extension("json.tmp");
+        let mut writer = BufWriter::new(File::create(&tmp_path)?);
+        serde_json::to_writer(&mut writer, entry)?;
+        writer.flush()?;
+        fs::rename(&tmp_path, &path)?;
+        Ok(())
+    }
+}
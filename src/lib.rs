@@ -0,0 +1,27 @@
+// Library side of the Altered data pipeline.
+// The pipeline stages live here so they can be driven by the integration
+// tests in `tests/`, the harvester against a local stand-in for the API; the
+// binary in `main.rs` wires them to the command line and the output files.
+
+pub mod asset_store;
+pub mod card_db;
+#[allow(unused_imports, mismatched_lifetime_syntaxes, clippy::all)]
+pub mod cards_generated;
+#[allow(unused_imports, mismatched_lifetime_syntaxes, clippy::all)]
+pub mod cards_optimized_generated;
+pub mod checkpoint;
+pub mod config;
+pub mod derivatives;
+pub mod details;
+pub mod fetcher;
+pub mod flatbuffer;
+pub mod harvester;
+pub mod http_cache;
+pub mod name_index;
+pub mod optimizer;
+pub mod optimizer_v2;
+pub mod rate_limiter;
+pub mod raw_card;
+pub mod reference;
+pub mod suspensions;
+pub mod validation;
@@ -1,459 +1,482 @@
 // Version: 2.0.0
 // Description: A data pipeline to harvest and optimize card data for the game "Altered" from its public API.
-//              This script fetches data from multiple predefined sets and also suspended cards,
+//              This script fetches data from the sets listed in `harvester.toml` and also suspended cards,
 //              adds a flag to identify suspended cards, and then processes the data into both
 //              JSON and high-performance FlatBuffer formats for maximum efficiency.
 
-use chrono::{DateTime, Utc};
-use flatbuffers::FlatBufferBuilder;
-use reqwest::blocking::Client;
-use serde::{Deserialize, Serialize};
-use std::collections::BTreeMap;
+use clap::Parser;
+use std::collections::BTreeSet;
 use std::fs::File;
-use std::io::{BufWriter, Write};
-use std::thread::sleep;
-use std::time::Duration;
+use std::io::{BufReader, Write};
+use std::path::Path;
 
-mod cards_generated;
-use cards_generated::altered_cards::*;
-
-mod optimizer_v2;
+mod cli;
+#[allow(dead_code)]
 mod delta_manager;
 
+use altered_harvester::asset_store::download_assets;
+use altered_harvester::card_db::{AnyCardDb, CardDb, OptimizedCardDb};
+use altered_harvester::config::Config;
+use altered_harvester::derivatives::{generate_derivatives, SourceImage};
+use altered_harvester::fetcher::Fetcher;
+use altered_harvester::flatbuffer::build_card_database;
+use altered_harvester::harvester::{self, harvest_cards, load_data_sources, load_raw_records, HarvestedCard};
+use altered_harvester::optimizer::{
+    load_optimized_data, optimize_harvest, rebuild_suspension_history, save_optimized_data,
+    save_suspension_ledger, OptimizedCard, OptimizedData,
+};
+use altered_harvester::optimizer_v2;
+use altered_harvester::suspensions::SuspensionLedger;
+use altered_harvester::validation::validate_records;
+use cli::{Cli, Command, DeltaCommand, InspectArgs};
+
 // --- Configuration ---
-const SCRIPT_VERSION: &str = "2.0.0";
 const RAW_OUTPUT_FILENAME: &str = "altered_all_cards.json";
 const OPTIMIZED_OUTPUT_FILENAME: &str = "altered_optimized.json";
 const FLATBUFFER_OUTPUT_FILENAME: &str = "altered_cards.fb";
-const REQUEST_DELAY: Duration = Duration::from_secs(1);
-const USER_AGENT: &str = "AlteredDataPipeline/1.0-Rust (for personal project)";
-const BASE_API_URL: &str = "https://api.altered.gg/cards?itemsPerPage=36&locale=fr-fr";
-const QUERIES: &[&str] = &[
-    "&cardSet[]=COREKS&rarity[]=COMMON&rarity[]=RARE", // COREKS Set
-    "&cardSet[]=CORE&rarity[]=COMMON&rarity[]=RARE",   // CORE Set
-    "&cardSet[]=ALIZE&rarity[]=COMMON&rarity[]=RARE",  // ALIZE Set
-    "&cardSet[]=BISE&rarity[]=COMMON&rarity[]=RARE",   // BISE Set
-    "&isSuspended=true&rarity[]=COMMON&rarity[]=RARE", // Suspended cards (must be last)
-];
-
-// --- Structs for Deserializing API Response ---
-#[derive(Deserialize, Debug)]
-struct HydraView {
-    #[serde(rename = "hydra:next")]
-    next: Option<String>,
-}
-
-#[derive(Deserialize, Debug)]
-struct ApiResponse {
-    #[serde(rename = "hydra:member")]
-    members: Vec<serde_json::Value>,
-    #[serde(rename = "hydra:view")]
-    view: Option<HydraView>,
-}
-
-// --- Intermediate struct for processing ---
-#[derive(Serialize)]
-struct HarvestedCard {
-    card_data: serde_json::Value,
-    is_suspended: bool,
-}
 
-// --- Structs for Serializing Optimized Output ---
-#[derive(Serialize)]
-struct Meta {
-    script_version: String,
-    generated_at_utc: DateTime<Utc>,
-    source_set: String,
-    data_sources: Vec<String>,
-    total_cards: usize,
-}
-
-#[derive(Serialize)]
-struct RarityInfo {
-    name: String,
-}
-#[derive(Serialize)]
-struct FactionInfo {
-    name: String,
-    color: String,
-}
-#[derive(Serialize)]
-struct CardTypeInfo {
-    name: String,
+/// ### STEP 3: FLATBUFFER GENERATOR ###
+/// Converts optimized data to ultra-fast FlatBuffer format.
+fn generate_flatbuffer(data: &OptimizedData, output_path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    let bytes = build_card_database(data)?;
+    
+    // Write to file
+    let mut file = File::create(output_path)?;
+    file.write_all(&bytes)?;
+    
+    println!(
+        "   > Ultra-fast FlatBuffer data saved to '{}' ({} KB)",
+        output_path.display(),
+        bytes.len() / 1024
+    );
+    println!("   > FlatBuffer provides zero-copy access and 500x faster queries!");
+    
+    Ok(())
 }
 
-#[derive(Serialize)]
-struct LookupTables {
-    rarities: BTreeMap<String, RarityInfo>,
-    factions: BTreeMap<String, FactionInfo>,
-    card_types: BTreeMap<String, CardTypeInfo>,
+/// Writes the FlatBuffer database and, unless skipped, the v2 optimized formats.
+fn write_flatbuffers(
+    data: &OptimizedData,
+    output_path: &Path,
+    skip_advanced: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    generate_flatbuffer(data, output_path)?;
+
+    if !skip_advanced {
+        println!("\n   > Generating advanced optimized formats...");
+        optimizer_v2::save_optimized_formats(data)?;
+    }
+    Ok(())
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-struct LocalPowerStats {
-    m: i64,
-    o: i64,
-    f: i64,
+/// Lists the card sets offered by the API and whether the configuration harvests them.
+fn list_sets(config: &Config) -> Result<(), Box<dyn std::error::Error>> {
+    let fetcher = Fetcher::new(&config.harvest)?;
+    let card_sets = harvester::discover_card_sets(&config.harvest, &fetcher)?;
+    let references: Vec<String> = card_sets.iter().map(|s| s.reference.clone()).collect();
+    let selected = config.harvest.select_sets(&references);
+
+    println!("Card sets available from '{}':", config.harvest.card_sets_url());
+    for card_set in &card_sets {
+        let marker = if selected.contains(&card_set.reference) { "✔" } else { " " };
+        println!("   {} {:<12} {:<28} {}", marker, card_set.reference, card_set.id, card_set.name);
+    }
+    Ok(())
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-struct OptimizedCard {
-    name: String,
-    type_ref: String,
-    faction_ref: String,
-    rarity_ref: String,
-    image_path: String,
-    qr_url: String,
-    main_cost: i64,
-    recall_cost: i64,
-    is_suspended: bool, // New field to identify suspended cards
-    power: LocalPowerStats,
+/// ### DELTA UPDATES ###
+fn run_delta(command: DeltaCommand) -> Result<(), Box<dyn std::error::Error>> {
+    let manager = delta_manager::DeltaManager::new("./deltas/");
+    match command {
+        DeltaCommand::Create {
+            old,
+            new,
+            base_version,
+            target_version,
+            output,
+        } => {
+            let old_data = load_optimized_data(&old)?;
+            let new_data = load_optimized_data(&new)?;
+            let delta = manager.create_delta(&old_data, &new_data, &base_version, &target_version)?;
+            manager.save_delta(&delta, &output.to_string_lossy())?;
+            println!(
+                "   > Delta {} -> {} saved to '{}' ({} added, {} modified, {} removed)",
+                base_version,
+                target_version,
+                output.display(),
+                delta.statistics.cards_added,
+                delta.statistics.cards_modified,
+                delta.statistics.cards_removed
+            );
+        }
+        DeltaCommand::Apply {
+            base,
+            delta,
+            output,
+        } => {
+            let mut base_data = load_optimized_data(&base)?;
+            let delta = manager.load_delta(&delta.to_string_lossy())?;
+            manager.apply_delta(&mut base_data, &delta)?;
+            save_optimized_data(&base_data, &output)?;
+            println!(
+                "   > Applied {} operations ({} -> {}), {} cards saved to '{}'",
+                delta.operations.len(),
+                delta.base_version,
+                delta.target_version,
+                base_data.meta.total_cards,
+                output.display()
+            );
+        }
+    }
+    Ok(())
 }
 
-#[derive(Serialize)]
-struct OptimizedData {
-    meta: Meta,
-    lookup_tables: LookupTables,
-    cards: BTreeMap<String, OptimizedCard>,
+fn print_suspension_change(date: &str, reference: &str, suspended: bool) {
+    let status = if suspended { "suspended" } else { "unsuspended" };
+    println!("     {}  {:<24} {}", date, reference, status);
 }
 
-/// ### STEP 1: HARVESTER ###
-/// Fetches all card data and flags suspended cards.
-fn harvest_cards() -> Result<Vec<HarvestedCard>, Box<dyn std::error::Error>> {
-    let client = Client::builder().user_agent(USER_AGENT).build()?;
-    let mut all_cards: Vec<HarvestedCard> = Vec::new();
-    let suspended_query_fragment = "&isSuspended=true";
-
-    for (index, query) in QUERIES.iter().enumerate() {
-        println!("\n--- Harvesting Query {}/{} ---", index + 1, QUERIES.len());
-        let is_suspended_query = query.contains(suspended_query_fragment);
-        let start_url = format!("{}{}", BASE_API_URL, query);
-        let mut next_page_url = Some(start_url);
-        let mut page_count = 0;
-
-        while let Some(url) = next_page_url {
-            page_count += 1;
-            println!("   > Fetching page {}: {}", page_count, url);
-            let response = client.get(&url).send()?.error_for_status()?;
-            let response_url = response.url().clone();
-            let api_data: ApiResponse = response.json()?;
-
-            // Wrap each card with its suspension status
-            for member in api_data.members {
-                all_cards.push(HarvestedCard {
-                    card_data: member,
-                    is_suspended: is_suspended_query,
-                });
-            }
-
-            next_page_url = if let Some(view) = api_data.view.and_then(|v| v.next) {
-                Some(response_url.join(&view)?.to_string())
-            } else {
-                None
-            };
-            sleep(REQUEST_DELAY);
+/// Prints a `CardDatabase` (altered_cards.fb).
+fn inspect_card_db(card_db: &CardDb, args: &InspectArgs) {
+    let database = card_db.database();
+    println!("FlatBuffer database '{}'", args.path.display());
+    println!("   script version : {}", card_db.script_version());
+    println!("   generated at   : {}", card_db.generated_at_utc());
+    println!("   cards          : {}", card_db.len());
+    println!("   factions       : {}", database.factions().map_or(0, |v| v.len()));
+    println!("   rarities       : {}", database.rarities().map_or(0, |v| v.len()));
+    println!("   card types     : {}", database.card_types().map_or(0, |v| v.len()));
+    println!("   card sets      : {}", database.card_sets().map_or(0, |v| v.len()));
+    println!("   card sub types : {}", database.card_sub_types().map_or(0, |v| v.len()));
+    println!("   card families  : {}", database.card_families().map_or(0, |v| v.len()));
+    println!(
+        "   suspended      : {}",
+        card_db.cards().filter(|c| c.is_suspended()).count()
+    );
+    if args.suspensions {
+        let events = card_db.suspension_timeline(args.card.as_deref());
+        println!("\n   {} suspension status change(s)", events.len());
+        for event in events {
+            print_suspension_change(
+                event.date().unwrap_or("?"),
+                event.reference().unwrap_or("?"),
+                event.suspended(),
+            );
+        }
+    } else if let Some(reference) = &args.card {
+        match card_db.card(reference) {
+            Some(card) => println!("\n{:#?}", card.raw()),
+            None => println!("\n   Card '{}' not found", reference),
         }
     }
+}
 
+/// Prints an `OptimizedCardDatabase` (altered_cards_optimized_v2.fb).
+fn inspect_optimized_card_db(card_db: &OptimizedCardDb, args: &InspectArgs) {
+    let database = card_db.database();
+    println!("Optimized FlatBuffer database '{}'", args.path.display());
+    println!("   script version : {}", card_db.script_version());
+    println!("   generated at   : {}", card_db.generated_at_utc());
+    println!("   version        : {}", database.database_version());
+    println!("   cards          : {}", card_db.len());
+    println!("   factions       : {}", database.factions().map_or(0, |v| v.len()));
+    println!("   rarities       : {}", database.rarities().map_or(0, |v| v.len()));
+    println!("   card types     : {}", database.card_types().map_or(0, |v| v.len()));
     println!(
-        "\n   > ✅ Harvest complete. Found {} total card objects (pre-optimization).",
-        all_cards.len()
+        "   suspended      : {}",
+        card_db.cards().filter(|c| c.is_suspended()).count()
     );
-
-    // Save the raw data (now including the suspension flag) as a backup.
-    let raw_file = File::create(RAW_OUTPUT_FILENAME)?;
-    serde_json::to_writer_pretty(BufWriter::new(raw_file), &all_cards)?;
-    println!("   > Raw data saved to '{}'", RAW_OUTPUT_FILENAME);
-
-    Ok(all_cards)
+    if args.suspensions {
+        println!("\n   The optimized layout carries no suspension history");
+    } else if let Some(reference) = &args.card {
+        match card_db.card(reference) {
+            Some(card) => println!("\n{:#?}", card.raw()),
+            None => println!("\n   Card '{}' not found", reference),
+        }
+    }
 }
 
-/// ### STEP 2: OPTIMIZER ###
-/// Transforms the raw card data into optimized JSON and FlatBuffer structures.
-fn optimize_cards(harvested_cards: Vec<HarvestedCard>) -> Result<(), Box<dyn std::error::Error>> {
-    let mut lookup_tables = LookupTables {
-        rarities: BTreeMap::new(),
-        factions: BTreeMap::new(),
-        card_types: BTreeMap::new(),
-    };
-    let mut optimized_cards = BTreeMap::new();
-    let source_set = "Multiple Sets".to_string();
-
-    for harvested_card in &harvested_cards {
-        let card_value = &harvested_card.card_data;
-        let is_suspended = harvested_card.is_suspended;
-
-        let get_str = |obj: &serde_json::Value, key: &str| {
-            obj.get(key)
-                .and_then(|v| v.as_str())
-                .unwrap_or("")
-                .to_string()
-        };
-        let get_i64 = |obj: &serde_json::Value, key: &str| {
-            obj.get(key)
-                .and_then(|v| v.as_str())
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(0)
-        };
-
-        if let Some(rarity) = card_value.get("rarity") {
-            lookup_tables
-                .rarities
-                .entry(get_str(rarity, "reference"))
-                .or_insert_with(|| RarityInfo {
-                    name: get_str(rarity, "name"),
-                });
+/// ### INSPECTOR ###
+/// Prints a short summary of any file produced by the pipeline.
+fn inspect(args: &InspectArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let is_flatbuffer = args
+        .path
+        .extension()
+        .is_some_and(|ext| ext == "fb");
+
+    if is_flatbuffer {
+        let bytes = std::fs::read(&args.path)?;
+        match AnyCardDb::new(&bytes)? {
+            AnyCardDb::Full(card_db) => inspect_card_db(&card_db, args),
+            AnyCardDb::Optimized(card_db) => inspect_optimized_card_db(&card_db, args),
         }
-        if let Some(faction) = card_value.get("mainFaction") {
-            lookup_tables
-                .factions
-                .entry(get_str(faction, "reference"))
-                .or_insert_with(|| FactionInfo {
-                    name: get_str(faction, "name"),
-                    color: get_str(faction, "color"),
-                });
+        return Ok(());
+    }
+
+    let file = File::open(&args.path)?;
+    let value: serde_json::Value = serde_json::from_reader(BufReader::new(file))?;
+    if value.get("cards").is_some() {
+        let data: OptimizedData = serde_json::from_value(value)?;
+        println!("Optimized JSON '{}'", args.path.display());
+        println!("   script version : {}", data.meta.script_version);
+        println!("   generated at   : {}", data.meta.generated_at_utc);
+        println!("   cards          : {}", data.cards.len());
+        println!("   factions       : {}", data.lookup_tables.factions.len());
+        println!("   rarities       : {}", data.lookup_tables.rarities.len());
+        println!("   card types     : {}", data.lookup_tables.card_types.len());
+        println!("   card sets      : {}", data.lookup_tables.card_sets.len());
+        println!("   card sub types : {}", data.lookup_tables.card_sub_types.len());
+        println!("   card families  : {}", data.lookup_tables.card_families.len());
+        println!(
+            "   suspended      : {}",
+            data.cards.values().filter(|c| c.is_suspended).count()
+        );
+        for source in &data.meta.data_sources {
+            println!("   source         : {}", source);
         }
-        if let Some(card_type) = card_value.get("cardType") {
-            lookup_tables
-                .card_types
-                .entry(get_str(card_type, "reference"))
-                .or_insert_with(|| CardTypeInfo {
-                    name: get_str(card_type, "name"),
-                });
+        if args.suspensions {
+            let changes: Vec<_> = data
+                .suspension_history
+                .iter()
+                .filter(|change| args.card.as_ref().is_none_or(|r| &change.reference == r))
+                .collect();
+            println!("\n   {} suspension status change(s)", changes.len());
+            for change in changes {
+                print_suspension_change(&change.date.to_rfc3339(), &change.reference, change.suspended);
+            }
+        } else if let Some(reference) = &args.card {
+            match data.cards.get(reference) {
+                Some(card) => println!("\n{}", serde_json::to_string_pretty(card)?),
+                None => println!("\n   Card '{}' not found", reference),
+            }
         }
-
-        let card_reference_id = get_str(card_value, "reference");
-        if card_reference_id.is_empty() {
-            continue;
+    } else {
+        let raw_cards: Vec<HarvestedCard> = serde_json::from_value(value)?;
+        println!("Raw harvest '{}'", args.path.display());
+        println!("   card objects   : {}", raw_cards.len());
+        println!(
+            "   suspended      : {}",
+            raw_cards.iter().filter(|c| c.is_suspended).count()
+        );
+        if let Some(reference) = &args.card {
+            let matches: Vec<_> = raw_cards
+                .iter()
+                .filter(|c| &c.card_data.reference == reference)
+                .collect();
+            if matches.is_empty() {
+                println!("\n   Card '{}' not found", reference);
+            }
+            for card in matches {
+                println!("\n{}", serde_json::to_string_pretty(card)?);
+            }
         }
-
-        let elements = card_value
-            .get("elements")
-            .unwrap_or(&serde_json::Value::Null);
-        let card = OptimizedCard {
-            name: get_str(card_value, "name"),
-            type_ref: get_str(
-                card_value
-                    .get("cardType")
-                    .unwrap_or(&serde_json::Value::Null),
-                "reference",
-            ),
-            faction_ref: get_str(
-                card_value
-                    .get("mainFaction")
-                    .unwrap_or(&serde_json::Value::Null),
-                "reference",
-            ),
-            rarity_ref: get_str(
-                card_value.get("rarity").unwrap_or(&serde_json::Value::Null),
-                "reference",
-            ),
-            image_path: get_str(card_value, "imagePath"),
-            qr_url: get_str(card_value, "qrUrlDetail"),
-            main_cost: get_i64(elements, "MAIN_COST"),
-            recall_cost: get_i64(elements, "RECALL_COST"),
-            is_suspended, // Set the flag here
-            power: LocalPowerStats {
-                m: get_i64(elements, "MOUNTAIN_POWER"),
-                o: get_i64(elements, "OCEAN_POWER"),
-                f: get_i64(elements, "FOREST_POWER"),
-            },
-        };
-        // This will insert the card. If a suspended version is processed later,
-        // it will overwrite the non-suspended version, which is the desired behavior.
-        optimized_cards.insert(card_reference_id, card);
     }
+    Ok(())
+}
 
-    let final_data = OptimizedData {
-        meta: Meta {
-            script_version: SCRIPT_VERSION.to_string(),
-            generated_at_utc: Utc::now(),
-            source_set,
-            data_sources: QUERIES
-                .iter()
-                .map(|q| format!("{}{}", BASE_API_URL, q))
-                .collect(),
-            total_cards: optimized_cards.len(),
-        },
-        lookup_tables,
-        cards: optimized_cards,
-    };
-
+/// ### STEP 1b: VALIDATION ###
+/// Checks the raw harvest file, writes the report and fails when the
+/// configured thresholds are exceeded so that bad data never reaches the
+/// outputs. Returns the cards that parsed.
+fn validate_harvest(
+    raw_path: &Path,
+    config: &Config,
+    report_path: &Path,
+) -> Result<Vec<HarvestedCard>, Box<dyn std::error::Error>> {
+    let records = load_raw_records(raw_path)?;
+    let (raw_cards, report) = validate_records(records, config.harvest.raw_card_mode, &config.validation);
+    report.save(report_path)?;
     println!(
-        "\n   > ✅ Optimization complete. Processed {} unique cards.",
-        final_data.meta.total_cards
+        "   > Validated {} card objects: {} error(s), {} warning(s). Report saved to '{}'",
+        report.total_cards,
+        report.errors,
+        report.warnings,
+        report_path.display()
     );
-    
-    // Save JSON format
-    let output_file = File::create(OPTIMIZED_OUTPUT_FILENAME)?;
-    serde_json::to_writer_pretty(BufWriter::new(output_file), &final_data)?;
+    for issue in report.issues.iter().take(10) {
+        println!(
+            "     {:?} [{}] {} ({}): {}",
+            issue.severity, issue.code, issue.reference, issue.field, issue.message
+        );
+    }
+    if report.issues.len() > 10 {
+        println!("     ... and {} more", report.issues.len() - 10);
+    }
+    report.check_thresholds(&config.validation)?;
+    Ok(raw_cards)
+}
+
+/// ### STEP 2b: ASSETS ###
+/// Downloads the images (and assets) of every card into the local store and
+/// records where each file ended up.
+fn localize_assets(data: &mut OptimizedData, config: &Config) -> Result<(), Box<dyn std::error::Error>> {
+    let card_urls = |card: &OptimizedCard| -> Vec<String> {
+        let mut urls = vec![card.image_path.clone()];
+        if config.assets.include_assets {
+            urls.extend(card.assets.values().flatten().cloned());
+        }
+        urls.retain(|url| !url.is_empty());
+        urls
+    };
+    let urls: BTreeSet<String> = data.cards.values().flat_map(card_urls).collect();
+    let files = download_assets(&config.assets, &config.harvest, &urls)?;
+    for card in data.cards.values_mut() {
+        card.local_files = card_urls(card)
+            .into_iter()
+            .filter_map(|url| files.get(&url).map(|file| (url, file.clone())))
+            .collect();
+    }
+    let stored: BTreeSet<&String> = files.values().map(|file| &file.path).collect();
     println!(
-        "   > Optimized JSON data saved to '{}'",
-        OPTIMIZED_OUTPUT_FILENAME
+        "   > ✅ {} URL(s) stored as {} distinct file(s) in '{}'",
+        files.len(),
+        stored.len(),
+        config.assets.dir
     );
-    
-    // Generate FlatBuffer format
-    generate_flatbuffer(&final_data)?;
-    
-    // Generate advanced optimized formats
-    println!("\n   > Generating advanced optimized formats...");
-    optimizer_v2::save_optimized_formats(&final_data)?;
-    
-    // Create sample delta update
-    println!("\n   > Creating sample delta update system...");
-    let sample_delta = delta_manager::create_sample_delta();
-    delta_manager::DeltaManager::new("./deltas/")
-        .save_delta(&sample_delta, "sample_delta_v2.0.0_to_v2.0.1.json")?;
-    println!("     - Sample delta update saved for demonstration");
-
     Ok(())
 }
 
-/// ### STEP 3: FLATBUFFER GENERATOR ###
-/// Converts optimized data to ultra-fast FlatBuffer format.
-fn generate_flatbuffer(data: &OptimizedData) -> Result<(), Box<dyn std::error::Error>> {
-    let mut builder = FlatBufferBuilder::with_capacity(1024 * 1024); // 1MB initial capacity
-    
-    // Create factions vector
-    let mut faction_offsets = Vec::new();
-    for (reference, faction) in &data.lookup_tables.factions {
-        let reference_offset = builder.create_string(reference);
-        let name_offset = builder.create_string(&faction.name);
-        let color_offset = builder.create_string(&faction.color);
-        
-        let faction_offset = Faction::create(&mut builder, &FactionArgs {
-            reference: Some(reference_offset),
-            name: Some(name_offset),
-            color: Some(color_offset),
-        });
-        faction_offsets.push(faction_offset);
-    }
-    let factions_vector = builder.create_vector(&faction_offsets);
-    
-    // Create rarities vector
-    let mut rarity_offsets = Vec::new();
-    for (reference, rarity) in &data.lookup_tables.rarities {
-        let reference_offset = builder.create_string(reference);
-        let name_offset = builder.create_string(&rarity.name);
-        
-        let rarity_offset = Rarity::create(&mut builder, &RarityArgs {
-            reference: Some(reference_offset),
-            name: Some(name_offset),
-        });
-        rarity_offsets.push(rarity_offset);
-    }
-    let rarities_vector = builder.create_vector(&rarity_offsets);
-    
-    // Create card types vector
-    let mut card_type_offsets = Vec::new();
-    for (reference, card_type) in &data.lookup_tables.card_types {
-        let reference_offset = builder.create_string(reference);
-        let name_offset = builder.create_string(&card_type.name);
-        
-        let card_type_offset = CardType::create(&mut builder, &CardTypeArgs {
-            reference: Some(reference_offset),
-            name: Some(name_offset),
-        });
-        card_type_offsets.push(card_type_offset);
-    }
-    let card_types_vector = builder.create_vector(&card_type_offsets);
-    
-    // Create lookup maps for indices
-    let faction_map: BTreeMap<String, u8> = data.lookup_tables.factions.keys()
-        .enumerate().map(|(i, k)| (k.clone(), i as u8)).collect();
-    let rarity_map: BTreeMap<String, u8> = data.lookup_tables.rarities.keys()
-        .enumerate().map(|(i, k)| (k.clone(), i as u8)).collect();
-    let card_type_map: BTreeMap<String, u8> = data.lookup_tables.card_types.keys()
-        .enumerate().map(|(i, k)| (k.clone(), i as u8)).collect();
-    
-    // Create cards vector
-    let mut card_offsets = Vec::new();
-    for (reference, card) in &data.cards {
-        let reference_offset = builder.create_string(reference);
-        let name_offset = builder.create_string(&card.name);
-        let image_path_offset = builder.create_string(&card.image_path);
-        let qr_url_offset = builder.create_string(&card.qr_url);
-        
-        let power_stats = PowerStats::create(&mut builder, &PowerStatsArgs {
-            mountain: card.power.m as u8,
-            ocean: card.power.o as u8,
-            forest: card.power.f as u8,
-        });
-        
-        let card_offset = Card::create(&mut builder, &CardArgs {
-            reference: Some(reference_offset),
-            name: Some(name_offset),
-            faction_idx: *faction_map.get(&card.faction_ref).unwrap_or(&0),
-            rarity_idx: *rarity_map.get(&card.rarity_ref).unwrap_or(&0),
-            card_type_idx: *card_type_map.get(&card.type_ref).unwrap_or(&0),
-            main_cost: card.main_cost as u8,
-            recall_cost: card.recall_cost as u8,
-            power: Some(power_stats),
-            image_path: Some(image_path_offset),
-            qr_url: Some(qr_url_offset),
-            is_suspended: card.is_suspended,
-        });
-        card_offsets.push(card_offset);
+/// ### STEP 2c: IMAGE DERIVATIVES ###
+/// Generates the configured thumbnails and formats from the downloaded card
+/// images and points `meta` at their manifest.
+fn derive_images(data: &mut OptimizedData, config: &Config) -> Result<(), Box<dyn std::error::Error>> {
+    let sources: Vec<SourceImage> = data
+        .cards
+        .iter()
+        .filter_map(|(reference, card)| {
+            let file = card.local_files.get(&card.image_path)?;
+            Some(SourceImage {
+                reference: reference.clone(),
+                image_path: card.image_path.clone(),
+                file: file.clone(),
+            })
+        })
+        .collect();
+    if sources.len() < data.cards.len() {
+        println!(
+            "   > ⚠️ {} card(s) have no downloaded image; run the assets stage first",
+            data.cards.len() - sources.len()
+        );
     }
-    let cards_vector = builder.create_vector(&card_offsets);
-    
-    // Create metadata strings
-    let generated_at_offset = builder.create_string(&data.meta.generated_at_utc.to_rfc3339());
-    let script_version_offset = builder.create_string(&data.meta.script_version);
-    
-    // Create root table
-    let card_database = CardDatabase::create(&mut builder, &CardDatabaseArgs {
-        factions: Some(factions_vector),
-        rarities: Some(rarities_vector),
-        card_types: Some(card_types_vector),
-        cards: Some(cards_vector),
-        generated_at_utc: Some(generated_at_offset),
-        script_version: Some(script_version_offset),
-        total_cards: data.meta.total_cards as u32,
-    });
-    
-    builder.finish(card_database, None);
-    
-    // Write to file
-    let mut file = File::create(FLATBUFFER_OUTPUT_FILENAME)?;
-    file.write_all(builder.finished_data())?;
-    
-    let file_size = builder.finished_data().len();
-    println!(
-        "   > Ultra-fast FlatBuffer data saved to '{}' ({} KB)",
-        FLATBUFFER_OUTPUT_FILENAME,
-        file_size / 1024
-    );
-    println!("   > FlatBuffer provides zero-copy access and 500x faster queries!");
-    
+    let manifest_path = generate_derivatives(&config.derivatives, Path::new(&config.assets.dir), &sources)?;
+    data.meta.derivatives_manifest = Some(manifest_path.to_string_lossy().into_owned());
     Ok(())
 }
 
-/// ### MAIN ORCHESTRATOR ###
 /// Runs the entire data pipeline in sequence.
-fn main() {
-    println!("🚀 Starting Altered Data Pipeline...");
-
+fn run_pipeline(config: &Config, fresh: bool) -> Result<(), Box<dyn std::error::Error>> {
     // --- Step 1 ---
     println!("\n--- Step 1: Harvesting Cards from API ---");
-    match harvest_cards() {
-        Ok(raw_cards) => {
-            // --- Step 2 ---
-            println!("\n--- Step 2: Optimizing Raw Data ---");
-            if let Err(e) = optimize_cards(raw_cards) {
-                eprintln!("\n❌ Optimization failed: {}", e);
-                std::process::exit(1);
-            }
-        }
-        Err(e) => {
-            eprintln!("\n❌ Harvesting failed: {}", e);
-            std::process::exit(1);
-        }
+    harvest_cards(&config.harvest, Path::new(RAW_OUTPUT_FILENAME), fresh)
+        .map_err(|e| format!("Harvesting failed: {}", e))?;
+
+    println!("\n--- Validating Raw Data ---");
+    let raw_cards = validate_harvest(
+        Path::new(RAW_OUTPUT_FILENAME),
+        config,
+        Path::new(&config.validation.report_path),
+    )
+    .map_err(|e| format!("Validation failed: {}", e))?;
+
+    // --- Step 2 ---
+    println!("\n--- Step 2: Optimizing Raw Data ---");
+    let mut ledger = SuspensionLedger::load(Path::new(&config.suspensions.ledger_path))?;
+    let data_sources = load_data_sources(Path::new(RAW_OUTPUT_FILENAME))?;
+    let mut optimized = optimize_harvest(&raw_cards, data_sources, config, &mut ledger, Path::new(OPTIMIZED_OUTPUT_FILENAME))
+        .map_err(|e| format!("Optimization failed: {}", e))?;
+
+    println!("\n--- Saving Suspension Ledger ---");
+    save_suspension_ledger(&ledger, &config.suspensions)
+        .map_err(|e| format!("Saving the suspension ledger failed: {}", e))?;
+
+    if config.assets.enabled {
+        println!("\n--- Downloading Card Assets ---");
+        localize_assets(&mut optimized, config)
+            .and_then(|_| save_optimized_data(&optimized, Path::new(OPTIMIZED_OUTPUT_FILENAME)))
+            .map_err(|e| format!("Asset download failed: {}", e))?;
     }
+    if config.derivatives.enabled {
+        println!("\n--- Generating Image Derivatives ---");
+        derive_images(&mut optimized, config)
+            .and_then(|_| save_optimized_data(&optimized, Path::new(OPTIMIZED_OUTPUT_FILENAME)))
+            .map_err(|e| format!("Image derivative generation failed: {}", e))?;
+    }
+
+    // --- Step 3 ---
+    println!("\n--- Step 3: Generating FlatBuffers ---");
+    write_flatbuffers(&optimized, Path::new(FLATBUFFER_OUTPUT_FILENAME), false)
+        .map_err(|e| format!("FlatBuffer generation failed: {}", e))?;
 
     println!("\n✨✨ Pipeline finished successfully! ✨✨");
     println!("\n📊 Performance Summary:");
     println!("   → JSON format: Optimized structure with lookup tables");
     println!("   → FlatBuffer format: Zero-copy access, 80-90% size reduction, 500x faster queries");
     println!("   → Use FlatBuffer format for production applications requiring maximum performance!");
+    Ok(())
+}
+
+/// ### MAIN ORCHESTRATOR ###
+/// Dispatches to the requested pipeline stage.
+fn main() {
+    let cli = Cli::parse();
+    println!("🚀 Starting Altered Data Pipeline...");
+
+    let config = match Config::load(cli.config.as_deref()) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("\n❌ {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let result = match cli.command.unwrap_or(Command::Run { fresh: false }) {
+        Command::Run { fresh } => run_pipeline(&config, fresh),
+        Command::Harvest { output, fresh } => {
+            harvest_cards(&config.harvest, &output, fresh).map(|_| ())
+        }
+        Command::Validate { input, report } => {
+            let report_path = report.unwrap_or_else(|| config.validation.report_path.clone().into());
+            validate_harvest(&input, &config, &report_path).map(|_| ())
+        }
+        Command::Optimize { input, output, no_ledger, harvested_at } => {
+            validate_harvest(&input, &config, Path::new(&config.validation.report_path)).and_then(|mut raw_cards| {
+                if let Some(harvested_at) = harvested_at {
+                    for card in &mut raw_cards {
+                        card.harvested_at_utc.get_or_insert(harvested_at);
+                    }
+                }
+                let mut ledger = SuspensionLedger::load(Path::new(&config.suspensions.ledger_path))?;
+                optimize_harvest(&raw_cards, load_data_sources(&input)?, &config, &mut ledger, &output)?;
+                if no_ledger {
+                    println!("   > Suspension ledger left untouched");
+                    return Ok(());
+                }
+                save_suspension_ledger(&ledger, &config.suspensions)
+            })
+        }
+        Command::Assets { input } => load_optimized_data(&input).and_then(|mut data| {
+            localize_assets(&mut data, &config)?;
+            save_optimized_data(&data, &input)
+        }),
+        Command::Derivatives { input } => load_optimized_data(&input).and_then(|mut data| {
+            derive_images(&mut data, &config)?;
+            save_optimized_data(&data, &input)
+        }),
+        Command::Flatbuffer {
+            input,
+            output,
+            skip_advanced,
+        } => load_optimized_data(&input).and_then(|data| write_flatbuffers(&data, &output, skip_advanced)),
+        Command::SuspensionHistory { snapshots, output } => {
+            rebuild_suspension_history(&snapshots, &output)
+        }
+        Command::Sets => list_sets(&config),
+        Command::Delta(command) => run_delta(command),
+        Command::Inspect(args) => inspect(&args),
+    };
+
+    if let Err(e) = result {
+        eprintln!("\n❌ {}", e);
+        std::process::exit(1);
+    }
 }
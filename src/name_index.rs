@@ -0,0 +1,78 @@
+// Accent-insensitive trigram index over card names.
+// Names are folded to lowercase with the accents of Latin letters removed
+// (é -> e, œ -> oe, ß -> ss); any character other than a letter or a digit
+// separates words. Every word is padded with one space on each side and cut
+// into trigrams, so "Sierra" gives " si", "sie", "ier", "err", "rra" and
+// "ra ". The card FlatBuffer stores, for every trigram, the cards having a
+// name in any locale that contains it; `CardDb::search_names` looks the
+// trigrams of a query up in it.
+
+use std::collections::BTreeSet;
+
+/// Lowercases `name`, removes accents and joins its words with single spaces.
+pub fn fold(name: &str) -> String {
+    let mut folded = String::with_capacity(name.len());
+    for c in name.chars().flat_map(char::to_lowercase) {
+        match c {
+            'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => folded.push('a'),
+            'ç' | 'ć' | 'č' => folded.push('c'),
+            'ď' | 'đ' => folded.push('d'),
+            'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ė' | 'ę' | 'ě' => folded.push('e'),
+            'ğ' => folded.push('g'),
+            'ì' | 'í' | 'î' | 'ï' | 'ī' | 'į' | 'ı' => folded.push('i'),
+            'ł' => folded.push('l'),
+            'ñ' | 'ń' | 'ň' => folded.push('n'),
+            'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ő' => folded.push('o'),
+            'ř' => folded.push('r'),
+            'ś' | 'š' | 'ş' => folded.push('s'),
+            'ť' | 'ţ' => folded.push('t'),
+            'ù' | 'ú' | 'û' | 'ü' | 'ū' | 'ů' | 'ű' => folded.push('u'),
+            'ý' | 'ÿ' => folded.push('y'),
+            'ź' | 'ż' | 'ž' => folded.push('z'),
+            'æ' => folded.push_str("ae"),
+            'œ' => folded.push_str("oe"),
+            'ß' => folded.push_str("ss"),
+            c if c.is_alphanumeric() => folded.push(c),
+            _ => folded.push(' '),
+        }
+    }
+    folded.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Trigrams of one word, padded with a space before and, when `closed`,
+/// after it. A word too short to give a trigram gives its padded form.
+fn word_grams(word: &str, closed: bool, grams: &mut Vec<String>) {
+    let mut chars = vec![' '];
+    chars.extend(word.chars());
+    if closed {
+        chars.push(' ');
+    }
+    if chars.len() < 3 {
+        grams.push(chars.into_iter().collect());
+        return;
+    }
+    grams.extend(chars.windows(3).map(|window| window.iter().collect()));
+}
+
+/// Trigrams indexed for a folded name.
+pub fn name_grams(folded: &str) -> BTreeSet<String> {
+    let mut grams = Vec::new();
+    for word in folded.split(' ').filter(|word| !word.is_empty()) {
+        word_grams(word, true, &mut grams);
+    }
+    grams.into_iter().collect()
+}
+
+/// Trigrams looked up for a folded query. Its last word is left open so that
+/// it matches as a prefix; a single letter gives the two-character gram
+/// " x", to be matched against every trigram it starts.
+pub fn query_grams(folded: &str) -> Vec<String> {
+    let words: Vec<&str> = folded.split(' ').filter(|word| !word.is_empty()).collect();
+    let mut grams = Vec::new();
+    for (i, word) in words.iter().enumerate() {
+        word_grams(word, i + 1 < words.len(), &mut grams);
+    }
+    grams.sort();
+    grams.dedup();
+    grams
+}
@@ -1,250 +1,576 @@
-// Version: 1.2.0
-// Description: A standalone script to optimize raw card data for the game "Altered".
-//              It reads a raw JSON file harvested by the main script, processes it
-//              into a structured format, adds lookup tables, and includes metadata.
-//              This version is updated to handle the `is_suspended` flag.
+// Optimization stage of the pipeline.
+// Turns a raw harvest into the optimized JSON structure: cards keyed by
+// reference, merged across locales, pointing into lookup tables of rarities,
+// factions, card types, sets, sub types and families. The full pipeline and
+// the standalone `optimize` command both go through `optimize_harvest`, so a
+// raw harvest gives the same output whichever way it is optimized.
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use std::fs::File;
 use std::io::{BufReader, BufWriter};
+use std::path::{Path, PathBuf};
 
-// --- Configuration ---
-const SCRIPT_VERSION: &str = "1.2.0"; // Version of this optimizer script
-const SOURCE_FILE: &str = "altered_all_cards.json";
-const OUTPUT_FILE: &str = "altered_optimized.json";
-
-// --- Input Data Structures ---
-// This struct must match the output of the harvester function in main.rs
-#[derive(Deserialize)]
-struct HarvestedCard {
-    card_data: serde_json::Value,
-    is_suspended: bool,
-}
-
-// --- Output Data Structures ---
-#[derive(Serialize)]
-struct Meta {
-    script_version: String,
-    generated_at_utc: DateTime<Utc>,
-    source_set: String,
-    data_sources: Vec<String>,
-    total_cards: usize,
-}
+use crate::asset_store::LocalFile;
+use crate::config::{Config, SuspensionConfig};
+use crate::details::CardDetail;
+use crate::harvester::{harvest_date, harvested_sets, HarvestedCard};
+use crate::reference::CardReference;
+use crate::suspensions::{collect_evidence, find_disagreements, SuspensionLedger, SuspensionStatusChange};
+
+/// Version recorded in the outputs.
+pub const SCRIPT_VERSION: &str = "2.0.0";
 
-#[derive(Serialize)]
-struct RarityInfo {
-    name: String,
+#[derive(Serialize, Deserialize)]
+pub struct Meta {
+    pub script_version: String,
+    pub generated_at_utc: DateTime<Utc>,
+    pub source_set: String,
+    pub data_sources: Vec<String>,
+    pub total_cards: usize,
+    // Manifest of the image derivatives, see `derivatives::DerivativeManifest`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub derivatives_manifest: Option<String>,
 }
 
-#[derive(Serialize)]
-struct FactionInfo {
-    name: String,
-    color: String,
+// `name` is in the primary locale, `names` holds every harvested locale.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RarityInfo {
+    pub name: String,
+    #[serde(default)]
+    pub names: BTreeMap<String, String>,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FactionInfo {
+    pub name: String,
+    pub color: String,
+    #[serde(default)]
+    pub names: BTreeMap<String, String>,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CardTypeInfo {
+    pub name: String,
+    #[serde(default)]
+    pub names: BTreeMap<String, String>,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CardSetInfo {
+    pub name: String,
+    #[serde(default)]
+    pub names: BTreeMap<String, String>,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CardSubTypeInfo {
+    pub name: String,
+    #[serde(default)]
+    pub names: BTreeMap<String, String>,
 }
 
-#[derive(Serialize)]
-struct CardTypeInfo {
-    name: String,
+// A card family groups every printing and rarity variant of one card.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CardFamilyMemberInfo {
+    pub reference: String,
+    pub set: String,
+    pub product: String,
+    pub variant: String,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CardFamilyInfo {
+    pub set: String,
+    pub faction: String,
+    pub number: String,
+    pub members: Vec<CardFamilyMemberInfo>,
 }
 
-#[derive(Serialize)]
-struct LookupTables {
-    rarities: BTreeMap<String, RarityInfo>,
-    factions: BTreeMap<String, FactionInfo>,
-    card_types: BTreeMap<String, CardTypeInfo>,
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LookupTables {
+    pub rarities: BTreeMap<String, RarityInfo>,
+    pub factions: BTreeMap<String, FactionInfo>,
+    pub card_types: BTreeMap<String, CardTypeInfo>,
+    #[serde(default)]
+    pub card_sets: BTreeMap<String, CardSetInfo>,
+    #[serde(default)]
+    pub card_sub_types: BTreeMap<String, CardSubTypeInfo>,
+    #[serde(default)]
+    pub card_families: BTreeMap<String, CardFamilyInfo>,
 }
 
-#[derive(Serialize)]
-struct PowerStats {
-    m: i64,
-    o: i64,
-    f: i64,
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LocalPowerStats {
+    pub m: i64,
+    pub o: i64,
+    pub f: i64,
 }
 
-#[derive(Serialize)]
-struct OptimizedCard {
-    name: String,
-    type_ref: String,
-    faction_ref: String,
-    rarity_ref: String,
-    image_path: String,
-    qr_url: String,
-    main_cost: i64,
-    recall_cost: i64,
-    is_suspended: bool, // New field
-    power: PowerStats,
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OptimizedCard {
+    pub name: String,
+    pub type_ref: String,
+    pub faction_ref: String,
+    pub rarity_ref: String,
+    pub image_path: String,
+    pub qr_url: String,
+    pub main_cost: i64,
+    pub recall_cost: i64,
+    // Returned by the suspended-cards query or flagged `isSuspended`
+    pub is_suspended: bool,
+    pub power: LocalPowerStats,
+    // Per-locale variants of `name` and `image_path`, keyed by locale
+    #[serde(default)]
+    pub names: BTreeMap<String, String>,
+    #[serde(default)]
+    pub image_paths: BTreeMap<String, String>,
+    // API ULID of the card
+    #[serde(default)]
+    pub id: String,
+    #[serde(default)]
+    pub set_ref: String,
+    #[serde(default)]
+    pub sub_type_refs: Vec<String>,
+    // Asset URLs grouped by kind, e.g. "WEB"
+    #[serde(default)]
+    pub assets: BTreeMap<String, Vec<String>>,
+    // `isSuspended` as reported by the API on any of the card's records
+    #[serde(default)]
+    pub raw_is_suspended: bool,
+    // First run that saw the card suspended, from the suspension ledger
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suspended_since: Option<DateTime<Utc>>,
+    // Key into `card_families`, e.g. "CORE_AX_01"
+    #[serde(default)]
+    pub family_ref: String,
+    // Effect text, flavor and keywords per locale, from the detail pass
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub texts: BTreeMap<String, CardDetail>,
+    // Downloaded copies of `image_path` and `assets`, keyed by URL
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub local_files: BTreeMap<String, LocalFile>,
 }
 
-#[derive(Serialize)]
-struct OptimizedData {
-    meta: Meta,
-    lookup_tables: LookupTables,
-    cards: BTreeMap<String, OptimizedCard>,
+#[derive(Serialize, Deserialize)]
+pub struct OptimizedData {
+    pub meta: Meta,
+    pub lookup_tables: LookupTables,
+    pub cards: BTreeMap<String, OptimizedCard>,
+    // Every suspension and unsuspension recorded by the ledger, oldest first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub suspension_history: Vec<SuspensionStatusChange>,
 }
 
-// --- Main Logic ---
-// This function is not called by main.rs but can be used if you want to run optimization separately.
-pub fn run_optimizer() -> Result<(), Box<dyn std::error::Error>> {
-    println!("⚙️  Starting JSON optimization (Standalone Rust version)...");
+/// Loads an optimized JSON file previously saved by `optimize_cards`.
+pub fn load_optimized_data(path: &Path) -> Result<OptimizedData, Box<dyn std::error::Error>> {
+    let file = File::open(path)
+        .map_err(|e| format!("cannot open optimized data '{}': {}", path.display(), e))?;
+    Ok(serde_json::from_reader(BufReader::new(file))?)
+}
 
-    // --- 1. Load and Parse Source File ---
-    println!("   > Loading raw data from '{}'...", SOURCE_FILE);
-    let source_file = match File::open(SOURCE_FILE) {
-        Ok(file) => file,
-        Err(_) => {
-            eprintln!(
-                "   ❌ Error: Source file '{}' not found. Please run the harvester first.",
-                SOURCE_FILE
-            );
-            return Ok(()); // Exit gracefully
-        }
-    };
-    let reader = BufReader::new(source_file);
+pub fn save_optimized_data(data: &OptimizedData, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    let output_file = File::create(path)?;
+    serde_json::to_writer_pretty(BufWriter::new(output_file), data)?;
+    Ok(())
+}
 
-    // Parse into the HarvestedCard structure
-    let raw_harvest: Vec<HarvestedCard> = serde_json::from_reader(reader)?;
-    println!("   > Loaded {} raw card objects.", raw_harvest.len());
+/// Optimizes a raw harvest with the settings of `config` and saves the result
+/// to `output_path`. `data_sources` are the query URLs recorded with the
+/// harvest, see `load_data_sources`. Used by both the full pipeline and the
+/// `optimize` command, which then save the updated `ledger` with
+/// `save_suspension_ledger`.
+pub fn optimize_harvest(
+    harvested_cards: &[HarvestedCard],
+    data_sources: Vec<String>,
+    config: &Config,
+    ledger: &mut SuspensionLedger,
+    output_path: &Path,
+) -> Result<OptimizedData, Box<dyn std::error::Error>> {
+    optimize_cards(
+        harvested_cards,
+        data_sources,
+        config.harvest.primary_locale(),
+        ledger,
+        output_path,
+    )
+}
 
-    // --- 2. Initialize Structures for Optimized Data ---
+/// Transforms the raw card data into the optimized JSON structure.
+///
+/// Records harvested in several locales are merged by reference: the primary
+/// locale fills the single-language fields and every locale is added to the
+/// `names`/`image_paths` maps. Suspension is derived from every record of a
+/// card and dated at the harvest through `ledger`, which is only updated in
+/// memory. Lookup references that are empty are left out of the lookup
+/// tables.
+pub fn optimize_cards(
+    harvested_cards: &[HarvestedCard],
+    data_sources: Vec<String>,
+    primary_locale: &str,
+    ledger: &mut SuspensionLedger,
+    output_path: &Path,
+) -> Result<OptimizedData, Box<dyn std::error::Error>> {
     let mut lookup_tables = LookupTables {
         rarities: BTreeMap::new(),
         factions: BTreeMap::new(),
         card_types: BTreeMap::new(),
+        card_sets: BTreeMap::new(),
+        card_sub_types: BTreeMap::new(),
+        card_families: BTreeMap::new(),
+    };
+    let mut optimized_cards: BTreeMap<String, OptimizedCard> = BTreeMap::new();
+    let now = Utc::now();
+    // Named after the set when the harvest covers a single one.
+    let sets: Vec<String> = harvested_sets(harvested_cards)
+        .into_iter()
+        .filter(|set| !set.is_empty())
+        .collect();
+    let source_set = match sets.as_slice() {
+        [set] => set.clone(),
+        _ => "Multiple Sets".to_string(),
     };
-    let mut optimized_cards = BTreeMap::new();
-
-    // Since we don't know the exact source set from the raw file, we make a reasonable guess or use a generic value.
-    let source_set = raw_harvest
-        .get(0)
-        .and_then(|h| h.card_data.get("cardSet"))
-        .and_then(|s| s.get("reference"))
-        .and_then(|r| r.as_str())
-        .unwrap_or("Multiple Sets") // Default to generic value
-        .to_string();
-
-    // --- 3. Process Each Card ---
-    for harvested_card in &raw_harvest {
-        let card_value = &harvested_card.card_data;
-        let is_suspended = harvested_card.is_suspended;
-
-        // Use helper closures for clean, safe data extraction
-        let get_str = |obj: &serde_json::Value, key: &str| -> String {
-            obj.get(key)
-                .and_then(|v| v.as_str())
-                .unwrap_or("")
-                .to_string()
-        };
-        let get_i64 = |obj: &serde_json::Value, key: &str| -> i64 {
-            obj.get(key)
-                .and_then(|v| v.as_str())
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(0)
-        };
 
-        // Populate lookup tables
-        if let Some(rarity) = card_value.get("rarity") {
-            let rarity_ref = get_str(rarity, "reference");
-            if !rarity_ref.is_empty() {
-                lookup_tables
-                    .rarities
-                    .entry(rarity_ref)
-                    .or_insert_with(|| RarityInfo {
-                        name: get_str(rarity, "name"),
-                    });
-            }
+    for harvested_card in harvested_cards {
+        let raw_card = &harvested_card.card_data;
+        let locale = &harvested_card.locale;
+        let is_primary = locale == primary_locale;
+
+        let rarity = &raw_card.rarity;
+        if !rarity.reference.is_empty() {
+            let info = lookup_tables
+                .rarities
+                .entry(rarity.reference.clone())
+                .or_insert_with(|| RarityInfo {
+                    name: rarity.name.clone(),
+                    names: BTreeMap::new(),
+                });
+            record_name(&mut info.name, &mut info.names, locale, is_primary, &rarity.name);
         }
-        if let Some(faction) = card_value.get("mainFaction") {
-            let faction_ref = get_str(faction, "reference");
-            if !faction_ref.is_empty() {
-                lookup_tables
-                    .factions
-                    .entry(faction_ref)
-                    .or_insert_with(|| FactionInfo {
-                        name: get_str(faction, "name"),
-                        color: get_str(faction, "color"),
-                    });
-            }
+
+        let faction = &raw_card.main_faction;
+        if !faction.reference.is_empty() {
+            let info = lookup_tables
+                .factions
+                .entry(faction.reference.clone())
+                .or_insert_with(|| FactionInfo {
+                    name: faction.name.clone(),
+                    color: faction.color.clone(),
+                    names: BTreeMap::new(),
+                });
+            record_name(&mut info.name, &mut info.names, locale, is_primary, &faction.name);
+        }
+
+        let card_type = &raw_card.card_type;
+        if !card_type.reference.is_empty() {
+            let info = lookup_tables
+                .card_types
+                .entry(card_type.reference.clone())
+                .or_insert_with(|| CardTypeInfo {
+                    name: card_type.name.clone(),
+                    names: BTreeMap::new(),
+                });
+            record_name(&mut info.name, &mut info.names, locale, is_primary, &card_type.name);
         }
-        if let Some(card_type) = card_value.get("cardType") {
-            let type_ref = get_str(card_type, "reference");
-            if !type_ref.is_empty() {
-                lookup_tables
-                    .card_types
-                    .entry(type_ref)
-                    .or_insert_with(|| CardTypeInfo {
-                        name: get_str(card_type, "name"),
-                    });
+
+        let card_set = &raw_card.card_set;
+        if !card_set.reference.is_empty() {
+            let info = lookup_tables
+                .card_sets
+                .entry(card_set.reference.clone())
+                .or_insert_with(|| CardSetInfo {
+                    name: card_set.name.clone(),
+                    names: BTreeMap::new(),
+                });
+            record_name(&mut info.name, &mut info.names, locale, is_primary, &card_set.name);
+        }
+
+        for sub_type in &raw_card.card_sub_types {
+            if sub_type.reference.is_empty() {
+                continue;
             }
+            let info = lookup_tables
+                .card_sub_types
+                .entry(sub_type.reference.clone())
+                .or_insert_with(|| CardSubTypeInfo {
+                    name: sub_type.name.clone(),
+                    names: BTreeMap::new(),
+                });
+            record_name(&mut info.name, &mut info.names, locale, is_primary, &sub_type.name);
         }
 
-        // Create the simplified, optimized card object
-        let card_reference_id = get_str(card_value, "reference");
+        let card_reference_id = raw_card.reference.clone();
         if card_reference_id.is_empty() {
-            continue; // Skip cards without a reference ID
+            continue;
         }
 
-        let elements = card_value
-            .get("elements")
-            .unwrap_or(&serde_json::Value::Null);
-
+        // Heroes have no costs and tokens no powers, so absent elements count as 0.
+        let elements = &raw_card.elements;
         let card = OptimizedCard {
-            name: get_str(card_value, "name"),
-            type_ref: get_str(
-                card_value
-                    .get("cardType")
-                    .unwrap_or(&serde_json::Value::Null),
-                "reference",
-            ),
-            faction_ref: get_str(
-                card_value
-                    .get("mainFaction")
-                    .unwrap_or(&serde_json::Value::Null),
-                "reference",
-            ),
-            rarity_ref: get_str(
-                card_value.get("rarity").unwrap_or(&serde_json::Value::Null),
-                "reference",
-            ),
-            image_path: get_str(card_value, "imagePath"),
-            qr_url: get_str(card_value, "qrUrlDetail"),
-            main_cost: get_i64(elements, "MAIN_COST"),
-            recall_cost: get_i64(elements, "RECALL_COST"),
-            is_suspended, // Set the flag
-            power: PowerStats {
-                m: get_i64(elements, "MOUNTAIN_POWER"),
-                o: get_i64(elements, "OCEAN_POWER"),
-                f: get_i64(elements, "FOREST_POWER"),
+            name: raw_card.name.clone(),
+            type_ref: card_type.reference.clone(),
+            faction_ref: faction.reference.clone(),
+            rarity_ref: rarity.reference.clone(),
+            image_path: raw_card.image_path.clone(),
+            qr_url: raw_card.qr_url_detail.clone(),
+            main_cost: elements.main_cost.unwrap_or(0),
+            recall_cost: elements.recall_cost.unwrap_or(0),
+            // Both flags are derived from every record of the card below.
+            is_suspended: false,
+            power: LocalPowerStats {
+                m: elements.mountain_power.unwrap_or(0),
+                o: elements.ocean_power.unwrap_or(0),
+                f: elements.forest_power.unwrap_or(0),
+            },
+            names: BTreeMap::new(),
+            image_paths: BTreeMap::new(),
+            id: raw_card.id.clone().unwrap_or_default(),
+            set_ref: card_set.reference.clone(),
+            sub_type_refs: raw_card
+                .card_sub_types
+                .iter()
+                .map(|sub_type| sub_type.reference.clone())
+                .collect(),
+            assets: raw_card.assets.clone(),
+            raw_is_suspended: false,
+            suspended_since: None,
+            family_ref: String::new(),
+            texts: BTreeMap::new(),
+            local_files: BTreeMap::new(),
+        };
+        let localized_name = card.name.clone();
+        let localized_image_path = card.image_path.clone();
+
+        // Primary-locale records replace the card while keeping the
+        // translations gathered so far. Other locales only contribute
+        // translations.
+        let mut merged = match optimized_cards.remove(&card_reference_id) {
+            Some(existing) if !is_primary => existing,
+            Some(existing) => OptimizedCard {
+                names: existing.names,
+                image_paths: existing.image_paths,
+                texts: existing.texts,
+                ..card
             },
+            None => card,
         };
-        optimized_cards.insert(card_reference_id, card);
+        merged.names.insert(locale.clone(), localized_name);
+        merged.image_paths.insert(locale.clone(), localized_image_path);
+        if let Some(detail) = &harvested_card.details {
+            merged.texts.insert(locale.clone(), detail.clone());
+        }
+        optimized_cards.insert(card_reference_id, merged);
     }
 
-    println!(
-        "   > Optimization complete. Processed {} unique cards.",
-        optimized_cards.len()
-    );
+    let suspension_history = track_suspensions(harvested_cards, &mut optimized_cards, ledger)?;
+    lookup_tables.card_families = build_card_families(&mut optimized_cards);
 
-    // --- 4. Assemble and Save the Final Optimized Data ---
     let final_data = OptimizedData {
         meta: Meta {
             script_version: SCRIPT_VERSION.to_string(),
-            generated_at_utc: Utc::now(),
+            generated_at_utc: now,
             source_set,
-            // Since this script only reads the file, we can't know the original queries.
-            data_sources: vec![format!("Loaded from '{}'", SOURCE_FILE)],
+            data_sources,
             total_cards: optimized_cards.len(),
+            derivatives_manifest: None,
         },
         lookup_tables,
         cards: optimized_cards,
+        suspension_history,
+    };
+
+    println!(
+        "\n   > ✅ Optimization complete. Processed {} unique cards.",
+        final_data.meta.total_cards
+    );
+
+    // Save JSON format
+    save_optimized_data(&final_data, output_path)?;
+    println!(
+        "   > Optimized JSON data saved to '{}'",
+        output_path.display()
+    );
+
+    Ok(final_data)
+}
+
+/// Derives `is_suspended` from both the suspended-cards query and the raw
+/// `isSuspended` field, whatever the order of the records, reports the cards
+/// where the two disagree and dates every change with the harvest through the
+/// ledger. Returns the full history of status changes, which is published.
+fn track_suspensions(
+    harvested_cards: &[HarvestedCard],
+    cards: &mut BTreeMap<String, OptimizedCard>,
+    ledger: &mut SuspensionLedger,
+) -> Result<Vec<SuspensionStatusChange>, Box<dyn std::error::Error>> {
+    let evidence = collect_evidence(harvested_cards);
+    for (reference, card) in cards.iter_mut() {
+        let evidence = evidence.get(reference).copied().unwrap_or_default();
+        card.is_suspended = evidence.is_suspended();
+        card.raw_is_suspended = evidence.flagged;
+    }
+
+    let conflicts = find_disagreements(&evidence);
+    if !conflicts.is_empty() {
+        println!(
+            "   > ⚠️ {} card(s) with conflicting suspension status:",
+            conflicts.len()
+        );
+        for (reference, evidence) in conflicts.iter().take(10) {
+            let reason = if evidence.listed {
+                "returned by the suspended-cards query but not flagged `isSuspended`"
+            } else {
+                "flagged `isSuspended` but missing from the suspended-cards query"
+            };
+            println!("     {}: {}", reference, reason);
+        }
+        if conflicts.len() > 10 {
+            println!("     ... and {} more", conflicts.len() - 10);
+        }
+    }
+
+    // Suspension dates come from the ledger, so an undated harvest cannot
+    // record changes without making one up.
+    let Some(harvested_at) = harvest_date(harvested_cards) else {
+        for (reference, card) in cards.iter_mut() {
+            card.suspended_since = ledger.suspended_since(reference);
+        }
+        println!("   > ⚠️ The harvest carries no date; the suspension ledger was left unchanged");
+        return Ok(ledger.history.clone());
     };
+    let changes = ledger.update(
+        cards.iter().map(|(reference, card)| (reference.as_str(), card.is_suspended)),
+        harvested_at,
+    );
+    for (reference, card) in cards.iter_mut() {
+        card.suspended_since = ledger.suspended_since(reference);
+    }
+    match changes {
+        Some(changes) => println!(
+            "   > {} suspended card(s), {} newly suspended, {} lifted as of {}",
+            ledger.suspended.len(),
+            changes.suspended.len(),
+            changes.lifted.len(),
+            harvested_at.to_rfc3339()
+        ),
+        None => println!(
+            "   > ⚠️ Harvest from {} predates the suspension ledger; the ledger was left unchanged",
+            harvested_at.to_rfc3339()
+        ),
+    }
+    Ok(ledger.history.clone())
+}
 
-    println!("   > 💾 Saving optimized data to '{}'...", OUTPUT_FILE);
-    let output_file = File::create(OUTPUT_FILE)?;
-    let writer = BufWriter::new(output_file);
-    serde_json::to_writer_pretty(writer, &final_data)?;
-    println!("   > ✨ Save successful!");
+/// Saves the ledger updated by `optimize_harvest` and publishes its history.
+pub fn save_suspension_ledger(
+    ledger: &SuspensionLedger,
+    config: &SuspensionConfig,
+) -> Result<(), Box<dyn std::error::Error>> {
+    ledger.save(Path::new(&config.ledger_path))?;
+    println!("   > Suspension ledger saved to '{}'", config.ledger_path);
+    ledger.history().save(Path::new(&config.history_path))?;
+    println!(
+        "   > Suspension history of {} status change(s) saved to '{}'",
+        ledger.history.len(),
+        config.history_path
+    );
+    Ok(())
+}
 
+/// Rebuilds the suspension history from optimized outputs of earlier runs,
+/// for changes that predate the ledger.
+pub fn rebuild_suspension_history(snapshots: &[PathBuf], output: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    let mut statuses = Vec::new();
+    for path in snapshots {
+        let data = load_optimized_data(path)?;
+        let snapshot: BTreeMap<String, bool> = data
+            .cards
+            .iter()
+            .map(|(reference, card)| (reference.clone(), card.is_suspended))
+            .collect();
+        statuses.push((data.meta.generated_at_utc, snapshot));
+    }
+    let history = SuspensionLedger::from_snapshots(statuses).history();
+    history.save(output)?;
+    println!(
+        "   > ✅ {} status change(s) across {} snapshot(s) saved to '{}'",
+        history.changes.len(),
+        snapshots.len(),
+        output.display()
+    );
     Ok(())
 }
+
+/// Groups the cards into families of printings and rarity variants using the
+/// reference grammar, and points every card at its family. A reference that
+/// does not follow the grammar forms a family of its own.
+fn build_card_families(
+    cards: &mut BTreeMap<String, OptimizedCard>,
+) -> BTreeMap<String, CardFamilyInfo> {
+    let mut families: BTreeMap<String, CardFamilyInfo> = BTreeMap::new();
+    let mut unparsed = 0;
+    for (reference, card) in cards.iter_mut() {
+        let (key, family, member) = match CardReference::parse(reference) {
+            Ok(parsed) => (
+                parsed.family_key(),
+                CardFamilyInfo {
+                    set: parsed.canonical_set().to_string(),
+                    faction: parsed.faction.clone(),
+                    number: parsed.number.clone(),
+                    members: Vec::new(),
+                },
+                CardFamilyMemberInfo {
+                    reference: reference.clone(),
+                    set: parsed.set.clone(),
+                    product: parsed.product.clone(),
+                    variant: parsed.variant(),
+                },
+            ),
+            Err(_) => {
+                unparsed += 1;
+                (
+                    reference.clone(),
+                    CardFamilyInfo {
+                        set: card.set_ref.clone(),
+                        faction: card.faction_ref.clone(),
+                        number: String::new(),
+                        members: Vec::new(),
+                    },
+                    CardFamilyMemberInfo {
+                        reference: reference.clone(),
+                        set: card.set_ref.clone(),
+                        product: String::new(),
+                        variant: String::new(),
+                    },
+                )
+            }
+        };
+        card.family_ref = key.clone();
+        families.entry(key).or_insert(family).members.push(member);
+    }
+    if unparsed > 0 {
+        println!(
+            "   > ⚠️ {} card reference(s) do not follow the reference grammar",
+            unparsed
+        );
+    }
+    println!(
+        "   > Grouped {} cards into {} card families",
+        cards.len(),
+        families.len()
+    );
+    families
+}
+
+/// Records the name of a lookup table entry in `locale`. The first name seen
+/// in the primary locale becomes the entry's single-language `name`.
+fn record_name(
+    name: &mut String,
+    names: &mut BTreeMap<String, String>,
+    locale: &str,
+    is_primary: bool,
+    value: &str,
+) {
+    if is_primary && !names.contains_key(locale) {
+        *name = value.to_string();
+    }
+    names
+        .entry(locale.to_string())
+        .or_insert_with(|| value.to_string());
+}
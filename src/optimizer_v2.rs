@@ -1,24 +1,32 @@
 // Optimized card data processing with advanced compression techniques
 // Features: Numeric IDs, string pools, bit-packed power values, compression
 
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{HashMap, HashSet};
 use std::fs::File;
-use std::io::{BufWriter, Write};
-use std::hash::{Hash, Hasher};
-use std::collections::hash_map::DefaultHasher;
-use serde_json;
+use std::io::Write;
 use flate2::write::GzEncoder;
 use flate2::Compression;
 use lz4_flex::compress_prepend_size;
 use flatbuffers::FlatBufferBuilder;
 
-use crate::OptimizedData;
+use crate::cards_optimized_generated::altered_cards as fb;
+use crate::checkpoint::stable_hash;
+use crate::optimizer::OptimizedData;
+
+// Bit 0 of `Card.flags`
+pub const FLAG_SUSPENDED: u8 = 1;
 
 // String pool for deduplication
 #[derive(Debug)]
 pub struct StringPool {
     strings: Vec<String>,
-    string_to_index: HashMap<String, u16>,
+    string_to_index: HashMap<String, u32>,
+}
+
+impl Default for StringPool {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl StringPool {
@@ -29,11 +37,12 @@ impl StringPool {
         }
     }
     
-    pub fn add_string(&mut self, s: &str) -> u16 {
+    // uint32 indices outnumber the strings a FlatBuffer under 2 GiB can hold
+    pub fn add_string(&mut self, s: &str) -> u32 {
         if let Some(&index) = self.string_to_index.get(s) {
             index
         } else {
-            let index = self.strings.len() as u16;
+            let index = self.strings.len() as u32;
             self.strings.push(s.to_string());
             self.string_to_index.insert(s.to_string(), index);
             index
@@ -46,10 +55,11 @@ impl StringPool {
 }
 
 // Generate numeric ID from string reference
+// Uses the stable hash so IDs written to the database survive Rust upgrades.
+// A card whose ID is already taken gets the next free one, see
+// `create_optimized_database`.
 pub fn generate_numeric_id(reference: &str) -> u32 {
-    let mut hasher = DefaultHasher::new();
-    reference.hash(&mut hasher);
-    hasher.finish() as u32
+    stable_hash(reference) as u32
 }
 
 // Pack power values into single u32 (8 bits each for mountain, ocean, forest)
@@ -69,51 +79,89 @@ pub fn unpack_power_values(packed: u32) -> (u8, u8, u8) {
 #[derive(Debug)]
 pub struct OptimizedCard {
     pub id: u32,
-    pub reference_idx: u16,
-    pub name_idx: u16,
+    pub reference_idx: u32,
+    pub name_idx: u32,
     pub faction_id: u16,
     pub rarity_id: u16,
     pub card_type_id: u16,
     pub main_cost: u8,
     pub recall_cost: u8,
     pub power_packed: u32,
-    pub image_path_idx: u16,
-    pub qr_url_idx: u16,
+    pub image_path_idx: u32,
+    pub qr_url_idx: u32,
     pub flags: u8,  // bit 0: is_suspended
 }
 
+// Narrow a cost or power value to the ubyte/8-bit fields of the schema
+pub(crate) fn to_u8(value: i64, field: &str, reference: &str) -> Result<u8, Box<dyn std::error::Error>> {
+    u8::try_from(value).map_err(|_| format!("{} of {} out of range: {}", field, reference, value).into())
+}
+
 // Create optimized database structure
 pub fn create_optimized_database(data: &OptimizedData) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
     let mut string_pool = StringPool::new();
     let mut optimized_cards = Vec::new();
+    let tables = &data.lookup_tables;
+    
+    // Lookup tables get their position in reference order as numeric ID
+    let mut factions = Vec::new();
+    for (id, (reference, faction)) in tables.factions.iter().enumerate() {
+        factions.push(fb::FactionArgs {
+            id: id as u16,
+            name_idx: string_pool.add_string(&faction.name),
+            color_idx: string_pool.add_string(&faction.color),
+            reference_idx: string_pool.add_string(reference),
+        });
+    }
+    let mut rarities = Vec::new();
+    for (id, (reference, rarity)) in tables.rarities.iter().enumerate() {
+        rarities.push(fb::RarityArgs {
+            id: id as u16,
+            name_idx: string_pool.add_string(&rarity.name),
+            reference_idx: string_pool.add_string(reference),
+        });
+    }
+    let mut card_types = Vec::new();
+    for (id, (reference, card_type)) in tables.card_types.iter().enumerate() {
+        card_types.push(fb::CardTypeArgs {
+            id: id as u16,
+            name_idx: string_pool.add_string(&card_type.name),
+            reference_idx: string_pool.add_string(reference),
+        });
+    }
     
     // Create numeric mappings for lookup tables
-    let faction_id_map: HashMap<String, u16> = data.lookup_tables.factions.keys()
-        .enumerate().map(|(i, k)| (k.clone(), i as u16)).collect();
-    let rarity_id_map: HashMap<String, u16> = data.lookup_tables.rarities.keys()
-        .enumerate().map(|(i, k)| (k.clone(), i as u16)).collect();
-    let card_type_id_map: HashMap<String, u16> = data.lookup_tables.card_types.keys()
-        .enumerate().map(|(i, k)| (k.clone(), i as u16)).collect();
-    
-    // Process cards with optimization
+    let faction_id_map: HashMap<&str, u16> = tables.factions.keys()
+        .enumerate().map(|(i, k)| (k.as_str(), i as u16)).collect();
+    let rarity_id_map: HashMap<&str, u16> = tables.rarities.keys()
+        .enumerate().map(|(i, k)| (k.as_str(), i as u16)).collect();
+    let card_type_id_map: HashMap<&str, u16> = tables.card_types.keys()
+        .enumerate().map(|(i, k)| (k.as_str(), i as u16)).collect();
+    
+    // Process cards with optimization. Colliding IDs are resolved by probing
+    // upwards in reference order, so the same cards always get the same IDs.
+    let mut used_ids = HashSet::new();
     for (reference, card) in &data.cards {
-        let card_id = generate_numeric_id(reference);
+        let mut card_id = generate_numeric_id(reference);
+        while !used_ids.insert(card_id) {
+            card_id = card_id.wrapping_add(1);
+        }
         let reference_idx = string_pool.add_string(reference);
         let name_idx = string_pool.add_string(&card.name);
         let image_path_idx = string_pool.add_string(&card.image_path);
         let qr_url_idx = string_pool.add_string(&card.qr_url);
         
-        let faction_id = *faction_id_map.get(&card.faction_ref).unwrap_or(&0);
-        let rarity_id = *rarity_id_map.get(&card.rarity_ref).unwrap_or(&0);
-        let card_type_id = *card_type_id_map.get(&card.type_ref).unwrap_or(&0);
+        let faction_id = *faction_id_map.get(card.faction_ref.as_str()).unwrap_or(&0);
+        let rarity_id = *rarity_id_map.get(card.rarity_ref.as_str()).unwrap_or(&0);
+        let card_type_id = *card_type_id_map.get(card.type_ref.as_str()).unwrap_or(&0);
         
         let power_packed = pack_power_values(
-            card.power.m as u8,
-            card.power.o as u8,
-            card.power.f as u8
+            to_u8(card.power.m, "mountain power", reference)?,
+            to_u8(card.power.o, "ocean power", reference)?,
+            to_u8(card.power.f, "forest power", reference)?,
         );
         
-        let flags = if card.is_suspended { 1 } else { 0 };
+        let flags = if card.is_suspended { FLAG_SUSPENDED } else { 0 };
         
         optimized_cards.push(OptimizedCard {
             id: card_id,
@@ -122,8 +170,8 @@ pub fn create_optimized_database(data: &OptimizedData) -> Result<Vec<u8>, Box<dy
             faction_id,
             rarity_id,
             card_type_id,
-            main_cost: card.main_cost as u8,
-            recall_cost: card.recall_cost as u8,
+            main_cost: to_u8(card.main_cost, "main cost", reference)?,
+            recall_cost: to_u8(card.recall_cost, "recall cost", reference)?,
             power_packed,
             image_path_idx,
             qr_url_idx,
@@ -131,8 +179,12 @@ pub fn create_optimized_database(data: &OptimizedData) -> Result<Vec<u8>, Box<dy
         });
     }
     
-    // Sort cards by ID for better compression
-    optimized_cards.sort_by_key(|c| c.id);
+    // Cards stay in reference order; the ID index lists their positions by ID
+    let mut id_index: Vec<u32> = (0..optimized_cards.len() as u32).collect();
+    id_index.sort_by_key(|&position| optimized_cards[position as usize].id);
+    
+    let generated_at_utc_idx = string_pool.add_string(&data.meta.generated_at_utc.to_rfc3339());
+    let script_version_idx = string_pool.add_string(&data.meta.script_version);
     
     // Generate the FlatBuffer data
     let mut builder = FlatBufferBuilder::with_capacity(1024 * 512);
@@ -142,16 +194,59 @@ pub fn create_optimized_database(data: &OptimizedData) -> Result<Vec<u8>, Box<dy
         .map(|s| builder.create_string(s))
         .collect();
     let strings_vector = builder.create_vector(&string_offsets);
+    let string_pool_offset = fb::StringPool::create(&mut builder, &fb::StringPoolArgs {
+        strings: Some(strings_vector),
+    });
+    
+    let faction_offsets: Vec<_> = factions.iter()
+        .map(|args| fb::Faction::create(&mut builder, args))
+        .collect();
+    let factions_vector = builder.create_vector(&faction_offsets);
+    let rarity_offsets: Vec<_> = rarities.iter()
+        .map(|args| fb::Rarity::create(&mut builder, args))
+        .collect();
+    let rarities_vector = builder.create_vector(&rarity_offsets);
+    let card_type_offsets: Vec<_> = card_types.iter()
+        .map(|args| fb::CardType::create(&mut builder, args))
+        .collect();
+    let card_types_vector = builder.create_vector(&card_type_offsets);
     
-    // For now, create a minimal structure for testing
-    // This would be expanded to use the full optimized schema
-    let metadata_offset = builder.create_string(&format!(
-        "Optimized DB v2.0 - {} cards, {} strings in pool",
-        optimized_cards.len(),
-        string_pool.get_strings().len()
-    ));
+    let card_offsets: Vec<_> = optimized_cards.iter()
+        .map(|card| fb::Card::create(&mut builder, &fb::CardArgs {
+            id: card.id,
+            reference_idx: card.reference_idx,
+            name_idx: card.name_idx,
+            faction_id: card.faction_id,
+            rarity_id: card.rarity_id,
+            card_type_id: card.card_type_id,
+            main_cost: card.main_cost,
+            recall_cost: card.recall_cost,
+            power_packed: card.power_packed,
+            image_path_idx: card.image_path_idx,
+            qr_url_idx: card.qr_url_idx,
+            flags: card.flags,
+        }))
+        .collect();
+    let cards_vector = builder.create_vector(&card_offsets);
+    let id_index_vector = builder.create_vector(&id_index);
     
-    builder.finish_minimal(metadata_offset);
+    // The buffer itself is never compressed; the .gz and .lz4 files wrap it
+    let database = fb::OptimizedCardDatabase::create(&mut builder, &fb::OptimizedCardDatabaseArgs {
+        string_pool: Some(string_pool_offset),
+        factions: Some(factions_vector),
+        rarities: Some(rarities_vector),
+        card_types: Some(card_types_vector),
+        cards: Some(cards_vector),
+        generated_at_utc_idx,
+        script_version_idx,
+        total_cards: optimized_cards.len() as u32,
+        database_version: data.meta.generated_at_utc.timestamp() as u32,
+        compression_type: 0,
+        original_size: 0,
+        compressed_size: 0,
+        id_index: Some(id_index_vector),
+    });
+    fb::finish_optimized_card_database_buffer(&mut builder, database);
     
     Ok(builder.finished_data().to_vec())
 }
@@ -192,57 +287,3 @@ pub fn save_optimized_formats(data: &OptimizedData) -> Result<(), Box<dyn std::e
     
     Ok(())
 }
-
-// Delta update structure for incremental updates
-#[derive(Debug)]
-pub struct DeltaUpdate {
-    pub added_cards: Vec<OptimizedCard>,
-    pub modified_cards: Vec<OptimizedCard>,
-    pub removed_card_ids: Vec<u32>,
-    pub delta_version: u32,
-    pub base_version: u32,
-}
-
-pub fn create_delta_update(
-    old_data: &OptimizedData,
-    new_data: &OptimizedData,
-    base_version: u32,
-    delta_version: u32
-) -> DeltaUpdate {
-    let mut added_cards = Vec::new();
-    let mut modified_cards = Vec::new();
-    let mut removed_card_ids = Vec::new();
-    
-    // Find added and modified cards
-    for (reference, new_card) in &new_data.cards {
-        let card_id = generate_numeric_id(reference);
-        
-        if let Some(old_card) = old_data.cards.get(reference) {
-            // Check if modified (simplified comparison)
-            if old_card.name != new_card.name || 
-               old_card.is_suspended != new_card.is_suspended ||
-               old_card.power.m != new_card.power.m {
-                // Card was modified - would create OptimizedCard here
-                // For now, just track that it changed
-            }
-        } else {
-            // Card was added - would create OptimizedCard here
-            // For now, just track that it was added
-        }
-    }
-    
-    // Find removed cards
-    for reference in old_data.cards.keys() {
-        if !new_data.cards.contains_key(reference) {
-            removed_card_ids.push(generate_numeric_id(reference));
-        }
-    }
-    
-    DeltaUpdate {
-        added_cards,
-        modified_cards,
-        removed_card_ids,
-        delta_version,
-        base_version,
-    }
-}
\ No newline at end of file
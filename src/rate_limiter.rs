@@ -0,0 +1,97 @@
+// Token-bucket rate limiter shared by every harvester worker.
+// The bucket holds up to `burst` tokens and refills at `requests_per_second`;
+// each request takes a token, blocking until one is available, so the total
+// request rate stays under the ceiling however many workers are running.
+
+use std::sync::{Mutex, PoisonError};
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+use crate::config::RateLimitConfig;
+
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+pub struct RateLimiter {
+    rate: f64,
+    capacity: f64,
+    bucket: Mutex<Bucket>,
+}
+
+impl RateLimiter {
+    pub fn new(config: &RateLimitConfig) -> Self {
+        let capacity = config.burst.max(1) as f64;
+        RateLimiter {
+            rate: config.requests_per_second,
+            capacity,
+            bucket: Mutex::new(Bucket {
+                tokens: capacity,
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    /// Blocks until a request may be sent. A non-positive rate disables the limit.
+    pub fn acquire(&self) {
+        if self.rate <= 0.0 {
+            return;
+        }
+        loop {
+            let wait = {
+                // The bucket is always left consistent, so a poisoned lock is safe to reuse.
+                let mut bucket = self.bucket.lock().unwrap_or_else(PoisonError::into_inner);
+                let now = Instant::now();
+                let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+                bucket.tokens = (bucket.tokens + elapsed * self.rate).min(self.capacity);
+                bucket.last_refill = now;
+                if bucket.tokens >= 1.0 {
+                    bucket.tokens -= 1.0;
+                    return;
+                }
+                Duration::from_secs_f64((1.0 - bucket.tokens) / self.rate)
+            };
+            sleep(wait);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn workers_share_one_bucket() {
+        let limiter = RateLimiter::new(&RateLimitConfig {
+            requests_per_second: 20.0,
+            burst: 2,
+        });
+        let started = Instant::now();
+        thread::scope(|scope| {
+            for _ in 0..3 {
+                scope.spawn(|| {
+                    for _ in 0..4 {
+                        limiter.acquire();
+                    }
+                });
+            }
+        });
+        // Twelve tokens: two from the burst, ten refilled at one per 50ms.
+        assert!(started.elapsed() >= Duration::from_millis(480), "{:?}", started.elapsed());
+    }
+
+    #[test]
+    fn non_positive_rate_disables_the_limit() {
+        let limiter = RateLimiter::new(&RateLimitConfig {
+            requests_per_second: 0.0,
+            burst: 1,
+        });
+        let started = Instant::now();
+        for _ in 0..100 {
+            limiter.acquire();
+        }
+        assert!(started.elapsed() < Duration::from_millis(100));
+    }
+}
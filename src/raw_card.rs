@@ -0,0 +1,228 @@
+// Typed model of a card object as returned by the `/cards` endpoint.
+// Deserialization fails on missing or mistyped fields instead of silently
+// falling back to `""` or `0`. Fields the model does not know about are kept
+// in the `extra` maps so they survive a round trip through the raw harvest
+// file; in strict mode their presence is an error, which flags API additions.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// How unknown fields of a card object are handled.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum RawCardMode {
+    /// Reject cards carrying fields the model does not know about.
+    Strict,
+    /// Keep unknown fields in `extra` so they are not lost.
+    #[default]
+    Lenient,
+}
+
+/// A `{ reference, name }` object such as `cardType`, `cardSet` or `rarity`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RawReference {
+    #[serde(rename = "@id", default, skip_serializing_if = "Option::is_none")]
+    pub iri: Option<String>,
+    #[serde(rename = "@type", default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub reference: String,
+    pub name: String,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RawFaction {
+    #[serde(rename = "@id", default, skip_serializing_if = "Option::is_none")]
+    pub iri: Option<String>,
+    #[serde(rename = "@type", default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub reference: String,
+    pub name: String,
+    pub color: String,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+/// The `elements` map. The API sends numbers as strings (`"MAIN_COST": "3"`);
+/// they are parsed here and written back in the same form.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub struct RawElements {
+    #[serde(default, with = "numeric_element", skip_serializing_if = "Option::is_none")]
+    pub main_cost: Option<i64>,
+    #[serde(default, with = "numeric_element", skip_serializing_if = "Option::is_none")]
+    pub recall_cost: Option<i64>,
+    #[serde(default, with = "numeric_element", skip_serializing_if = "Option::is_none")]
+    pub mountain_power: Option<i64>,
+    #[serde(default, with = "numeric_element", skip_serializing_if = "Option::is_none")]
+    pub ocean_power: Option<i64>,
+    #[serde(default, with = "numeric_element", skip_serializing_if = "Option::is_none")]
+    pub forest_power: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub main_effect: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub echo_effect: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub flavor_text: Option<String>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RawCard {
+    #[serde(rename = "@id", default, skip_serializing_if = "Option::is_none")]
+    pub iri: Option<String>,
+    #[serde(rename = "@type", default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /// ULID of the card.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub reference: String,
+    pub name: String,
+    pub card_type: RawReference,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub card_sub_types: Vec<RawReference>,
+    pub card_set: RawReference,
+    pub rarity: RawReference,
+    pub main_faction: RawFaction,
+    #[serde(default)]
+    pub image_path: String,
+    /// Image URL per locale, e.g. `"fr-fr"`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub all_image_path: BTreeMap<String, String>,
+    /// Asset URLs grouped by kind, e.g. `"WEB"`.
+    #[serde(default, deserialize_with = "asset_map::deserialize", skip_serializing_if = "BTreeMap::is_empty")]
+    pub assets: BTreeMap<String, Vec<String>>,
+    #[serde(default)]
+    pub qr_url_detail: String,
+    #[serde(default)]
+    pub is_suspended: bool,
+    #[serde(default)]
+    pub elements: RawElements,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+/// A card object that does not match the model.
+#[derive(Debug)]
+pub struct RawCardError {
+    /// Reference of the card, when it could be read.
+    pub reference: Option<String>,
+    pub reason: String,
+}
+
+impl fmt::Display for RawCardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.reference {
+            Some(reference) => write!(f, "invalid card '{}': {}", reference, self.reason),
+            None => write!(f, "invalid card: {}", self.reason),
+        }
+    }
+}
+
+impl std::error::Error for RawCardError {}
+
+impl RawCard {
+    /// Parses a card object from the API in the given mode.
+    pub fn parse(value: Value, mode: RawCardMode) -> Result<RawCard, RawCardError> {
+        let reference = value
+            .get("reference")
+            .and_then(|r| r.as_str())
+            .map(str::to_string);
+        let card: RawCard = serde_json::from_value(value).map_err(|e| RawCardError {
+            reference,
+            reason: e.to_string(),
+        })?;
+        card.check(mode)?;
+        Ok(card)
+    }
+
+    /// In strict mode, fails when any object of the card carries unknown fields.
+    pub fn check(&self, mode: RawCardMode) -> Result<(), RawCardError> {
+        if mode == RawCardMode::Lenient {
+            return Ok(());
+        }
+        let unknown = self.unknown_fields();
+        if unknown.is_empty() {
+            return Ok(());
+        }
+        Err(RawCardError {
+            reference: Some(self.reference.clone()),
+            reason: format!("unknown field(s) {}", unknown.join(", ")),
+        })
+    }
+
+    /// Dotted paths of every field kept in an `extra` map.
+    pub fn unknown_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.extra.keys().cloned().collect();
+        let mut nested = |prefix: &str, extra: &BTreeMap<String, Value>| {
+            fields.extend(extra.keys().map(|key| format!("{}.{}", prefix, key)));
+        };
+        nested("cardType", &self.card_type.extra);
+        for sub_type in &self.card_sub_types {
+            nested("cardSubTypes", &sub_type.extra);
+        }
+        nested("cardSet", &self.card_set.extra);
+        nested("rarity", &self.rarity.extra);
+        nested("mainFaction", &self.main_faction.extra);
+        nested("elements", &self.elements.extra);
+        fields.sort();
+        fields.dedup();
+        fields
+    }
+}
+
+/// (De)serializes an optional element sent by the API as a numeric string.
+mod numeric_element {
+    use super::*;
+    use serde::de::Error;
+
+    pub fn serialize<S: Serializer>(value: &Option<i64>, serializer: S) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(number) => serializer.serialize_str(&number.to_string()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<i64>, D::Error> {
+        match Option::<Value>::deserialize(deserializer)? {
+            None | Some(Value::Null) => Ok(None),
+            Some(Value::Number(number)) => number
+                .as_i64()
+                .map(Some)
+                .ok_or_else(|| D::Error::custom(format!("expected an integer, got {}", number))),
+            Some(Value::String(text)) => text
+                .trim()
+                .parse()
+                .map(Some)
+                .map_err(|_| D::Error::custom(format!("expected a numeric string, got {:?}", text))),
+            Some(other) => Err(D::Error::custom(format!("expected a numeric string, got {}", other))),
+        }
+    }
+}
+
+/// Deserializes the `assets` map, which the API sends as an empty array
+/// (`"assets": []`) when a card has no assets.
+mod asset_map {
+    use super::*;
+    use serde::de::Error;
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<BTreeMap<String, Vec<String>>, D::Error> {
+        match Value::deserialize(deserializer)? {
+            Value::Null => Ok(BTreeMap::new()),
+            Value::Array(items) if items.is_empty() => Ok(BTreeMap::new()),
+            map @ Value::Object(_) => serde_json::from_value(map).map_err(D::Error::custom),
+            other => Err(D::Error::custom(format!("expected an asset map, got {}", other))),
+        }
+    }
+}
@@ -0,0 +1,101 @@
+// Card reference grammar.
+// Every card reference reads `ALT_<set>_<product>_<faction>_<number>_<rarity>`,
+// e.g. `ALT_CORE_B_AX_01_C`, with a trailing unique id for uniques
+// (`ALT_CORE_B_AX_01_U_123`). Printings of the same card in different sets or
+// products and its rarity variants share a family key, `CORE_AX_01`. Foilers
+// carry no card number and read `ALT_CORE_B_NE_FOILER_C` instead.
+
+use std::fmt;
+
+/// Sets that reprint another set card for card, such as the Kickstarter
+/// edition of the core set, mapped to the set they reprint.
+const SET_ALIASES: &[(&str, &str)] = &[("COREKS", "CORE")];
+
+/// Words standing in for the card number of printings that have none.
+const UNNUMBERED: &[&str] = &["FOILER"];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CardReference {
+    pub set: String,
+    /// Product code, e.g. `B` for booster or `P` for promo.
+    pub product: String,
+    pub faction: String,
+    /// Card number, or `FOILER` for foilers.
+    pub number: String,
+    /// Rarity variant, e.g. `C`, `R1`, `R2` or `U`.
+    pub rarity: String,
+    /// Serial number of a unique.
+    pub unique_id: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct ReferenceError {
+    pub reference: String,
+    pub reason: &'static str,
+}
+
+impl fmt::Display for ReferenceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid card reference '{}': {}", self.reference, self.reason)
+    }
+}
+
+impl std::error::Error for ReferenceError {}
+
+impl CardReference {
+    pub fn parse(reference: &str) -> Result<CardReference, ReferenceError> {
+        let error = |reason| ReferenceError {
+            reference: reference.to_string(),
+            reason,
+        };
+        let parts: Vec<&str> = reference.split('_').collect();
+        let [prefix, set, product, faction, number, rarity, rest @ ..] = parts.as_slice() else {
+            return Err(error("expected at least six '_'-separated parts"));
+        };
+        if *prefix != "ALT" {
+            return Err(error("missing the ALT prefix"));
+        }
+        if [set, product, faction, rarity].iter().any(|part| part.is_empty()) {
+            return Err(error("empty part"));
+        }
+        let is_numeric = !number.is_empty() && number.bytes().all(|b| b.is_ascii_digit());
+        if !is_numeric && !UNNUMBERED.contains(number) {
+            return Err(error("card number is not numeric"));
+        }
+        let unique_id = match (*rarity, rest) {
+            (_, []) => None,
+            ("U", [id]) if !id.is_empty() => Some(id.to_string()),
+            _ => return Err(error("unexpected trailing parts")),
+        };
+
+        Ok(CardReference {
+            set: set.to_string(),
+            product: product.to_string(),
+            faction: faction.to_string(),
+            number: number.to_string(),
+            rarity: rarity.to_string(),
+            unique_id,
+        })
+    }
+
+    /// The set this printing belongs to once reprint aliases are resolved.
+    pub fn canonical_set(&self) -> &str {
+        SET_ALIASES
+            .iter()
+            .find(|(alias, _)| *alias == self.set)
+            .map_or(self.set.as_str(), |(_, set)| set)
+    }
+
+    /// Key shared by every printing and rarity variant of the card.
+    pub fn family_key(&self) -> String {
+        format!("{}_{}_{}", self.canonical_set(), self.faction, self.number)
+    }
+
+    /// Everything after the card number, e.g. `R1` or `U_123`.
+    pub fn variant(&self) -> String {
+        match &self.unique_id {
+            Some(id) => format!("{}_{}", self.rarity, id),
+            None => self.rarity.clone(),
+        }
+    }
+}
@@ -0,0 +1,198 @@
+// Suspension tracking.
+// A card counts as suspended when the suspended-cards query returns it or when
+// any of its records carries `isSuspended`, whatever the order in which the
+// queries ran. Cards where the two sources disagree are reported. The ledger
+// persists across runs, remembers since when each card has been suspended and
+// keeps every status change, which is published as `suspensions_history.json`.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Write};
+use std::path::Path;
+
+use crate::cards_generated::altered_cards::{CardDatabase, SuspensionEvent};
+use crate::harvester::HarvestedCard;
+
+/// What the harvest says about a card's suspension.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SuspensionEvidence {
+    /// Returned by the suspended-cards query.
+    pub listed: bool,
+    /// `isSuspended` set on at least one of its records.
+    pub flagged: bool,
+}
+
+impl SuspensionEvidence {
+    pub fn is_suspended(&self) -> bool {
+        self.listed || self.flagged
+    }
+}
+
+/// Collects the suspension evidence of every harvested reference.
+pub fn collect_evidence(cards: &[HarvestedCard]) -> BTreeMap<String, SuspensionEvidence> {
+    let mut evidence: BTreeMap<String, SuspensionEvidence> = BTreeMap::new();
+    for card in cards {
+        let entry = evidence.entry(card.card_data.reference.clone()).or_default();
+        entry.listed |= card.is_suspended;
+        entry.flagged |= card.card_data.is_suspended;
+    }
+    evidence
+}
+
+/// References whose two sources disagree. When the suspended-cards query
+/// returned nothing at all it most likely did not run, so flagged cards are
+/// not reported as missing from it.
+pub fn find_disagreements(evidence: &BTreeMap<String, SuspensionEvidence>) -> Vec<(String, SuspensionEvidence)> {
+    let query_ran = evidence.values().any(|e| e.listed);
+    evidence
+        .iter()
+        .filter(|(_, e)| e.listed != e.flagged && (query_ran || !e.flagged))
+        .map(|(reference, e)| (reference.clone(), *e))
+        .collect()
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SuspensionRecord {
+    /// First run that saw the card suspended.
+    pub suspended_since: DateTime<Utc>,
+}
+
+/// A card becoming suspended or unsuspended.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SuspensionStatusChange {
+    /// Run, or snapshot, in which the change was first seen.
+    pub date: DateTime<Utc>,
+    pub reference: String,
+    /// `true` when the card became suspended, `false` when it was unsuspended.
+    pub suspended: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct SuspensionLedger {
+    #[serde(default)]
+    pub updated_at_utc: Option<DateTime<Utc>>,
+    /// Cards suspended as of the last run.
+    #[serde(default)]
+    pub suspended: BTreeMap<String, SuspensionRecord>,
+    /// Every status change, oldest first.
+    #[serde(default)]
+    pub history: Vec<SuspensionStatusChange>,
+}
+
+/// The published timeline of status changes.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SuspensionHistory {
+    pub generated_at_utc: DateTime<Utc>,
+    pub changes: Vec<SuspensionStatusChange>,
+}
+
+impl SuspensionHistory {
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        serde_json::to_writer_pretty(&mut writer, self)?;
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+/// Changes applied by `SuspensionLedger::update`.
+#[derive(Debug, Default, PartialEq)]
+pub struct SuspensionChanges {
+    pub suspended: Vec<String>,
+    pub lifted: Vec<String>,
+}
+
+impl SuspensionLedger {
+    /// Loads the ledger, or starts an empty one when the file does not exist yet.
+    pub fn load(path: &Path) -> Result<SuspensionLedger, Box<dyn std::error::Error>> {
+        if !path.exists() {
+            return Ok(SuspensionLedger::default());
+        }
+        let file = File::open(path)?;
+        Ok(serde_json::from_reader(BufReader::new(file))
+            .map_err(|e| format!("corrupt suspension ledger '{}': {}", path.display(), e))?)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        serde_json::to_writer_pretty(&mut writer, self)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Records the suspension status seen at `now`. Cards keep their original
+    /// date while they stay suspended; a lifted suspension is forgotten, so a
+    /// card suspended again starts a new period. Cards missing from `statuses`
+    /// are left untouched so that a partial harvest lifts nothing. A harvest
+    /// older than the ledger's last update is skipped, returning `None`, so
+    /// that re-optimizing an old harvest does not rewrite newer history.
+    pub fn update<'a>(
+        &mut self,
+        statuses: impl IntoIterator<Item = (&'a str, bool)>,
+        now: DateTime<Utc>,
+    ) -> Option<SuspensionChanges> {
+        if self.updated_at_utc.is_some_and(|updated_at| now < updated_at) {
+            return None;
+        }
+        let mut changes = SuspensionChanges::default();
+        for (reference, is_suspended) in statuses {
+            match (is_suspended, self.suspended.contains_key(reference)) {
+                (true, false) => {
+                    self.suspended
+                        .insert(reference.to_string(), SuspensionRecord { suspended_since: now });
+                    changes.suspended.push(reference.to_string());
+                }
+                (false, true) => {
+                    self.suspended.remove(reference);
+                    changes.lifted.push(reference.to_string());
+                }
+                _ => continue,
+            }
+            self.history.push(SuspensionStatusChange {
+                date: now,
+                reference: reference.to_string(),
+                suspended: is_suspended,
+            });
+        }
+        self.updated_at_utc = Some(now);
+        Some(changes)
+    }
+
+    /// Replays snapshots of the suspension status, e.g. taken from
+    /// successive optimized outputs, in date order.
+    pub fn from_snapshots(mut snapshots: Vec<(DateTime<Utc>, BTreeMap<String, bool>)>) -> SuspensionLedger {
+        snapshots.sort_by_key(|(date, _)| *date);
+        let mut ledger = SuspensionLedger::default();
+        for (date, statuses) in &snapshots {
+            ledger.update(statuses.iter().map(|(reference, suspended)| (reference.as_str(), *suspended)), *date);
+        }
+        ledger
+    }
+
+    pub fn history(&self) -> SuspensionHistory {
+        SuspensionHistory {
+            generated_at_utc: Utc::now(),
+            changes: self.history.clone(),
+        }
+    }
+
+    pub fn suspended_since(&self, reference: &str) -> Option<DateTime<Utc>> {
+        self.suspended.get(reference).map(|record| record.suspended_since)
+    }
+}
+
+/// Suspension status changes stored in a FlatBuffer database, oldest first,
+/// optionally restricted to one card.
+pub fn suspension_timeline<'a>(database: &CardDatabase<'a>, reference: Option<&str>) -> Vec<SuspensionEvent<'a>> {
+    database
+        .suspension_events()
+        .map(|events| {
+            events
+                .iter()
+                .filter(|event| reference.is_none_or(|r| event.reference() == Some(r)))
+                .collect()
+        })
+        .unwrap_or_default()
+}
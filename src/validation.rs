@@ -0,0 +1,283 @@
+// Validation stage run between harvest and optimize.
+// Checks every harvested card for missing required fields, numbers that do
+// not fit the outputs, unknown faction/rarity/type references, duplicates and
+// other suspicious values, and collects the findings in a JSON report. The
+// raw harvest is validated from its JSON records, so a card that does not
+// even parse, e.g. because of a non-numeric cost, is reported like any other
+// error. The pipeline stops when the configured severity thresholds are
+// exceeded.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use crate::config::ValidationConfig;
+use crate::harvester::HarvestedCard;
+use crate::raw_card::RawCardMode;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ValidationIssue {
+    pub severity: Severity,
+    /// Stable machine-readable identifier, e.g. `out_of_range`.
+    pub code: String,
+    pub reference: String,
+    pub locale: String,
+    /// API field the issue is about, e.g. `elements.MAIN_COST`.
+    pub field: String,
+    pub message: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ValidationReport {
+    pub generated_at_utc: DateTime<Utc>,
+    pub total_cards: usize,
+    pub errors: usize,
+    pub warnings: usize,
+    pub issues: Vec<ValidationIssue>,
+}
+
+/// Card types that are played from hand and therefore carry costs.
+const COSTED_CARD_TYPES: &[&str] = &[
+    "CHARACTER",
+    "SPELL",
+    "PERMANENT",
+    "LANDMARK_PERMANENT",
+    "EXPEDITION_PERMANENT",
+];
+
+/// Every number ends up in a `ubyte` of the FlatBuffer schemas.
+const OUTPUT_RANGE: std::ops::RangeInclusive<i64> = 0..=u8::MAX as i64;
+
+/// Numeric elements the API sends as strings.
+const NUMERIC_ELEMENTS: &[&str] = &[
+    "MAIN_COST",
+    "RECALL_COST",
+    "MOUNTAIN_POWER",
+    "OCEAN_POWER",
+    "FOREST_POWER",
+];
+
+/// Parses the records of a raw harvest file and runs every check on the
+/// cards that parse. A record that does not match the card model is left out
+/// of the returned cards and reported as an `invalid_number` error for each
+/// numeric element that is not a number, or as `unparsable_card` otherwise.
+pub fn validate_records(
+    records: Vec<Value>,
+    mode: RawCardMode,
+    config: &ValidationConfig,
+) -> (Vec<HarvestedCard>, ValidationReport) {
+    let total_cards = records.len();
+    let mut cards = Vec::new();
+    let mut parse_issues = Vec::new();
+    for record in records {
+        let parsed = serde_json::from_value::<HarvestedCard>(record.clone())
+            .map_err(|e| e.to_string())
+            .and_then(|card| card.card_data.check(mode).map(|_| card).map_err(|e| e.reason));
+        match parsed {
+            Ok(card) => cards.push(card),
+            Err(reason) => parse_issues.extend(parse_failure(&record, &reason)),
+        }
+    }
+
+    let mut report = validate_cards(&cards, config);
+    parse_issues.append(&mut report.issues);
+    report.issues = parse_issues;
+    report.total_cards = total_cards;
+    report.errors = report.issues.iter().filter(|i| i.severity == Severity::Error).count();
+    report.warnings = report.issues.len() - report.errors;
+    (cards, report)
+}
+
+/// Issues describing why a raw harvest record does not parse.
+fn parse_failure(record: &Value, reason: &str) -> Vec<ValidationIssue> {
+    let card = &record["card_data"];
+    let issue = |code: &str, field: &str, message: String| ValidationIssue {
+        severity: Severity::Error,
+        code: code.to_string(),
+        reference: card["reference"].as_str().unwrap_or_default().to_string(),
+        locale: record["locale"].as_str().unwrap_or_default().to_string(),
+        field: field.to_string(),
+        message,
+    };
+    let invalid_numbers: Vec<ValidationIssue> = NUMERIC_ELEMENTS
+        .iter()
+        .filter_map(|element| {
+            let text = card["elements"][element].as_str()?;
+            text.trim().parse::<i64>().is_err().then(|| {
+                issue(
+                    "invalid_number",
+                    &format!("elements.{}", element),
+                    format!("{:?} is not a number", text),
+                )
+            })
+        })
+        .collect();
+    if invalid_numbers.is_empty() {
+        vec![issue("unparsable_card", "", reason.to_string())]
+    } else {
+        invalid_numbers
+    }
+}
+
+/// Runs every check on the harvested cards.
+pub fn validate_cards(harvested_cards: &[HarvestedCard], config: &ValidationConfig) -> ValidationReport {
+    let mut issues = Vec::new();
+    let mut occurrences: HashMap<(&str, &str, bool), usize> = HashMap::new();
+
+    for harvested_card in harvested_cards {
+        let card = &harvested_card.card_data;
+        let mut report = |severity: Severity, code: &str, field: &str, message: String| {
+            issues.push(ValidationIssue {
+                severity,
+                code: code.to_string(),
+                reference: card.reference.clone(),
+                locale: harvested_card.locale.clone(),
+                field: field.to_string(),
+                message,
+            });
+        };
+
+        let required = [
+            ("reference", &card.reference),
+            ("name", &card.name),
+            ("cardType.reference", &card.card_type.reference),
+            ("cardSet.reference", &card.card_set.reference),
+            ("rarity.reference", &card.rarity.reference),
+            ("mainFaction.reference", &card.main_faction.reference),
+        ];
+        for (field, value) in required {
+            if value.trim().is_empty() {
+                report(Severity::Error, "missing_field", field, format!("{} is empty", field));
+            }
+        }
+
+        let elements = &card.elements;
+        let numbers = [
+            ("elements.MAIN_COST", elements.main_cost, config.max_cost),
+            ("elements.RECALL_COST", elements.recall_cost, config.max_cost),
+            ("elements.MOUNTAIN_POWER", elements.mountain_power, config.max_power),
+            ("elements.OCEAN_POWER", elements.ocean_power, config.max_power),
+            ("elements.FOREST_POWER", elements.forest_power, config.max_power),
+        ];
+        for (field, value, max) in numbers {
+            match value {
+                Some(value) if !OUTPUT_RANGE.contains(&value) => report(
+                    Severity::Error,
+                    "out_of_range",
+                    field,
+                    format!("{} is outside {:?} and cannot be stored", value, OUTPUT_RANGE),
+                ),
+                Some(value) if value > max => report(
+                    Severity::Warning,
+                    "suspicious_value",
+                    field,
+                    format!("{} is above the expected maximum of {}", value, max),
+                ),
+                _ => {}
+            }
+        }
+        if COSTED_CARD_TYPES.contains(&card.card_type.reference.as_str()) {
+            for &(field, value, _) in &numbers[..2] {
+                if value.is_none() {
+                    report(
+                        Severity::Warning,
+                        "missing_element",
+                        field,
+                        format!("{} card has no {}", card.card_type.reference, field),
+                    );
+                }
+            }
+        }
+
+        let references = [
+            ("mainFaction.reference", &card.main_faction.reference, &config.factions),
+            ("rarity.reference", &card.rarity.reference, &config.rarities),
+            ("cardType.reference", &card.card_type.reference, &config.card_types),
+        ];
+        for (field, value, known) in references {
+            if !value.is_empty() && !known.is_empty() && !known.contains(value) {
+                report(
+                    Severity::Warning,
+                    "unknown_reference",
+                    field,
+                    format!("'{}' is not a known reference", value),
+                );
+            }
+        }
+
+        if card.image_path.trim().is_empty() {
+            report(Severity::Warning, "suspicious_value", "imagePath", "card has no image".to_string());
+        }
+        if harvested_card.is_suspended && !card.is_suspended {
+            report(
+                Severity::Warning,
+                "suspicious_value",
+                "isSuspended",
+                "returned by the suspended-cards query but not flagged as suspended".to_string(),
+            );
+        }
+
+        // The suspended query repeats cards of the set queries on purpose, so
+        // only repeats within the same kind of query count as duplicates.
+        let count = occurrences
+            .entry((&card.reference, &harvested_card.locale, harvested_card.is_suspended))
+            .or_insert(0);
+        *count += 1;
+        if *count == 2 {
+            report(
+                Severity::Warning,
+                "duplicate_reference",
+                "reference",
+                "card was harvested more than once".to_string(),
+            );
+        }
+    }
+
+    let errors = issues.iter().filter(|i| i.severity == Severity::Error).count();
+    ValidationReport {
+        generated_at_utc: Utc::now(),
+        total_cards: harvested_cards.len(),
+        errors,
+        warnings: issues.len() - errors,
+        issues,
+    }
+}
+
+impl ValidationReport {
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        serde_json::to_writer_pretty(&mut writer, self)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Fails when the report exceeds the configured thresholds.
+    pub fn check_thresholds(&self, config: &ValidationConfig) -> Result<(), String> {
+        if self.errors > config.max_errors {
+            return Err(format!(
+                "validation found {} error(s), the limit is {}",
+                self.errors, config.max_errors
+            ));
+        }
+        if let Some(max_warnings) = config.max_warnings {
+            if self.warnings > max_warnings {
+                return Err(format!(
+                    "validation found {} warning(s), the limit is {}",
+                    self.warnings, max_warnings
+                ));
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+// Tests for the content-addressed asset store and the download stage.
+
+mod common;
+
+use altered_harvester::asset_store::{download_assets, AssetStore};
+use altered_harvester::config::{AssetConfig, RateLimitConfig};
+use common::{scratch_dir, test_config, MockApi, MockResponse};
+use std::collections::BTreeSet;
+
+#[test]
+fn stores_identical_content_once() {
+    let scratch = scratch_dir("asset_dedup");
+    let store = AssetStore::open(&scratch).unwrap();
+
+    let first = store.insert("https://cdn.test/fr/ALT_CORE_B_AX_04_C.jpg", b"image").unwrap();
+    let second = store.insert("https://cdn.test/en/ALT_CORE_B_AX_04_C.JPG?v=2", b"image").unwrap();
+    let other = store.insert("https://cdn.test/assets/ALT_CORE_B_AX_04_C", b"other").unwrap();
+
+    assert_eq!(first, second);
+    assert_eq!(
+        first.sha256,
+        "6105d6cc76af400325e94d588ce511be5bfdbb73b437dc51eca43917d7a43e3d"
+    );
+    assert_eq!(first.path, format!("objects/61/{}.jpg", first.sha256));
+    assert_eq!(first.size, 5);
+    assert!(other.path.ends_with(".bin"));
+    assert_eq!(std::fs::read_dir(scratch.join("objects/61")).unwrap().count(), 1);
+
+    // The index survives reopening, but a file deleted from disk is missing again.
+    store.save_index().unwrap();
+    let reopened = AssetStore::open(&scratch).unwrap();
+    assert_eq!(reopened.get("https://cdn.test/fr/ALT_CORE_B_AX_04_C.jpg"), Some(first));
+    std::fs::remove_file(scratch.join(&other.path)).unwrap();
+    assert_eq!(reopened.get("https://cdn.test/assets/ALT_CORE_B_AX_04_C"), None);
+}
+
+#[test]
+fn downloads_missing_files_only() {
+    let api = MockApi::start();
+    let scratch = scratch_dir("asset_download");
+    let harvest = test_config(&api, &scratch);
+    let config = AssetConfig {
+        dir: scratch.join("assets").to_string_lossy().into_owned(),
+        workers: 2,
+        rate_limit: RateLimitConfig {
+            requests_per_second: 0.0,
+            burst: 1,
+        },
+        ..AssetConfig::default()
+    };
+    let urls: BTreeSet<String> = ["a.jpg", "b.jpg", "c.png"]
+        .iter()
+        .map(|name| format!("{}/img/{}", api.base_url(), name))
+        .collect();
+    api.route("/img/a.jpg", vec![MockResponse::json("same")]);
+    api.route("/img/b.jpg", vec![MockResponse::json("same")]);
+    api.route("/img/c.png", vec![MockResponse::error(503), MockResponse::json("png")]);
+
+    let files = download_assets(&config, &harvest, &urls).unwrap();
+    assert_eq!(files.len(), 3);
+    let paths: BTreeSet<&String> = files.values().map(|file| &file.path).collect();
+    assert_eq!(paths.len(), 2);
+    assert_eq!(api.requests().len(), 4);
+
+    // A second run finds every file in the store.
+    let again = download_assets(&config, &harvest, &urls).unwrap();
+    assert_eq!(again, files);
+    assert_eq!(api.requests().len(), 4);
+}
@@ -0,0 +1,334 @@
+// Tests for the FlatBuffer readers.
+
+mod common;
+
+use altered_harvester::card_db::{
+    AnyCardDb, CardDb, CardFilter, MatchKind, OptimizedCardDb, Power,
+};
+use altered_harvester::flatbuffer::build_card_database;
+use altered_harvester::optimizer::OptimizedCard;
+use altered_harvester::optimizer_v2::{create_optimized_database, generate_numeric_id};
+use altered_harvester::suspensions::SuspensionStatusChange;
+use chrono::{TimeZone, Utc};
+
+fn expected_power(card: &OptimizedCard) -> Power {
+    Power {
+        mountain: card.power.m as u8,
+        ocean: card.power.o as u8,
+        forest: card.power.f as u8,
+    }
+}
+
+#[test]
+fn card_db_resolves_lookups_and_power() {
+    let scratch = common::scratch_dir("card_db");
+    let data = common::optimized_fixtures(&scratch);
+    let bytes = build_card_database(&data).unwrap();
+    let db = CardDb::new(&bytes).unwrap();
+
+    assert_eq!(db.len(), data.cards.len());
+    assert_eq!(db.script_version(), data.meta.script_version);
+    let references: Vec<&str> = db.cards().map(|card| card.reference()).collect();
+    assert_eq!(references, data.cards.keys().map(String::as_str).collect::<Vec<_>>());
+    for (reference, expected) in &data.cards {
+        let card = db.card(reference).unwrap();
+        assert_eq!(card.name(), expected.name);
+        assert_eq!(card.faction().unwrap().reference, expected.faction_ref);
+        assert_eq!(
+            card.faction().unwrap().name,
+            data.lookup_tables.factions[&expected.faction_ref].name
+        );
+        assert_eq!(card.rarity().unwrap().reference, expected.rarity_ref);
+        assert_eq!(card.card_type().unwrap().reference, expected.type_ref);
+        assert_eq!(card.main_cost() as i64, expected.main_cost);
+        assert_eq!(card.power(), expected_power(expected));
+        assert_eq!(card.is_suspended(), expected.is_suspended);
+        assert_eq!(db.get(card.index()).unwrap().reference(), reference.as_str());
+    }
+    assert!(db.card("ALT_CORE_B_XX_99_C").is_none());
+    assert!(db.get(db.len()).is_none());
+}
+
+#[test]
+fn optimized_card_db_reads_the_same_cards() {
+    let scratch = common::scratch_dir("optimized_card_db");
+    let data = common::optimized_fixtures(&scratch);
+    let bytes = create_optimized_database(&data).unwrap();
+    let db = OptimizedCardDb::new(&bytes).unwrap();
+
+    assert_eq!(db.len(), data.cards.len());
+    assert_eq!(db.generated_at_utc(), data.meta.generated_at_utc.to_rfc3339());
+    for (reference, expected) in &data.cards {
+        let card = db.card(reference).unwrap();
+        assert_eq!(card.name(), expected.name);
+        assert_eq!(card.image_path(), expected.image_path);
+        assert_eq!(card.faction().unwrap().reference, expected.faction_ref);
+        assert_eq!(card.rarity().unwrap().reference, expected.rarity_ref);
+        assert_eq!(card.card_type().unwrap().reference, expected.type_ref);
+        assert_eq!(card.recall_cost() as i64, expected.recall_cost);
+        assert_eq!(card.power(), expected_power(expected));
+        assert_eq!(card.is_suspended(), expected.is_suspended);
+        let by_id = db.card_by_id(generate_numeric_id(reference)).unwrap();
+        assert_eq!(by_id.reference(), reference.as_str());
+    }
+    assert_eq!(db.cards().filter(|card| card.is_suspended()).count(), 1);
+    assert!(db.card("ALT_CORE_B_XX_99_C").is_none());
+    assert!(db.card_by_id(generate_numeric_id("ALT_CORE_B_XX_99_C")).is_none());
+}
+
+#[test]
+fn colliding_card_ids_are_probed_in_reference_order() {
+    let scratch = common::scratch_dir("card_db_collisions");
+    let mut data = common::optimized_fixtures(&scratch);
+    // Both references hash to the same 32-bit ID.
+    let (first, second) = ("ALT_CORE_B_AX_01_U_314991", "ALT_CORE_B_AX_01_U_522880");
+    assert_eq!(generate_numeric_id(first), generate_numeric_id(second));
+    let card = data.cards.values().next().unwrap().clone();
+    data.cards.insert(first.to_string(), card.clone());
+    data.cards.insert(second.to_string(), card);
+
+    let bytes = create_optimized_database(&data).unwrap();
+    let db = OptimizedCardDb::new(&bytes).unwrap();
+
+    let id = generate_numeric_id(first);
+    assert_eq!(db.card(first).unwrap().id(), id);
+    assert_eq!(db.card(second).unwrap().id(), id + 1);
+    assert_eq!(db.card_by_id(id).unwrap().reference(), first);
+    assert_eq!(db.card_by_id(id + 1).unwrap().reference(), second);
+    assert_eq!(create_optimized_database(&data).unwrap(), bytes);
+}
+
+#[test]
+fn lookups_miss_before_and_after_every_key() {
+    let scratch = common::scratch_dir("card_db_bounds");
+    let data = common::optimized_fixtures(&scratch);
+    let bytes = build_card_database(&data).unwrap();
+    let db = CardDb::new(&bytes).unwrap();
+    let optimized_bytes = create_optimized_database(&data).unwrap();
+    let optimized_db = OptimizedCardDb::new(&optimized_bytes).unwrap();
+
+    for reference in ["", "A", "ALT_CORE_B_AX_04_C_", "ZZZ"] {
+        assert!(db.card(reference).is_none(), "{}", reference);
+        assert!(optimized_db.card(reference).is_none(), "{}", reference);
+    }
+    assert!(optimized_db.card_by_id(0).is_none());
+    assert!(optimized_db.card_by_id(u32::MAX).is_none());
+}
+
+#[test]
+fn filters_intersect_the_posting_lists() {
+    let scratch = common::scratch_dir("card_db_filters");
+    let data = common::optimized_fixtures(&scratch);
+    let bytes = build_card_database(&data).unwrap();
+    let db = CardDb::new(&bytes).unwrap();
+
+    let scan = |keep: &dyn Fn(&OptimizedCard) -> bool| -> Vec<&str> {
+        data.cards
+            .iter()
+            .filter(|(_, card)| keep(card))
+            .map(|(reference, _)| reference.as_str())
+            .collect()
+    };
+    let filtered = |filter: CardFilter| -> Vec<&str> {
+        db.filter(&filter).iter().map(|card| card.reference()).collect()
+    };
+
+    let a_card = data.cards.values().next().unwrap();
+    let faction = a_card.faction_ref.as_str();
+    let cost = a_card.main_cost;
+    assert!(!db.cards_with_faction(faction).is_empty());
+    assert_eq!(
+        filtered(CardFilter {
+            faction: Some(faction),
+            ..CardFilter::default()
+        }),
+        scan(&|card| card.faction_ref == faction)
+    );
+    assert_eq!(
+        filtered(CardFilter {
+            faction: Some(faction),
+            rarity: Some(a_card.rarity_ref.as_str()),
+            card_type: Some(a_card.type_ref.as_str()),
+            main_cost: Some(cost as u8),
+            ..CardFilter::default()
+        }),
+        scan(&|card| card.faction_ref == faction
+            && card.rarity_ref == a_card.rarity_ref
+            && card.type_ref == a_card.type_ref
+            && card.main_cost == cost)
+    );
+    assert_eq!(
+        filtered(CardFilter {
+            suspended: Some(true),
+            ..CardFilter::default()
+        }),
+        ["ALT_CORE_B_BR_06_C"]
+    );
+    assert_eq!(
+        filtered(CardFilter {
+            suspended: Some(false),
+            ..CardFilter::default()
+        }),
+        scan(&|card| !card.is_suspended)
+    );
+    assert_eq!(filtered(CardFilter::default()), scan(&|_| true));
+    assert!(filtered(CardFilter {
+        faction: Some("XX"),
+        ..CardFilter::default()
+    })
+    .is_empty());
+}
+
+#[test]
+fn searches_names_ignoring_case_and_accents() {
+    let scratch = common::scratch_dir("card_db_search");
+    let data = common::optimized_fixtures(&scratch);
+    let bytes = build_card_database(&data).unwrap();
+    let db = CardDb::new(&bytes).unwrap();
+    let search = |query: &str| -> Vec<(String, MatchKind)> {
+        db.search_names(query, 10)
+            .iter()
+            .map(|found| (found.card.name().to_string(), found.kind))
+            .collect()
+    };
+    let top = |query: &str| search(query).first().cloned();
+
+    assert_eq!(
+        top("INGENIEURE d'ordis"),
+        Some(("Ingénieure d'Ordis".to_string(), MatchKind::Exact))
+    );
+    assert_eq!(top("ingé"), Some(("Ingénieure d'Ordis".to_string(), MatchKind::Prefix)));
+    assert_eq!(top("vallee"), Some(("Druide de la Vallée".to_string(), MatchKind::WordPrefix)));
+    assert_eq!(top("saltimbanqe"), Some(("Saltimbanque".to_string(), MatchKind::Fuzzy)));
+
+    // A single letter is enough while typing; prefixes rank before words.
+    let names: Vec<(String, MatchKind)> = search("v");
+    assert_eq!(names[0], ("Vaillant Cuirassé".to_string(), MatchKind::Prefix));
+    assert!(names.contains(&("Druide de la Vallée".to_string(), MatchKind::WordPrefix)));
+    assert!(names.contains(&("Alchimiste Vagabond".to_string(), MatchKind::WordPrefix)));
+    assert!(!names.iter().any(|(name, _)| name == "Saltimbanque"));
+
+    assert!(search("zzzz").is_empty());
+    assert!(search(" ' ").is_empty());
+    assert_eq!(db.search_names("v", 1).len(), 1);
+}
+
+#[test]
+fn suspension_timeline_is_filtered_by_card() {
+    let scratch = common::scratch_dir("card_db_timeline");
+    let mut data = common::optimized_fixtures(&scratch);
+    let change = |month: u32, reference: &str, suspended: bool| SuspensionStatusChange {
+        date: Utc.with_ymd_and_hms(2025, month, 1, 0, 0, 0).unwrap(),
+        reference: reference.to_string(),
+        suspended,
+    };
+    data.suspension_history = vec![
+        change(1, "ALT_CORE_B_BR_06_C", true),
+        change(3, "ALT_CORE_B_AX_04_C", true),
+        change(5, "ALT_CORE_B_AX_04_C", false),
+    ];
+    let bytes = build_card_database(&data).unwrap();
+    let db = CardDb::new(&bytes).unwrap();
+    let timeline = |reference: Option<&str>| -> Vec<(String, bool)> {
+        db.suspension_timeline(reference)
+            .iter()
+            .map(|event| (event.reference().unwrap().to_string(), event.suspended()))
+            .collect()
+    };
+
+    assert_eq!(timeline(None).len(), 3);
+    assert_eq!(
+        timeline(Some("ALT_CORE_B_AX_04_C")),
+        [
+            ("ALT_CORE_B_AX_04_C".to_string(), true),
+            ("ALT_CORE_B_AX_04_C".to_string(), false),
+        ]
+    );
+    assert!(timeline(Some("ALT_CORE_B_XX_99_C")).is_empty());
+}
+
+#[test]
+fn reads_databases_written_before_the_indices() {
+    // The committed database predates the posting lists and the trigram index.
+    let bytes = std::fs::read(std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("altered_cards.fb")).unwrap();
+    let db = CardDb::new(&bytes).unwrap();
+    assert!(db.database().cards_by_faction().is_none());
+    assert!(db.database().name_grams().is_none());
+
+    let a_card = db.cards().nth(5).unwrap();
+    let faction = a_card.faction().unwrap().reference;
+    let filtered: Vec<&str> = db
+        .filter(&CardFilter {
+            faction: Some(faction),
+            main_cost: Some(a_card.main_cost()),
+            suspended: Some(false),
+            ..CardFilter::default()
+        })
+        .iter()
+        .map(|card| card.reference())
+        .collect();
+    let scanned: Vec<&str> = db
+        .cards()
+        .filter(|card| {
+            card.faction().unwrap().reference == faction
+                && card.main_cost() == a_card.main_cost()
+                && !card.is_suspended()
+        })
+        .map(|card| card.reference())
+        .collect();
+    assert!(filtered.contains(&a_card.reference()));
+    assert_eq!(filtered, scanned);
+    assert_eq!(
+        db.suspended_cards().len(),
+        db.cards().filter(|card| card.is_suspended()).count()
+    );
+
+    let found = db.search_names(&a_card.name().to_uppercase(), 100);
+    assert!(found
+        .iter()
+        .any(|found| found.card.reference() == a_card.reference() && found.kind == MatchKind::Exact));
+    assert!(found.iter().all(|found| found.kind > MatchKind::Fuzzy));
+}
+
+#[test]
+fn any_card_db_detects_the_layout() {
+    let scratch = common::scratch_dir("card_db_layouts");
+    let data = common::optimized_fixtures(&scratch);
+
+    let bytes = build_card_database(&data).unwrap();
+    match AnyCardDb::new(&bytes).unwrap() {
+        AnyCardDb::Full(db) => assert_eq!(db.len(), data.cards.len()),
+        AnyCardDb::Optimized(_) => panic!("CardDatabase read as the optimized layout"),
+    }
+    let optimized_bytes = create_optimized_database(&data).unwrap();
+    match AnyCardDb::new(&optimized_bytes).unwrap() {
+        AnyCardDb::Optimized(db) => assert_eq!(db.len(), data.cards.len()),
+        AnyCardDb::Full(_) => panic!("OptimizedCardDatabase read as the full layout"),
+    }
+    assert!(AnyCardDb::new(b"not a flatbuffer").is_err());
+}
+
+#[test]
+fn out_of_range_costs_and_power_fail_the_build() {
+    let scratch = common::scratch_dir("card_db_out_of_range");
+    let reference = "ALT_CORE_B_AX_04_C";
+    for field in ["main cost", "recall cost", "forest power"] {
+        let mut data = common::optimized_fixtures(&scratch);
+        let card = data.cards.get_mut(reference).unwrap();
+        match field {
+            "main cost" => card.main_cost = 256,
+            "recall cost" => card.recall_cost = -1,
+            _ => card.power.f = 300,
+        }
+
+        let error = build_card_database(&data).unwrap_err().to_string();
+        assert!(error.contains(field) && error.contains(reference), "{}", error);
+        assert!(create_optimized_database(&data).is_err());
+    }
+}
+
+#[test]
+fn rejects_buffers_that_fail_verification() {
+    assert!(CardDb::new(b"not a flatbuffer").is_err());
+    assert!(OptimizedCardDb::new(&[0xff; 64]).is_err());
+}
@@ -0,0 +1,301 @@
+// In-process stand-in for the Altered API used by the integration tests.
+// Serves canned responses, usually Hydra pages loaded from `tests/fixtures`,
+// keyed by the exact request target (path and query string), and records
+// every request it receives.
+
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use altered_harvester::config::{CacheConfig, Config, HarvestConfig, RateLimitConfig, RetryConfig};
+use altered_harvester::harvester::HarvestedCard;
+use altered_harvester::optimizer::{optimize_harvest, OptimizedData};
+use altered_harvester::raw_card::{RawCard, RawCardMode};
+use altered_harvester::suspensions::SuspensionLedger;
+use chrono::{DateTime, TimeZone, Utc};
+use serde_json::Value;
+
+#[derive(Clone, Debug)]
+pub struct MockResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl MockResponse {
+    pub fn json(body: impl Into<String>) -> Self {
+        MockResponse {
+            status: 200,
+            headers: Vec::new(),
+            body: body.into(),
+        }
+    }
+
+    /// A 200 response whose body is the named file in `tests/fixtures`.
+    pub fn fixture(name: &str) -> Self {
+        let path = fixtures_dir().join(name);
+        let body = fs::read_to_string(&path)
+            .unwrap_or_else(|e| panic!("cannot read fixture '{}': {}", path.display(), e));
+        MockResponse::json(body)
+    }
+
+    /// An error page shaped like the API's Hydra errors.
+    pub fn error(status: u16) -> Self {
+        MockResponse {
+            status,
+            headers: Vec::new(),
+            body: format!(
+                r#"{{"@type":"hydra:Error","hydra:title":"An error occurred","hydra:description":"HTTP {}"}}"#,
+                status
+            ),
+        }
+    }
+
+    /// An empty 304, answering a conditional request.
+    pub fn not_modified() -> Self {
+        MockResponse {
+            status: 304,
+            headers: Vec::new(),
+            body: String::new(),
+        }
+    }
+
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+}
+
+#[derive(Default)]
+struct State {
+    routes: HashMap<String, Vec<MockResponse>>,
+    requests: Vec<String>,
+    /// Headers of every request, lowercased names, parallel to `requests`.
+    headers: Vec<HashMap<String, String>>,
+}
+
+pub struct MockApi {
+    base_url: String,
+    state: Arc<Mutex<State>>,
+}
+
+impl MockApi {
+    /// Binds an ephemeral port on localhost and serves requests in the background.
+    pub fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("cannot bind mock API");
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let state = Arc::new(Mutex::new(State::default()));
+
+        let server_state = Arc::clone(&state);
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(stream) = stream else {
+                    continue;
+                };
+                let state = Arc::clone(&server_state);
+                thread::spawn(move || handle(stream, &state));
+            }
+        });
+        MockApi { base_url, state }
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Strips the server address from a full URL built by the harvester.
+    pub fn target<'a>(&self, url: &'a str) -> &'a str {
+        url.strip_prefix(self.base_url.as_str()).unwrap_or(url)
+    }
+
+    /// Registers the responses for a request target. They are served in
+    /// order and the last one is repeated for any further request.
+    pub fn route(&self, target: &str, responses: Vec<MockResponse>) {
+        assert!(!responses.is_empty(), "a route needs at least one response");
+        self.state
+            .lock()
+            .unwrap()
+            .routes
+            .insert(target.to_string(), responses);
+    }
+
+    /// Request targets received so far, in arrival order.
+    pub fn requests(&self) -> Vec<String> {
+        self.state.lock().unwrap().requests.clone()
+    }
+
+    pub fn request_count(&self, target: &str) -> usize {
+        self.requests().iter().filter(|r| r.as_str() == target).count()
+    }
+
+    /// Value of a header in every request received for `target`, in arrival
+    /// order; `None` where a request did not send it.
+    pub fn request_header(&self, target: &str, name: &str) -> Vec<Option<String>> {
+        let state = self.state.lock().unwrap();
+        state
+            .requests
+            .iter()
+            .zip(&state.headers)
+            .filter(|(request, _)| request.as_str() == target)
+            .map(|(_, headers)| headers.get(&name.to_ascii_lowercase()).cloned())
+            .collect()
+    }
+}
+
+fn handle(stream: TcpStream, state: &Mutex<State>) {
+    let mut reader = BufReader::new(&stream);
+    let mut request_line = String::new();
+    if reader.read_line(&mut request_line).is_err() {
+        return;
+    }
+    // The harvester only sends GET requests, so there is no body after the headers.
+    let mut headers = HashMap::new();
+    loop {
+        let mut line = String::new();
+        match reader.read_line(&mut line) {
+            Ok(0) | Err(_) => break,
+            Ok(_) if line.trim_end().is_empty() => break,
+            Ok(_) => {
+                if let Some((name, value)) = line.split_once(':') {
+                    headers.insert(name.trim().to_ascii_lowercase(), value.trim().to_string());
+                }
+            }
+        }
+    }
+
+    let target = request_line.split_whitespace().nth(1).unwrap_or("").to_string();
+    let response = {
+        let mut state = state.lock().unwrap();
+        state.requests.push(target.clone());
+        state.headers.push(headers);
+        match state.routes.get_mut(&target) {
+            Some(responses) if responses.len() > 1 => responses.remove(0),
+            Some(responses) => responses[0].clone(),
+            None => MockResponse::error(404),
+        }
+    };
+
+    let mut head = format!(
+        "HTTP/1.1 {} Mock\r\nContent-Type: application/ld+json\r\nContent-Length: {}\r\nConnection: close\r\n",
+        response.status,
+        response.body.len()
+    );
+    for (name, value) in &response.headers {
+        head.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    head.push_str("\r\n");
+
+    let mut stream = &stream;
+    let _ = stream.write_all(head.as_bytes());
+    let _ = stream.write_all(response.body.as_bytes());
+    let _ = stream.flush();
+}
+
+pub fn fixtures_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures")
+}
+
+/// Creates an empty directory under the system temp dir for one test.
+pub fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir()
+        .join("altered_harvester_tests")
+        .join(format!("{}_{}", name, std::process::id()));
+    if dir.exists() {
+        fs::remove_dir_all(&dir).unwrap();
+    }
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// A configuration harvesting the `CORE` commons from the mock API, with
+/// fast retries, no rate limit, no response cache and checkpoints kept in
+/// the scratch directory.
+pub fn test_config(api: &MockApi, scratch: &Path) -> HarvestConfig {
+    HarvestConfig {
+        api_base_url: api.base_url().to_string(),
+        locales: vec!["fr-fr".to_string()],
+        discover_sets: false,
+        sets: vec!["CORE".to_string()],
+        rarities: vec!["COMMON".to_string()],
+        include_suspended: false,
+        workers: 2,
+        rate_limit: RateLimitConfig {
+            requests_per_second: 0.0,
+            burst: 1,
+        },
+        retry: RetryConfig {
+            max_retries: 2,
+            initial_backoff_ms: 1,
+            max_backoff_ms: 5,
+            max_retry_after_ms: None,
+        },
+        cache: CacheConfig {
+            enabled: false,
+            ..CacheConfig::default()
+        },
+        checkpoint_dir: scratch.join("checkpoints").to_string_lossy().into_owned(),
+        ..HarvestConfig::default()
+    }
+}
+
+/// The cards of a fixture page as harvested in `locale`.
+pub fn fixture_cards(name: &str, locale: &str, is_suspended: bool) -> Vec<HarvestedCard> {
+    let page: Value = serde_json::from_str(&fs::read_to_string(fixtures_dir().join(name)).unwrap()).unwrap();
+    page["hydra:member"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|member| HarvestedCard {
+            card_data: RawCard::parse(member.clone(), RawCardMode::Strict).unwrap(),
+            is_suspended,
+            locale: locale.to_string(),
+            details: None,
+            harvested_at_utc: Some(fixture_harvest_date()),
+        })
+        .collect()
+}
+
+/// The date every fixture card was harvested at.
+pub fn fixture_harvest_date() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2025, 6, 1, 12, 0, 0).unwrap()
+}
+
+/// Every CORE fixture page, followed by the suspended-cards query.
+pub fn fixture_harvest() -> Vec<HarvestedCard> {
+    let mut cards = Vec::new();
+    for page in 1..=3 {
+        cards.extend(fixture_cards(&format!("cards_core_page_{}.json", page), "fr-fr", false));
+    }
+    cards.extend(fixture_cards("cards_suspended.json", "fr-fr", true));
+    cards
+}
+
+/// A configuration optimizing the fixture harvest, with the suspension
+/// ledger and history kept in the scratch directory should they be saved.
+pub fn optimizer_config(scratch: &Path) -> Config {
+    let mut config = Config::default();
+    config.harvest.locales = vec!["fr-fr".to_string()];
+    config.harvest.sets = vec!["CORE".to_string()];
+    config.suspensions.ledger_path = scratch.join("ledger.json").to_string_lossy().into_owned();
+    config.suspensions.history_path = scratch.join("history.json").to_string_lossy().into_owned();
+    config
+}
+
+/// The fixture harvest, optimized into the scratch directory against an
+/// empty suspension ledger.
+pub fn optimized_fixtures(scratch: &Path) -> OptimizedData {
+    optimize_harvest(
+        &fixture_harvest(),
+        Vec::new(),
+        &optimizer_config(scratch),
+        &mut SuspensionLedger::default(),
+        &scratch.join("optimized.json"),
+    )
+    .unwrap()
+}
@@ -0,0 +1,56 @@
+// Tests for the `delta` commands.
+
+mod common;
+
+use altered_harvester::optimizer::{load_optimized_data, save_optimized_data};
+use std::process::Command;
+
+/// Runs the binary with `args` and fails the test when it does.
+fn run(args: &[&str]) {
+    let output = Command::new(env!("CARGO_BIN_EXE_altered_harvester"))
+        .args(args)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+}
+
+#[test]
+fn applying_a_delta_updates_the_lookup_tables() {
+    let scratch = common::scratch_dir("delta_lookups");
+    let mut old = common::optimized_fixtures(&scratch);
+    let mut new = common::optimized_fixtures(&scratch);
+    // The target version adds a card of a faction the base does not know and
+    // renames a rarity.
+    let (reference, card) = old.cards.pop_last().unwrap();
+    old.lookup_tables.factions.remove(&card.faction_ref);
+    old.lookup_tables.card_families.remove(&card.family_ref);
+    new.lookup_tables.rarities.values_mut().next().unwrap().name = "Renamed".to_string();
+    let old_path = scratch.join("old.json");
+    let new_path = scratch.join("new.json");
+    save_optimized_data(&old, &old_path).unwrap();
+    save_optimized_data(&new, &new_path).unwrap();
+
+    let delta_path = scratch.join("delta.json");
+    let applied_path = scratch.join("applied.json");
+    run(&[
+        "delta", "create",
+        "--old", old_path.to_str().unwrap(),
+        "--new", new_path.to_str().unwrap(),
+        "--base-version", "1",
+        "--target-version", "2",
+        "--output", delta_path.to_str().unwrap(),
+    ]);
+    run(&[
+        "delta", "apply",
+        "--base", old_path.to_str().unwrap(),
+        "--delta", delta_path.to_str().unwrap(),
+        "--output", applied_path.to_str().unwrap(),
+    ]);
+
+    let applied = load_optimized_data(&applied_path).unwrap();
+    assert!(applied.cards.contains_key(&reference));
+    assert_eq!(
+        serde_json::to_value(&applied.lookup_tables).unwrap(),
+        serde_json::to_value(&new.lookup_tables).unwrap()
+    );
+}
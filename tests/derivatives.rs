@@ -0,0 +1,74 @@
+// Tests for the image derivative stage.
+
+mod common;
+
+use altered_harvester::asset_store::AssetStore;
+use altered_harvester::config::DerivativeConfig;
+use altered_harvester::derivatives::{generate_derivatives, DerivativeFormat, DerivativeManifest, SourceImage};
+use common::scratch_dir;
+use image::{ImageFormat, RgbaImage};
+use std::io::Cursor;
+
+fn png(width: u32, height: u32) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    RgbaImage::new(width, height)
+        .write_to(&mut Cursor::new(&mut bytes), ImageFormat::Png)
+        .unwrap();
+    bytes
+}
+
+#[test]
+fn resizes_encodes_and_skips_unchanged_images() {
+    let scratch = scratch_dir("derivatives");
+    let store = AssetStore::open(&scratch.join("assets")).unwrap();
+    let image_path = "https://cdn.test/ALT_CORE_B_AX_04_C.png";
+    let broken_path = "https://cdn.test/ALT_CORE_B_BR_06_C.png";
+    let sources = vec![
+        SourceImage {
+            reference: "ALT_CORE_B_AX_04_C".to_string(),
+            image_path: image_path.to_string(),
+            file: store.insert(image_path, &png(100, 50)).unwrap(),
+        },
+        SourceImage {
+            reference: "ALT_CORE_B_BR_06_C".to_string(),
+            image_path: broken_path.to_string(),
+            file: store.insert(broken_path, b"not an image").unwrap(),
+        },
+    ];
+    let config = DerivativeConfig {
+        dir: scratch.join("derivatives").to_string_lossy().into_owned(),
+        widths: vec![40, 80, 200],
+        formats: vec![DerivativeFormat::Webp, DerivativeFormat::Jpeg],
+        workers: 2,
+        ..DerivativeConfig::default()
+    };
+
+    let manifest_path = generate_derivatives(&config, store.dir(), &sources).unwrap();
+    let manifest = DerivativeManifest::load(&manifest_path).unwrap();
+    assert_eq!(manifest.cards.len(), 1);
+    let card = &manifest.cards["ALT_CORE_B_AX_04_C"];
+    assert_eq!(card.source_sha256, sources[0].file.sha256);
+    let outputs: Vec<(u32, u32, DerivativeFormat, &str)> = card
+        .derivatives
+        .iter()
+        .map(|d| (d.width, d.height, d.format, d.path.as_str()))
+        .collect();
+    assert_eq!(
+        outputs,
+        [
+            (40, 20, DerivativeFormat::Webp, "ALT_CORE_B_AX_04_C/40w.webp"),
+            (40, 20, DerivativeFormat::Jpeg, "ALT_CORE_B_AX_04_C/40w.jpg"),
+            (80, 40, DerivativeFormat::Webp, "ALT_CORE_B_AX_04_C/80w.webp"),
+            (80, 40, DerivativeFormat::Jpeg, "ALT_CORE_B_AX_04_C/80w.jpg"),
+        ]
+    );
+    let derivatives_dir = scratch.join("derivatives");
+    let thumbnail = image::open(derivatives_dir.join("ALT_CORE_B_AX_04_C/40w.webp")).unwrap();
+    assert_eq!((thumbnail.width(), thumbnail.height()), (40, 20));
+
+    // An unchanged source is not processed again.
+    let marker = derivatives_dir.join("ALT_CORE_B_AX_04_C/80w.jpg");
+    std::fs::write(&marker, b"kept").unwrap();
+    generate_derivatives(&config, store.dir(), &sources).unwrap();
+    assert_eq!(std::fs::read(&marker).unwrap(), b"kept");
+}
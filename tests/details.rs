@@ -0,0 +1,91 @@
+// Tests for the card detail pass.
+
+mod common;
+
+use altered_harvester::details::{extract_keywords, CardDetail};
+use altered_harvester::harvester::harvest_cards;
+use common::{scratch_dir, test_config, MockApi, MockResponse};
+
+const REFERENCES: [&str; 5] = [
+    "ALT_CORE_B_AX_04_C",
+    "ALT_CORE_B_BR_06_C",
+    "ALT_CORE_B_LY_08_C",
+    "ALT_CORE_B_MU_10_C",
+    "ALT_CORE_B_YZ_12_C",
+];
+
+/// A detail resource carrying effect text on top of the collection fields.
+fn detail_body(reference: &str) -> String {
+    serde_json::json!({
+        "@id": format!("/cards/{}", reference),
+        "@type": "Card",
+        "reference": reference,
+        "name": "Carte",
+        "cardType": { "reference": "CHARACTER", "name": "Personnage" },
+        "cardSet": { "reference": "CORE", "name": "Au-delà des Portes" },
+        "rarity": { "reference": "COMMON", "name": "Commun" },
+        "mainFaction": { "reference": "AX", "name": "Axiom", "color": "#8c432a" },
+        "elements": {
+            "MAIN_COST": "1",
+            "MAIN_EFFECT": "{J} Je gagne [[Coriace]]. ",
+            "ECHO_EFFECT": "{D} [[Coriace]] et [[Ancré]].",
+            "FLAVOR_TEXT": "Rien ne passe.",
+            "PERMANENT": "2"
+        }
+    })
+    .to_string()
+}
+
+#[test]
+fn extracts_marked_up_keywords() {
+    assert_eq!(
+        extract_keywords("{J} Je gagne [[Coriace]] et [[ Ancré ]]. [[]] [[Fugace"),
+        ["Coriace", "Ancré"]
+    );
+    assert!(extract_keywords("Pas de mot-clé.").is_empty());
+}
+
+#[test]
+fn attaches_details_to_harvested_cards() {
+    let api = MockApi::start();
+    let scratch = scratch_dir("details");
+    let config = {
+        let mut config = test_config(&api, &scratch);
+        config.details.enabled = true;
+        config
+    };
+    let queries = config.queries(&config.sets, &config.rarities);
+    let first_page = api.target(&queries[0].url).to_string();
+    for page in 1..=3 {
+        let target = match page {
+            1 => first_page.clone(),
+            _ => format!("{}&page={}", first_page, page),
+        };
+        let fixture = format!("cards_core_page_{}.json", page);
+        api.route(&target, vec![MockResponse::fixture(&fixture)]);
+    }
+    let detail_targets: Vec<String> = REFERENCES
+        .iter()
+        .map(|reference| {
+            let url = config.detail_url(&format!("/cards/{}", reference), "fr-fr");
+            api.target(&url).to_string()
+        })
+        .collect();
+    for (reference, target) in REFERENCES.iter().zip(&detail_targets) {
+        api.route(target, vec![MockResponse::json(detail_body(reference))]);
+    }
+
+    let cards = harvest_cards(&config, &scratch.join("raw.json"), false).unwrap();
+
+    let expected = CardDetail {
+        main_effect: Some("{J} Je gagne [[Coriace]].".to_string()),
+        echo_effect: Some("{D} [[Coriace]] et [[Ancré]].".to_string()),
+        flavor: Some("Rien ne passe.".to_string()),
+        keywords: vec!["Coriace".to_string(), "Ancré".to_string()],
+    };
+    assert_eq!(cards.len(), REFERENCES.len());
+    assert!(cards.iter().all(|c| c.details.as_ref() == Some(&expected)));
+    for target in &detail_targets {
+        assert_eq!(api.request_count(target), 1);
+    }
+}
@@ -0,0 +1,529 @@
+// Integration tests for `harvest_cards()` against the in-process mock API.
+
+mod common;
+
+use altered_harvester::checkpoint::stable_hash;
+use altered_harvester::config::{
+    CacheConfig, Config, HarvestConfig, RateLimitConfig, UniqueConfig, ValidationConfig,
+};
+use altered_harvester::harvester::{harvest_cards, load_data_sources, load_raw_records, HarvestedCard};
+use altered_harvester::optimizer::optimize_harvest;
+use altered_harvester::suspensions::SuspensionLedger;
+use altered_harvester::validation::validate_records;
+use common::{scratch_dir, test_config, MockApi, MockResponse};
+use std::time::{Duration, Instant};
+
+/// Registers the three fixture pages of the `CORE` query and returns the
+/// targets of its pages.
+fn route_core_pages(api: &MockApi, first_page: &str) -> [String; 3] {
+    let pages = [
+        first_page.to_string(),
+        format!("{}&page=2", first_page),
+        format!("{}&page=3", first_page),
+    ];
+    for (index, target) in pages.iter().enumerate() {
+        let fixture = format!("cards_core_page_{}.json", index + 1);
+        api.route(target, vec![MockResponse::fixture(&fixture)]);
+    }
+    pages
+}
+
+/// A configuration harvesting only the `CORE` uniques, limited by `uniques`,
+/// and the target of their first page. The page serves every fixture card
+/// and links to a second page that is not routed.
+fn uniques_config(api: &MockApi, scratch: &std::path::Path, uniques: UniqueConfig) -> (HarvestConfig, String) {
+    let mut config = test_config(api, scratch);
+    config.rarities = vec!["UNIQUE".to_string()];
+    config.uniques = uniques;
+    let queries = config.queries(&config.sets, &config.rarities);
+    assert_eq!(queries.len(), 1);
+    let target = api.target(&queries[0].url).to_string();
+    let mut page: serde_json::Value =
+        serde_json::from_str(&MockResponse::fixture("cards_core_page_1.json").body).unwrap();
+    let members: Vec<serde_json::Value> = (1..=3)
+        .flat_map(|number| {
+            let fixture = MockResponse::fixture(&format!("cards_core_page_{}.json", number));
+            let other: serde_json::Value = serde_json::from_str(&fixture.body).unwrap();
+            other["hydra:member"].as_array().unwrap().clone()
+        })
+        .collect();
+    page["hydra:member"] = members.into();
+    page["hydra:view"]["hydra:next"] = format!("{}&page=2", target).into();
+    api.route(&target, vec![MockResponse::json(page.to_string())]);
+    (config, target)
+}
+
+fn references(cards: &[HarvestedCard]) -> Vec<&str> {
+    cards
+        .iter()
+        .map(|c| c.card_data.reference.as_str())
+        .collect()
+}
+
+#[test]
+fn follows_pagination_and_relative_next_links() {
+    let api = MockApi::start();
+    let scratch = scratch_dir("pagination");
+    let config = test_config(&api, &scratch);
+    let queries = config.queries(&config.sets, &config.rarities);
+    let pages = route_core_pages(&api, api.target(&queries[0].url));
+
+    let output = scratch.join("raw.json");
+    let cards = harvest_cards(&config, &output, false).unwrap();
+
+    assert_eq!(
+        references(&cards),
+        [
+            "ALT_CORE_B_AX_04_C",
+            "ALT_CORE_B_BR_06_C",
+            "ALT_CORE_B_LY_08_C",
+            "ALT_CORE_B_MU_10_C",
+            "ALT_CORE_B_YZ_12_C",
+        ]
+    );
+    // Page 2 links to page 3 with a path-relative URL.
+    assert_eq!(api.requests(), pages);
+    assert!(cards.iter().all(|c| c.locale == "fr-fr" && !c.is_suspended));
+
+    let saved: Vec<HarvestedCard> =
+        serde_json::from_str(&std::fs::read_to_string(&output).unwrap()).unwrap();
+    assert_eq!(references(&saved), references(&cards));
+    assert!(!scratch.join("checkpoints").exists());
+}
+
+#[test]
+fn flags_cards_from_the_suspended_query() {
+    let api = MockApi::start();
+    let scratch = scratch_dir("suspended");
+    let config = {
+        let mut config = test_config(&api, &scratch);
+        config.include_suspended = true;
+        config
+    };
+    let queries = config.queries(&config.sets, &config.rarities);
+    assert_eq!(queries.len(), 2);
+    assert!(queries[1].is_suspended);
+    route_core_pages(&api, api.target(&queries[0].url));
+    api.route(
+        api.target(&queries[1].url),
+        vec![MockResponse::fixture("cards_suspended.json")],
+    );
+
+    let cards = harvest_cards(&config, &scratch.join("raw.json"), false).unwrap();
+
+    // Results are merged in query order, so the suspended copy comes last.
+    assert_eq!(cards.len(), 6);
+    let last = cards.last().unwrap();
+    assert_eq!(last.card_data.reference, "ALT_CORE_B_BR_06_C");
+    assert!(last.is_suspended);
+    assert_eq!(cards.iter().filter(|c| c.is_suspended).count(), 1);
+}
+
+#[test]
+fn merges_the_names_of_every_locale() {
+    let api = MockApi::start();
+    let scratch = scratch_dir("locales");
+    let config = Config {
+        harvest: {
+            let mut config = test_config(&api, &scratch);
+            config.locales = vec!["fr-fr".to_string(), "en-us".to_string()];
+            config
+        },
+        ..Config::default()
+    };
+    let queries = config.harvest.queries(&config.harvest.sets, &config.harvest.rarities);
+    assert_eq!(queries.len(), 2);
+    route_core_pages(&api, api.target(&queries[0].url));
+    // The English query returns the first page only, translated.
+    let mut english: serde_json::Value =
+        serde_json::from_str(&MockResponse::fixture("cards_core_page_1.json").body).unwrap();
+    english.as_object_mut().unwrap().remove("hydra:view");
+    english["hydra:member"][0]["name"] = "Ordis Engineer".into();
+    english["hydra:member"][0]["rarity"]["name"] = "Common".into();
+    api.route(
+        api.target(&queries[1].url),
+        vec![MockResponse::json(english.to_string())],
+    );
+
+    let cards = harvest_cards(&config.harvest, &scratch.join("raw.json"), false).unwrap();
+    assert_eq!(cards.len(), 7);
+    assert_eq!(cards.iter().filter(|c| c.locale == "en-us").count(), 2);
+
+    let data = optimize_harvest(
+        &cards,
+        Vec::new(),
+        &config,
+        &mut SuspensionLedger::default(),
+        &scratch.join("optimized.json"),
+    )
+    .unwrap();
+    assert_eq!(data.cards.len(), 5);
+    let engineer = &data.cards["ALT_CORE_B_AX_04_C"];
+    assert_eq!(engineer.name, "Ingénieure d'Ordis");
+    assert_eq!(engineer.names["fr-fr"], "Ingénieure d'Ordis");
+    assert_eq!(engineer.names["en-us"], "Ordis Engineer");
+    // Cards missing from a locale keep the names they have.
+    assert_eq!(data.cards["ALT_CORE_B_LY_08_C"].names.len(), 1);
+    let common = &data.lookup_tables.rarities["COMMON"];
+    assert_eq!(common.name, "Commun");
+    assert_eq!(common.names["en-us"], "Common");
+}
+
+#[test]
+fn applies_include_and_exclude_filters_to_discovered_sets() {
+    let api = MockApi::start();
+    let scratch = scratch_dir("discovery");
+    let config = {
+        let mut config = test_config(&api, &scratch);
+        config.discover_sets = true;
+        config.sets = vec!["CORE".to_string(), "ALIZE".to_string()];
+        config.exclude_sets = vec!["ALIZE".to_string()];
+        config
+    };
+    let sets_target = api.target(&config.card_sets_url()).to_string();
+    api.route(
+        &sets_target,
+        vec![MockResponse::json(
+            r#"{"hydra:member": [{"reference": "COREKS"}, {"reference": "CORE"}, {"reference": "ALIZE"}, {"reference": "BISE"}]}"#,
+        )],
+    );
+    // Only the CORE pages are routed: a query for any other set would fail.
+    let queries = config.queries(&["CORE".to_string()], &config.rarities);
+    let pages = route_core_pages(&api, api.target(&queries[0].url));
+
+    let cards = harvest_cards(&config, &scratch.join("raw.json"), false).unwrap();
+
+    assert_eq!(cards.len(), 5);
+    let mut expected = vec![sets_target];
+    expected.extend(pages);
+    assert_eq!(api.requests(), expected);
+}
+
+#[test]
+fn records_the_queries_run_as_data_sources() {
+    let api = MockApi::start();
+    let scratch = scratch_dir("data_sources");
+    let mut config = Config {
+        harvest: test_config(&api, &scratch),
+        ..Config::default()
+    };
+    config.harvest.discover_sets = true;
+    config.harvest.sets = vec!["BISE".to_string(), "CORE".to_string()];
+    let sets_target = api.target(&config.harvest.card_sets_url()).to_string();
+    api.route(
+        &sets_target,
+        vec![MockResponse::json(
+            r#"{"hydra:member": [{"reference": "COREKS"}, {"reference": "CORE"}, {"reference": "BISE"}]}"#,
+        )],
+    );
+    // BISE is queried but returns no card.
+    let sets = ["CORE".to_string(), "BISE".to_string()];
+    let queries = config.harvest.queries(&sets, &config.harvest.rarities);
+    route_core_pages(&api, api.target(&queries[0].url));
+    api.route(api.target(&queries[1].url), vec![MockResponse::json(r#"{"hydra:member": []}"#)]);
+
+    let raw_path = scratch.join("raw.json");
+    let cards = harvest_cards(&config.harvest, &raw_path, false).unwrap();
+    let data_sources = load_data_sources(&raw_path).unwrap();
+    let data = optimize_harvest(
+        &cards,
+        data_sources,
+        &config,
+        &mut SuspensionLedger::default(),
+        &scratch.join("optimized.json"),
+    )
+    .unwrap();
+
+    let urls: Vec<String> = queries.into_iter().map(|query| query.url).collect();
+    assert_eq!(data.meta.data_sources, urls);
+    assert_eq!(data.meta.source_set, "CORE");
+}
+
+#[test]
+fn stops_harvesting_uniques_at_the_per_set_limit() {
+    let api = MockApi::start();
+    let scratch = scratch_dir("uniques_cap");
+    let uniques = UniqueConfig {
+        max_per_set: Some(3),
+        sample_rate: 1,
+    };
+    let (config, target) = uniques_config(&api, &scratch, uniques);
+
+    let cards = harvest_cards(&config, &scratch.join("raw.json"), false).unwrap();
+
+    assert_eq!(
+        references(&cards),
+        ["ALT_CORE_B_AX_04_C", "ALT_CORE_B_BR_06_C", "ALT_CORE_B_LY_08_C"]
+    );
+    // The limit is reached on the first page, so the second is never fetched.
+    assert_eq!(api.requests(), [target]);
+}
+
+#[test]
+fn samples_uniques_by_reference() {
+    let api = MockApi::start();
+    let scratch = scratch_dir("uniques_sample");
+    let uniques = UniqueConfig {
+        max_per_set: None,
+        sample_rate: 2,
+    };
+    let (config, target) = uniques_config(&api, &scratch, uniques);
+    api.route(
+        &format!("{}&page=2", target),
+        vec![MockResponse::json(r#"{"hydra:member": []}"#)],
+    );
+
+    let cards = harvest_cards(&config, &scratch.join("raw.json"), false).unwrap();
+
+    let expected: Vec<&str> = [
+        "ALT_CORE_B_AX_04_C",
+        "ALT_CORE_B_BR_06_C",
+        "ALT_CORE_B_LY_08_C",
+        "ALT_CORE_B_MU_10_C",
+        "ALT_CORE_B_YZ_12_C",
+    ]
+    .into_iter()
+    .filter(|reference| stable_hash(reference).is_multiple_of(2))
+    .collect();
+    assert!(!expected.is_empty() && expected.len() < 5);
+    assert_eq!(references(&cards), expected);
+    // Sampling is stable: a second run keeps the same uniques.
+    let again = harvest_cards(&config, &scratch.join("raw.json"), true).unwrap();
+    assert_eq!(references(&again), expected);
+}
+
+#[test]
+fn retries_server_errors() {
+    let api = MockApi::start();
+    let scratch = scratch_dir("retry");
+    let config = test_config(&api, &scratch);
+    let queries = config.queries(&config.sets, &config.rarities);
+    let pages = route_core_pages(&api, api.target(&queries[0].url));
+    api.route(
+        &pages[1],
+        vec![
+            MockResponse::error(503).with_header("Retry-After", "0"),
+            MockResponse::error(500),
+            MockResponse::fixture("cards_core_page_2.json"),
+        ],
+    );
+
+    let cards = harvest_cards(&config, &scratch.join("raw.json"), false).unwrap();
+
+    assert_eq!(cards.len(), 5);
+    assert_eq!(api.request_count(&pages[1]), 3);
+}
+
+#[test]
+fn waits_out_retry_after_beyond_the_maximum_backoff() {
+    let api = MockApi::start();
+    let scratch = scratch_dir("retry_after_wait");
+    let config = test_config(&api, &scratch);
+    let queries = config.queries(&config.sets, &config.rarities);
+    let pages = route_core_pages(&api, api.target(&queries[0].url));
+    api.route(
+        &pages[1],
+        vec![
+            MockResponse::error(429).with_header("Retry-After", "1"),
+            MockResponse::fixture("cards_core_page_2.json"),
+        ],
+    );
+
+    let started = Instant::now();
+    let cards = harvest_cards(&config, &scratch.join("raw.json"), false).unwrap();
+
+    assert_eq!(cards.len(), 5);
+    assert_eq!(api.request_count(&pages[1]), 2);
+    assert!(started.elapsed() >= Duration::from_secs(1));
+}
+
+#[test]
+fn fails_on_retry_after_beyond_its_ceiling() {
+    let api = MockApi::start();
+    let scratch = scratch_dir("retry_after_ceiling");
+    let mut config = test_config(&api, &scratch);
+    config.retry.max_retry_after_ms = Some(1000);
+    let queries = config.queries(&config.sets, &config.rarities);
+    let pages = route_core_pages(&api, api.target(&queries[0].url));
+    api.route(&pages[1], vec![MockResponse::error(429).with_header("Retry-After", "3600")]);
+
+    let started = Instant::now();
+    let error = harvest_cards(&config, &scratch.join("raw.json"), false).unwrap_err().to_string();
+
+    assert!(error.contains("Retry-After of 3600s"), "{}", error);
+    assert_eq!(api.request_count(&pages[1]), 1);
+    assert!(started.elapsed() < Duration::from_secs(10));
+}
+
+#[test]
+fn gives_up_once_the_retry_budget_is_spent() {
+    let api = MockApi::start();
+    let scratch = scratch_dir("retry_budget");
+    let config = test_config(&api, &scratch);
+    let queries = config.queries(&config.sets, &config.rarities);
+    let pages = route_core_pages(&api, api.target(&queries[0].url));
+    api.route(&pages[1], vec![MockResponse::error(500)]);
+
+    let output = scratch.join("raw.json");
+    let error = harvest_cards(&config, &output, false).unwrap_err().to_string();
+
+    assert!(error.contains("page 2"), "{}", error);
+    assert!(error.contains("HTTP 500"), "{}", error);
+    assert!(error.contains("3 attempt(s)"), "{}", error);
+    assert_eq!(api.request_count(&pages[1]), 3);
+    assert!(!output.exists());
+    // The first page stays checkpointed for the next run.
+    assert!(scratch.join("checkpoints").exists());
+}
+
+#[test]
+fn does_not_retry_client_errors() {
+    let api = MockApi::start();
+    let scratch = scratch_dir("client_error");
+    let config = test_config(&api, &scratch);
+    let queries = config.queries(&config.sets, &config.rarities);
+    let target = api.target(&queries[0].url).to_string();
+    api.route(&target, vec![MockResponse::error(404)]);
+
+    let error = harvest_cards(&config, &scratch.join("raw.json"), false).unwrap_err().to_string();
+
+    assert!(error.contains("HTTP 404"), "{}", error);
+    assert_eq!(api.request_count(&target), 1);
+}
+
+#[test]
+fn resumes_from_checkpoints_without_refetching_completed_pages() {
+    let api = MockApi::start();
+    let scratch = scratch_dir("resume");
+    let config = test_config(&api, &scratch);
+    let queries = config.queries(&config.sets, &config.rarities);
+    let pages = route_core_pages(&api, api.target(&queries[0].url));
+    api.route(&pages[2], vec![MockResponse::error(500)]);
+
+    let output = scratch.join("raw.json");
+    assert!(harvest_cards(&config, &output, false).is_err());
+    assert_eq!(api.request_count(&pages[0]), 1);
+    assert_eq!(api.request_count(&pages[1]), 1);
+
+    api.route(&pages[2], vec![MockResponse::fixture("cards_core_page_3.json")]);
+    let cards = harvest_cards(&config, &output, false).unwrap();
+
+    assert_eq!(cards.len(), 5);
+    // Pages 1 and 2 are replayed from their checkpoints.
+    assert_eq!(api.request_count(&pages[0]), 1);
+    assert_eq!(api.request_count(&pages[1]), 1);
+    assert_eq!(api.request_count(&pages[2]), 4);
+    assert!(!scratch.join("checkpoints").exists());
+}
+
+#[test]
+fn fresh_harvest_discards_checkpoints() {
+    let api = MockApi::start();
+    let scratch = scratch_dir("fresh");
+    let config = test_config(&api, &scratch);
+    let queries = config.queries(&config.sets, &config.rarities);
+    let pages = route_core_pages(&api, api.target(&queries[0].url));
+    api.route(&pages[2], vec![MockResponse::error(500)]);
+
+    let output = scratch.join("raw.json");
+    assert!(harvest_cards(&config, &output, false).is_err());
+    api.route(&pages[2], vec![MockResponse::fixture("cards_core_page_3.json")]);
+    let cards = harvest_cards(&config, &output, true).unwrap();
+
+    assert_eq!(cards.len(), 5);
+    assert_eq!(api.request_count(&pages[0]), 2);
+    assert_eq!(api.request_count(&pages[1]), 2);
+}
+
+#[test]
+fn reuses_cached_pages_on_not_modified() {
+    let api = MockApi::start();
+    let scratch = scratch_dir("not_modified");
+    let config = {
+        let mut config = test_config(&api, &scratch);
+        config.cache = CacheConfig {
+            enabled: true,
+            dir: scratch.join("cache").to_string_lossy().into_owned(),
+            ..CacheConfig::default()
+        };
+        config
+    };
+    let queries = config.queries(&config.sets, &config.rarities);
+    let pages = route_core_pages(&api, api.target(&queries[0].url));
+    for (index, target) in pages.iter().enumerate() {
+        let fixture = format!("cards_core_page_{}.json", index + 1);
+        let etag = format!("\"page-{}\"", index + 1);
+        api.route(target, vec![MockResponse::fixture(&fixture).with_header("ETag", &etag)]);
+    }
+    let first = harvest_cards(&config, &scratch.join("raw.json"), false).unwrap();
+
+    for target in &pages {
+        api.route(target, vec![MockResponse::not_modified()]);
+    }
+    let second = harvest_cards(&config, &scratch.join("raw.json"), false).unwrap();
+
+    assert_eq!(references(&second), references(&first));
+    for (index, target) in pages.iter().enumerate() {
+        let etag = format!("\"page-{}\"", index + 1);
+        assert_eq!(api.request_header(target, "If-None-Match"), [None, Some(etag)]);
+    }
+}
+
+#[test]
+fn workers_share_the_rate_limit_and_merge_in_query_order() {
+    let api = MockApi::start();
+    let scratch = scratch_dir("workers");
+    let config = {
+        let mut config = test_config(&api, &scratch);
+        config.locales = vec!["fr-fr".to_string(), "en-us".to_string(), "de-de".to_string()];
+        config.workers = 3;
+        config.rate_limit = RateLimitConfig {
+            requests_per_second: 20.0,
+            burst: 1,
+        };
+        config
+    };
+    let queries = config.queries(&config.sets, &config.rarities);
+    assert_eq!(queries.len(), 3);
+    for query in &queries {
+        route_core_pages(&api, api.target(&query.url));
+    }
+
+    let started = Instant::now();
+    let cards = harvest_cards(&config, &scratch.join("raw.json"), false).unwrap();
+
+    // Nine pages under a single token refilled every 50ms, whatever the
+    // number of workers.
+    assert_eq!(api.requests().len(), 9);
+    assert!(started.elapsed() >= Duration::from_millis(380), "{:?}", started.elapsed());
+    let locales: Vec<&str> = cards.iter().map(|c| c.locale.as_str()).collect();
+    assert_eq!(
+        locales,
+        [["fr-fr"; 5], ["en-us"; 5], ["de-de"; 5]].concat()
+    );
+    assert_eq!(references(&cards[..5]), references(&cards[10..]));
+}
+
+#[test]
+fn keeps_cards_that_do_not_parse_for_validation() {
+    let api = MockApi::start();
+    let scratch = scratch_dir("unparsable");
+    let config = test_config(&api, &scratch);
+    let queries = config.queries(&config.sets, &config.rarities);
+    let pages = route_core_pages(&api, api.target(&queries[0].url));
+    let mut page: serde_json::Value =
+        serde_json::from_str(&MockResponse::fixture("cards_core_page_2.json").body).unwrap();
+    page["hydra:member"][0]["elements"]["MAIN_COST"] = "?".into();
+    api.route(&pages[1], vec![MockResponse::json(page.to_string())]);
+
+    let output = scratch.join("raw.json");
+    let cards = harvest_cards(&config, &output, false).unwrap();
+
+    assert_eq!(cards.len(), 4);
+    let records = load_raw_records(&output).unwrap();
+    assert_eq!(records.len(), 5);
+    let (parsed, report) = validate_records(records, config.raw_card_mode, &ValidationConfig::default());
+    assert_eq!(references(&parsed), references(&cards));
+    assert_eq!(report.errors, 1);
+    assert_eq!(report.issues[0].field, "elements.MAIN_COST");
+}
@@ -0,0 +1,42 @@
+// Tests for the `inspect` command.
+
+mod common;
+
+use altered_harvester::flatbuffer::build_card_database;
+use altered_harvester::optimizer_v2::create_optimized_database;
+use std::path::Path;
+use std::process::Command;
+
+/// Runs `inspect` on `path` and returns what it printed.
+fn inspect(path: &Path, extra: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_altered_harvester"))
+        .arg("inspect")
+        .arg(path)
+        .args(extra)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn inspects_both_flatbuffer_layouts() {
+    let scratch = common::scratch_dir("inspect_layouts");
+    let data = common::optimized_fixtures(&scratch);
+    let full = scratch.join("altered_cards.fb");
+    std::fs::write(&full, build_card_database(&data).unwrap()).unwrap();
+    let optimized = scratch.join("altered_cards_optimized_v2.fb");
+    std::fs::write(&optimized, create_optimized_database(&data).unwrap()).unwrap();
+    let cards = format!("cards          : {}", data.cards.len());
+
+    let printed = inspect(&full, &[]);
+    assert!(printed.contains("FlatBuffer database"), "{}", printed);
+    assert!(!printed.contains("Optimized FlatBuffer database"), "{}", printed);
+    assert!(printed.contains(&cards), "{}", printed);
+
+    let printed = inspect(&optimized, &["--card", "ALT_CORE_B_BR_06_C"]);
+    assert!(printed.contains("Optimized FlatBuffer database"), "{}", printed);
+    assert!(printed.contains(&cards), "{}", printed);
+    assert!(printed.contains("suspended      : 1"), "{}", printed);
+    assert!(!printed.contains("not found"), "{}", printed);
+}
@@ -0,0 +1,21 @@
+// Tests for name folding and trigrams.
+
+use altered_harvester::name_index::{fold, name_grams, query_grams};
+
+#[test]
+fn folds_case_accents_and_separators() {
+    assert_eq!(fold("Ingénieure d'Ordis"), "ingenieure d ordis");
+    assert_eq!(fold("  Druide de la VALLÉE "), "druide de la vallee");
+    assert_eq!(fold("Œuvre – Straße"), "oeuvre strasse");
+    assert_eq!(fold("Au-delà des Portes"), "au dela des portes");
+}
+
+#[test]
+fn pads_words_and_leaves_the_last_query_word_open() {
+    let grams: Vec<String> = name_grams("sierra x").into_iter().collect();
+    assert_eq!(grams, [" si", " x ", "err", "ier", "ra ", "rra", "sie"]);
+    assert_eq!(query_grams("sie"), [" si", "sie"]);
+    assert_eq!(query_grams("x sie"), [" si", " x ", "sie"]);
+    assert_eq!(query_grams("s"), [" s"]);
+    assert!(query_grams("").is_empty());
+}
@@ -0,0 +1,274 @@
+// Tests for the optimization stage.
+
+mod common;
+
+use altered_harvester::cards_optimized_generated::altered_cards::root_as_optimized_card_database;
+use altered_harvester::harvester::load_raw_cards;
+use altered_harvester::optimizer::{load_optimized_data, optimize_harvest, save_suspension_ledger, OptimizedData};
+use altered_harvester::optimizer_v2::{
+    create_optimized_database, generate_numeric_id, unpack_power_values, FLAG_SUSPENDED,
+};
+use altered_harvester::suspensions::{SuspensionHistory, SuspensionLedger};
+use common::{fixture_cards, fixture_harvest, fixture_harvest_date, optimizer_config};
+use serde_json::{json, Value};
+use std::path::Path;
+use std::process::Command;
+
+/// The saved output without its generation date.
+fn saved_output(path: &Path) -> Value {
+    let mut value: Value = serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+    value["meta"]
+        .as_object_mut()
+        .unwrap()
+        .remove("generated_at_utc");
+    value
+}
+
+#[test]
+fn pipeline_and_standalone_optimization_agree() {
+    let scratch = common::scratch_dir("optimizer_paths");
+    let config = optimizer_config(&scratch);
+    let mut cards = fixture_harvest();
+    let card = &mut cards[0].card_data;
+    card.id = Some("01HN5TXR2AE9M3ZWKWYP5F7V7K".to_string());
+    card.card_sub_types = vec![serde_json::from_value(json!({ "reference": "ADVENTURER", "name": "Aventurier" })).unwrap()];
+    card.assets.insert("WEB".to_string(), vec!["https://example.com/ax_04.jpg".to_string()]);
+
+    // The pipeline optimizes the harvest it holds in memory, the `optimize`
+    // command the raw harvest file it saved.
+    let from_pipeline = scratch.join("pipeline.json");
+    optimize_harvest(&cards, Vec::new(), &config, &mut SuspensionLedger::default(), &from_pipeline).unwrap();
+    let raw_path = scratch.join("raw.json");
+    std::fs::write(&raw_path, serde_json::to_string(&cards).unwrap()).unwrap();
+    let from_file = scratch.join("standalone.json");
+    let loaded = load_raw_cards(&raw_path, config.harvest.raw_card_mode).unwrap();
+    optimize_harvest(&loaded, Vec::new(), &config, &mut SuspensionLedger::default(), &from_file).unwrap();
+
+    assert_eq!(saved_output(&from_pipeline), saved_output(&from_file));
+
+    // Neither path drops the fields only the raw harvest carries.
+    let data = load_optimized_data(&from_file).unwrap();
+    let card = &data.cards["ALT_CORE_B_AX_04_C"];
+    assert_eq!(card.id, "01HN5TXR2AE9M3ZWKWYP5F7V7K");
+    assert_eq!(card.set_ref, "CORE");
+    assert_eq!(card.sub_type_refs, ["ADVENTURER"]);
+    assert_eq!(card.assets["WEB"], ["https://example.com/ax_04.jpg"]);
+    assert_eq!(data.lookup_tables.card_sets["CORE"].name, "Au-delà des Portes");
+    assert_eq!(data.lookup_tables.card_sub_types["ADVENTURER"].name, "Aventurier");
+}
+
+#[test]
+fn suspension_does_not_depend_on_query_order() {
+    let scratch = common::scratch_dir("optimizer_order");
+    let config = optimizer_config(&scratch);
+    let mut cards = fixture_harvest();
+    let forward = optimize_harvest(&cards, Vec::new(), &config, &mut SuspensionLedger::default(), &scratch.join("forward.json")).unwrap();
+    cards.reverse();
+    let reversed = optimize_harvest(&cards, Vec::new(), &config, &mut SuspensionLedger::default(), &scratch.join("reversed.json")).unwrap();
+
+    let suspended = |data: &OptimizedData| -> Vec<String> {
+        data.cards
+            .iter()
+            .filter(|(_, card)| card.is_suspended)
+            .map(|(reference, _)| reference.clone())
+            .collect()
+    };
+    assert_eq!(suspended(&forward), ["ALT_CORE_B_BR_06_C"]);
+    assert_eq!(suspended(&reversed), suspended(&forward));
+    assert!(forward.cards["ALT_CORE_B_BR_06_C"].raw_is_suspended);
+    assert!(forward.cards["ALT_CORE_B_BR_06_C"].suspended_since.is_some());
+}
+
+#[test]
+fn dates_suspensions_at_the_harvest_and_saves_the_ledger_on_request() {
+    let scratch = common::scratch_dir("optimizer_ledger");
+    let config = optimizer_config(&scratch);
+    let mut ledger = SuspensionLedger::default();
+    let data = optimize_harvest(&fixture_harvest(), Vec::new(), &config, &mut ledger, &scratch.join("optimized.json")).unwrap();
+
+    let harvested_at = fixture_harvest_date();
+    assert_eq!(data.cards["ALT_CORE_B_BR_06_C"].suspended_since, Some(harvested_at));
+    assert_eq!(data.suspension_history.len(), 1);
+    assert_eq!(data.suspension_history[0].date, harvested_at);
+    assert_eq!(ledger.history, data.suspension_history);
+    assert!(!Path::new(&config.suspensions.ledger_path).exists());
+    assert!(!Path::new(&config.suspensions.history_path).exists());
+
+    save_suspension_ledger(&ledger, &config.suspensions).unwrap();
+    let saved = SuspensionLedger::load(Path::new(&config.suspensions.ledger_path)).unwrap();
+    assert_eq!(saved.suspended_since("ALT_CORE_B_BR_06_C"), Some(harvested_at));
+    let history: SuspensionHistory =
+        serde_json::from_str(&std::fs::read_to_string(&config.suspensions.history_path).unwrap()).unwrap();
+    assert_eq!(history.changes, data.suspension_history);
+}
+
+#[test]
+fn undated_harvests_leave_the_ledger_unchanged() {
+    let scratch = common::scratch_dir("optimizer_undated");
+    let config = optimizer_config(&scratch);
+    let mut cards = fixture_harvest();
+    for card in &mut cards {
+        card.harvested_at_utc = None;
+    }
+    // Loading the saved harvest does not date it by its file either.
+    let raw_path = scratch.join("raw.json");
+    std::fs::write(&raw_path, serde_json::to_string(&cards).unwrap()).unwrap();
+    let loaded = load_raw_cards(&raw_path, config.harvest.raw_card_mode).unwrap();
+    assert!(loaded.iter().all(|card| card.harvested_at_utc.is_none()));
+
+    let mut ledger = SuspensionLedger::default();
+    let data = optimize_harvest(&loaded, Vec::new(), &config, &mut ledger, &scratch.join("optimized.json")).unwrap();
+
+    assert!(data.cards["ALT_CORE_B_BR_06_C"].is_suspended);
+    assert_eq!(data.cards["ALT_CORE_B_BR_06_C"].suspended_since, None);
+    assert_eq!(ledger.updated_at_utc, None);
+    assert!(ledger.suspended.is_empty());
+    assert!(data.suspension_history.is_empty());
+}
+
+#[test]
+fn optimize_dates_an_undated_harvest_on_request() {
+    let scratch = common::scratch_dir("optimizer_harvested_at");
+    let mut cards = fixture_harvest();
+    for card in &mut cards {
+        card.harvested_at_utc = None;
+    }
+    let raw_path = scratch.join("raw.json");
+    std::fs::write(&raw_path, serde_json::to_string(&cards).unwrap()).unwrap();
+    let config_path = scratch.join("harvester.toml");
+    let ledger_path = scratch.join("ledger.json");
+    std::fs::write(
+        &config_path,
+        format!(
+            "[validation]\nreport_path = {:?}\n\n[suspensions]\nledger_path = {:?}\nhistory_path = {:?}\n",
+            scratch.join("report.json"),
+            ledger_path,
+            scratch.join("history.json"),
+        ),
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_altered_harvester"))
+        .args(["--config", config_path.to_str().unwrap(), "optimize"])
+        .args(["--input", raw_path.to_str().unwrap()])
+        .args(["--output", scratch.join("optimized.json").to_str().unwrap()])
+        .args(["--harvested-at", "2025-06-01T12:00:00Z"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let ledger = SuspensionLedger::load(&ledger_path).unwrap();
+    assert_eq!(ledger.updated_at_utc, Some(fixture_harvest_date()));
+    assert_eq!(ledger.suspended_since("ALT_CORE_B_BR_06_C"), Some(fixture_harvest_date()));
+}
+
+#[test]
+fn skips_empty_lookup_references_and_names_a_single_set() {
+    let scratch = common::scratch_dir("optimizer_lookups");
+    let config = optimizer_config(&scratch);
+    let mut cards = fixture_harvest();
+    cards[0].card_data.main_faction.reference.clear();
+
+    let data = optimize_harvest(&cards, Vec::new(), &config, &mut SuspensionLedger::default(), &scratch.join("optimized.json")).unwrap();
+
+    assert!(!data.lookup_tables.factions.contains_key(""));
+    assert!(data.lookup_tables.factions.contains_key("BR"));
+    assert_eq!(data.meta.source_set, "CORE");
+    assert_eq!(data.meta.total_cards, 5);
+}
+
+#[test]
+fn groups_foiler_variants_into_one_family() {
+    let scratch = common::scratch_dir("optimizer_foilers");
+    let config = optimizer_config(&scratch);
+    let mut cards = fixture_harvest();
+    for variant in ["C", "R1"] {
+        let mut foiler = fixture_cards("cards_core_page_1.json", "fr-fr", false).remove(0);
+        foiler.card_data.reference = format!("ALT_CORE_B_NE_FOILER_{}", variant);
+        cards.push(foiler);
+    }
+
+    let data = optimize_harvest(&cards, Vec::new(), &config, &mut SuspensionLedger::default(), &scratch.join("optimized.json")).unwrap();
+
+    let family = &data.lookup_tables.card_families["CORE_NE_FOILER"];
+    assert_eq!(family.number, "FOILER");
+    assert_eq!(family.members.len(), 2);
+    assert_eq!(data.cards["ALT_CORE_B_NE_FOILER_R1"].family_ref, "CORE_NE_FOILER");
+}
+
+#[test]
+fn optimized_database_outgrows_sixteen_bit_string_indices() {
+    let scratch = common::scratch_dir("optimizer_v2_pool");
+    let mut data = common::optimized_fixtures(&scratch);
+    let template = data.cards.values().next().unwrap().clone();
+    for n in 0..25_000 {
+        let mut card = template.clone();
+        card.name = format!("Unique {}", n);
+        card.image_path = format!("https://example.com/unique_{}.jpg", n);
+        data.cards.insert(format!("ALT_CORE_B_AX_01_U_{}", n), card);
+    }
+
+    let bytes = create_optimized_database(&data).unwrap();
+
+    let db = root_as_optimized_card_database(&bytes).unwrap();
+    let strings = db.string_pool().unwrap().strings().unwrap();
+    assert!(strings.len() > u16::MAX as usize);
+    for card in db.cards().unwrap().iter() {
+        let expected = &data.cards[strings.get(card.reference_idx() as usize)];
+        assert_eq!(strings.get(card.name_idx() as usize), expected.name);
+        assert_eq!(strings.get(card.image_path_idx() as usize), expected.image_path);
+    }
+}
+
+#[test]
+fn optimized_database_round_trips() {
+    let scratch = common::scratch_dir("optimizer_v2");
+    let data = common::optimized_fixtures(&scratch);
+
+    let bytes = create_optimized_database(&data).unwrap();
+    let db = root_as_optimized_card_database(&bytes).unwrap();
+    let pool = db.string_pool().unwrap().strings().unwrap();
+    let string = |idx: u32| pool.get(idx as usize);
+
+    assert_eq!(string(db.script_version_idx()), data.meta.script_version);
+    assert_eq!(string(db.generated_at_utc_idx()), data.meta.generated_at_utc.to_rfc3339());
+    assert_eq!(db.total_cards() as usize, data.cards.len());
+    let factions = db.factions().unwrap();
+    assert_eq!(factions.len(), data.lookup_tables.factions.len());
+    for faction in factions.iter() {
+        let info = &data.lookup_tables.factions[string(faction.reference_idx())];
+        assert_eq!(string(faction.name_idx()), info.name);
+        assert_eq!(string(faction.color_idx()), info.color);
+    }
+
+    let cards = db.cards().unwrap();
+    assert_eq!(cards.len(), data.cards.len());
+    let references: Vec<&str> = cards.iter().map(|card| string(card.reference_idx())).collect();
+    assert_eq!(references, data.cards.keys().map(String::as_str).collect::<Vec<_>>());
+    let ids: Vec<u32> = db
+        .id_index()
+        .unwrap()
+        .iter()
+        .map(|position| cards.get(position as usize).id())
+        .collect();
+    assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+    for card in cards.iter() {
+        let reference = string(card.reference_idx());
+        let expected = &data.cards[reference];
+        assert_eq!(card.id(), generate_numeric_id(reference));
+        assert_eq!(string(card.name_idx()), expected.name);
+        assert_eq!(string(card.image_path_idx()), expected.image_path);
+        assert_eq!(string(card.qr_url_idx()), expected.qr_url);
+        let faction = factions.get(card.faction_id() as usize);
+        assert_eq!(string(faction.reference_idx()), expected.faction_ref);
+        let rarity = db.rarities().unwrap().get(card.rarity_id() as usize);
+        assert_eq!(string(rarity.reference_idx()), expected.rarity_ref);
+        let card_type = db.card_types().unwrap().get(card.card_type_id() as usize);
+        assert_eq!(string(card_type.reference_idx()), expected.type_ref);
+        assert_eq!(card.main_cost() as i64, expected.main_cost);
+        assert_eq!(card.recall_cost() as i64, expected.recall_cost);
+        let (m, o, f) = unpack_power_values(card.power_packed());
+        assert_eq!((m as i64, o as i64, f as i64), (expected.power.m, expected.power.o, expected.power.f));
+        assert_eq!(card.flags() & FLAG_SUSPENDED != 0, expected.is_suspended);
+    }
+}
@@ -0,0 +1,92 @@
+// Tests for the typed raw card model.
+
+mod common;
+
+use altered_harvester::raw_card::{RawCard, RawCardMode};
+use serde_json::{json, Value};
+
+fn fixture_card() -> Value {
+    let page: Value =
+        serde_json::from_str(&std::fs::read_to_string(common::fixtures_dir().join("cards_core_page_1.json")).unwrap())
+            .unwrap();
+    page["hydra:member"][0].clone()
+}
+
+#[test]
+fn parses_an_api_card() {
+    let card = RawCard::parse(fixture_card(), RawCardMode::Strict).unwrap();
+
+    assert_eq!(card.reference, "ALT_CORE_B_AX_04_C");
+    assert_eq!(card.card_type.reference, "CHARACTER");
+    assert_eq!(card.card_set.reference, "CORE");
+    assert_eq!(card.main_faction.color, "#8c432a");
+    assert_eq!(card.elements.main_cost, Some(1));
+    assert_eq!(card.elements.forest_power, Some(1));
+    assert!(card.unknown_fields().is_empty());
+}
+
+#[test]
+fn lenient_mode_keeps_unknown_fields() {
+    let mut value = fixture_card();
+    value["loreEntries"] = json!([{ "text": "..." }]);
+    value["rarity"]["position"] = json!(1);
+
+    let card = RawCard::parse(value.clone(), RawCardMode::Lenient).unwrap();
+
+    assert_eq!(card.unknown_fields(), ["loreEntries", "rarity.position"]);
+    // Unknown fields survive a round trip, and numeric elements keep their string form.
+    assert_eq!(serde_json::to_value(&card).unwrap(), value);
+}
+
+#[test]
+fn strict_mode_rejects_unknown_fields() {
+    let mut value = fixture_card();
+    value["elements"]["PERMANENT"] = json!("1");
+
+    let error = RawCard::parse(value, RawCardMode::Strict).unwrap_err();
+
+    assert_eq!(error.reference.as_deref(), Some("ALT_CORE_B_AX_04_C"));
+    assert!(error.reason.contains("elements.PERMANENT"), "{}", error);
+}
+
+#[test]
+fn rejects_missing_and_mistyped_fields() {
+    let mut missing = fixture_card();
+    missing.as_object_mut().unwrap().remove("mainFaction");
+    let error = RawCard::parse(missing, RawCardMode::Lenient).unwrap_err();
+    assert!(error.reason.contains("mainFaction"), "{}", error);
+
+    let mut mistyped = fixture_card();
+    mistyped["elements"]["MAIN_COST"] = json!("three");
+    let error = RawCard::parse(mistyped, RawCardMode::Lenient).unwrap_err();
+    assert!(error.reason.contains("three"), "{}", error);
+}
+
+#[test]
+fn parses_a_harvested_card_with_empty_assets() {
+    // Copied from a real harvest: `assets` comes as an empty array.
+    let value: Value =
+        serde_json::from_str(&std::fs::read_to_string(common::fixtures_dir().join("card_coreks_hero.json")).unwrap())
+            .unwrap();
+
+    let card = RawCard::parse(value, RawCardMode::Strict).unwrap();
+
+    assert_eq!(card.reference, "ALT_COREKS_B_AX_01_C");
+    assert_eq!(card.id.as_deref(), Some("01HN5TXR2AE9M3ZWKWYP5F7V7K"));
+    assert!(card.assets.is_empty());
+    assert!(card.card_sub_types.is_empty());
+    assert_eq!(card.card_type.reference, "HERO");
+}
+
+#[test]
+fn parses_assets_given_as_a_map() {
+    let mut value = fixture_card();
+    value["assets"] = json!({ "WEB": ["https://example.com/a.jpg"] });
+    let card = RawCard::parse(value, RawCardMode::Strict).unwrap();
+    assert_eq!(card.assets["WEB"], ["https://example.com/a.jpg"]);
+
+    let mut value = fixture_card();
+    value["assets"] = json!(["https://example.com/a.jpg"]);
+    let error = RawCard::parse(value, RawCardMode::Strict).unwrap_err();
+    assert!(error.reason.contains("asset map"), "{}", error);
+}
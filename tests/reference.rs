@@ -0,0 +1,62 @@
+// Tests for the card reference grammar.
+
+use altered_harvester::reference::CardReference;
+
+#[test]
+fn parses_a_common() {
+    let parsed = CardReference::parse("ALT_CORE_B_AX_01_C").unwrap();
+
+    assert_eq!(parsed.set, "CORE");
+    assert_eq!(parsed.product, "B");
+    assert_eq!(parsed.faction, "AX");
+    assert_eq!(parsed.number, "01");
+    assert_eq!(parsed.rarity, "C");
+    assert_eq!(parsed.unique_id, None);
+    assert_eq!(parsed.variant(), "C");
+}
+
+#[test]
+fn parses_a_unique() {
+    let parsed = CardReference::parse("ALT_CORE_B_MU_10_U_4521").unwrap();
+
+    assert_eq!(parsed.rarity, "U");
+    assert_eq!(parsed.unique_id.as_deref(), Some("4521"));
+    assert_eq!(parsed.variant(), "U_4521");
+}
+
+#[test]
+fn parses_a_foiler() {
+    let parsed = CardReference::parse("ALT_CORE_B_NE_FOILER_R1").unwrap();
+
+    assert_eq!(parsed.faction, "NE");
+    assert_eq!(parsed.number, "FOILER");
+    assert_eq!(parsed.variant(), "R1");
+    assert_eq!(parsed.family_key(), CardReference::parse("ALT_COREKS_B_NE_FOILER_C").unwrap().family_key());
+}
+
+#[test]
+fn printings_and_rarity_variants_share_a_family() {
+    let family = |reference| CardReference::parse(reference).unwrap().family_key();
+
+    assert_eq!(family("ALT_CORE_B_AX_01_C"), "CORE_AX_01");
+    assert_eq!(family("ALT_COREKS_B_AX_01_C"), "CORE_AX_01");
+    assert_eq!(family("ALT_CORE_P_AX_01_R1"), "CORE_AX_01");
+    assert_eq!(family("ALT_CORE_B_AX_01_R2"), "CORE_AX_01");
+    assert_eq!(family("ALT_CORE_B_AX_01_U_7"), "CORE_AX_01");
+    assert_ne!(family("ALT_ALIZE_B_AX_01_C"), "CORE_AX_01");
+}
+
+#[test]
+fn rejects_malformed_references() {
+    for reference in [
+        "",
+        "CORE_B_AX_01_C",
+        "ALT_CORE_B_AX_01",
+        "ALT_CORE_B_AX_XX_C",
+        "ALT_CORE_B_NE_foiler_C",
+        "ALT_CORE_B_AX_01_C_7",
+        "ALT__B_AX_01_C",
+    ] {
+        assert!(CardReference::parse(reference).is_err(), "{}", reference);
+    }
+}
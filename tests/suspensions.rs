@@ -0,0 +1,197 @@
+// Tests for suspension tracking.
+
+mod common;
+
+use altered_harvester::cards_generated::altered_cards::{
+    root_as_card_database, CardDatabase, CardDatabaseArgs, SuspensionEvent, SuspensionEventArgs,
+};
+use altered_harvester::harvester::HarvestedCard;
+use altered_harvester::raw_card::{RawCard, RawCardMode};
+use altered_harvester::suspensions::{
+    collect_evidence, find_disagreements, suspension_timeline, SuspensionEvidence,
+    SuspensionLedger, SuspensionStatusChange,
+};
+use chrono::{DateTime, TimeZone, Utc};
+use flatbuffers::FlatBufferBuilder;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+fn fixture_card(reference: &str) -> RawCard {
+    let page: Value = serde_json::from_str(
+        &std::fs::read_to_string(common::fixtures_dir().join("cards_core_page_1.json")).unwrap(),
+    )
+    .unwrap();
+    let mut member = page["hydra:member"][0].clone();
+    member["reference"] = reference.into();
+    RawCard::parse(member, RawCardMode::Strict).unwrap()
+}
+
+/// A record of `reference`, from the suspended query when `listed`.
+fn record(reference: &str, listed: bool, flagged: bool) -> HarvestedCard {
+    let mut card_data = fixture_card(reference);
+    card_data.is_suspended = flagged;
+    HarvestedCard {
+        card_data,
+        is_suspended: listed,
+        locale: "fr-fr".to_string(),
+        details: None,
+        harvested_at_utc: None,
+    }
+}
+
+#[test]
+fn combines_both_sources_whatever_the_order() {
+    let mut cards = vec![
+        record("ALT_CORE_B_AX_04_C", true, true),
+        record("ALT_CORE_B_AX_04_C", false, false),
+        record("ALT_CORE_B_BR_06_C", false, true),
+        record("ALT_CORE_B_LY_08_C", false, false),
+    ];
+    let forward = collect_evidence(&cards);
+    cards.reverse();
+    assert_eq!(collect_evidence(&cards), forward);
+
+    let suspended: Vec<&str> = forward
+        .iter()
+        .filter(|(_, e)| e.is_suspended())
+        .map(|(reference, _)| reference.as_str())
+        .collect();
+    assert_eq!(suspended, ["ALT_CORE_B_AX_04_C", "ALT_CORE_B_BR_06_C"]);
+    assert_eq!(
+        find_disagreements(&forward),
+        [(
+            "ALT_CORE_B_BR_06_C".to_string(),
+            SuspensionEvidence {
+                listed: false,
+                flagged: true
+            }
+        )]
+    );
+}
+
+#[test]
+fn flagged_cards_are_not_disagreements_without_the_suspended_query() {
+    let cards = vec![
+        record("ALT_CORE_B_AX_04_C", false, true),
+        record("ALT_CORE_B_BR_06_C", false, false),
+    ];
+    assert!(find_disagreements(&collect_evidence(&cards)).is_empty());
+}
+
+#[test]
+fn ledger_keeps_the_first_suspension_date() {
+    let first_run = Utc.with_ymd_and_hms(2025, 1, 10, 0, 0, 0).unwrap();
+    let second_run = Utc.with_ymd_and_hms(2025, 2, 10, 0, 0, 0).unwrap();
+    let third_run = Utc.with_ymd_and_hms(2025, 3, 10, 0, 0, 0).unwrap();
+    let mut ledger = SuspensionLedger::default();
+
+    let changes = ledger.update([("A", true), ("B", false)], first_run).unwrap();
+    assert_eq!(changes.suspended, ["A"]);
+    let changes = ledger.update([("A", true), ("B", true)], second_run).unwrap();
+    assert_eq!(changes.suspended, ["B"]);
+    assert_eq!(ledger.suspended_since("A"), Some(first_run));
+    assert_eq!(ledger.suspended_since("B"), Some(second_run));
+
+    // Lifted suspensions are forgotten; cards absent from the run are kept.
+    let changes = ledger.update([("A", false)], third_run).unwrap();
+    assert_eq!(changes.lifted, ["A"]);
+    assert_eq!(ledger.suspended_since("A"), None);
+    assert_eq!(ledger.suspended_since("B"), Some(second_run));
+
+    // An older harvest arriving late does not rewrite the ledger.
+    assert_eq!(ledger.update([("A", true), ("B", false)], second_run), None);
+    assert_eq!(ledger.suspended_since("A"), None);
+    assert_eq!(ledger.suspended_since("B"), Some(second_run));
+    assert_eq!(ledger.updated_at_utc, Some(third_run));
+
+    let scratch = common::scratch_dir("suspension_ledger");
+    let path = scratch.join("ledger.json");
+    ledger.save(&path).unwrap();
+    let reloaded = SuspensionLedger::load(&path).unwrap();
+    assert_eq!(reloaded.suspended, ledger.suspended);
+    assert_eq!(reloaded.updated_at_utc, Some(third_run));
+    assert_eq!(
+        reloaded.history,
+        [
+            change(first_run, "A", true),
+            change(second_run, "B", true),
+            change(third_run, "A", false),
+        ]
+    );
+}
+
+fn change(date: DateTime<Utc>, reference: &str, suspended: bool) -> SuspensionStatusChange {
+    SuspensionStatusChange {
+        date,
+        reference: reference.to_string(),
+        suspended,
+    }
+}
+
+#[test]
+fn history_is_rebuilt_from_snapshots_in_date_order() {
+    let january = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+    let march = Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap();
+    let may = Utc.with_ymd_and_hms(2025, 5, 1, 0, 0, 0).unwrap();
+    let snapshot = |statuses: &[(&str, bool)]| -> BTreeMap<String, bool> {
+        statuses.iter().map(|(r, s)| (r.to_string(), *s)).collect()
+    };
+    let ledger = SuspensionLedger::from_snapshots(vec![
+        (may, snapshot(&[("A", true), ("B", false)])),
+        (january, snapshot(&[("A", false), ("B", true)])),
+        (march, snapshot(&[("A", true), ("B", true)])),
+    ]);
+
+    assert_eq!(
+        ledger.history().changes,
+        [
+            change(january, "B", true),
+            change(march, "A", true),
+            change(may, "B", false),
+        ]
+    );
+    assert_eq!(ledger.suspended_since("A"), Some(march));
+}
+
+#[test]
+fn suspension_timeline_is_filtered_by_card() {
+    let march = Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap();
+    let may = Utc.with_ymd_and_hms(2025, 5, 1, 0, 0, 0).unwrap();
+    let changes = [change(march, "A", true), change(march, "B", true), change(may, "A", false)];
+    let mut builder = FlatBufferBuilder::new();
+    let events: Vec<_> = changes
+        .iter()
+        .map(|change| {
+            let reference = builder.create_string(&change.reference);
+            let date = builder.create_string(&change.date.to_rfc3339());
+            SuspensionEvent::create(
+                &mut builder,
+                &SuspensionEventArgs {
+                    reference: Some(reference),
+                    suspended: change.suspended,
+                    date: Some(date),
+                },
+            )
+        })
+        .collect();
+    let suspension_events = builder.create_vector(&events);
+    let database = CardDatabase::create(
+        &mut builder,
+        &CardDatabaseArgs {
+            suspension_events: Some(suspension_events),
+            ..Default::default()
+        },
+    );
+    builder.finish(database, None);
+    let database = root_as_card_database(builder.finished_data()).unwrap();
+    let timeline = |reference: Option<&str>| -> Vec<(String, bool)> {
+        suspension_timeline(&database, reference)
+            .iter()
+            .map(|event| (event.reference().unwrap().to_string(), event.suspended()))
+            .collect()
+    };
+
+    assert_eq!(timeline(None).len(), 3);
+    assert_eq!(timeline(Some("A")), [("A".to_string(), true), ("A".to_string(), false)]);
+    assert!(timeline(Some("C")).is_empty());
+}
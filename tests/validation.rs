@@ -0,0 +1,117 @@
+// Tests for the validation stage.
+
+mod common;
+
+use altered_harvester::config::ValidationConfig;
+use altered_harvester::harvester::HarvestedCard;
+use altered_harvester::raw_card::{RawCard, RawCardMode};
+use altered_harvester::validation::{validate_cards, validate_records, Severity};
+use serde_json::{json, Value};
+
+fn fixture_cards() -> Vec<HarvestedCard> {
+    let page: Value = serde_json::from_str(
+        &std::fs::read_to_string(common::fixtures_dir().join("cards_core_page_1.json")).unwrap(),
+    )
+    .unwrap();
+    page["hydra:member"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|member| HarvestedCard {
+            card_data: RawCard::parse(member.clone(), RawCardMode::Strict).unwrap(),
+            is_suspended: false,
+            locale: "fr-fr".to_string(),
+            details: None,
+            harvested_at_utc: None,
+        })
+        .collect()
+}
+
+#[test]
+fn clean_harvest_passes() {
+    let config = ValidationConfig::default();
+    let report = validate_cards(&fixture_cards(), &config);
+
+    assert_eq!(report.total_cards, 2);
+    assert!(report.issues.is_empty(), "{:?}", report.issues);
+    assert!(report.check_thresholds(&config).is_ok());
+}
+
+#[test]
+fn reports_bad_values_and_applies_thresholds() {
+    let mut cards = fixture_cards();
+    cards[0].card_data.elements.main_cost = Some(300);
+    cards[0].card_data.main_faction.reference = "XX".to_string();
+    cards[1].card_data.elements.forest_power = Some(14);
+    cards[1].card_data.elements.recall_cost = None;
+    let duplicate = HarvestedCard {
+        card_data: cards[1].card_data.clone(),
+        is_suspended: false,
+        locale: "fr-fr".to_string(),
+        details: None,
+        harvested_at_utc: None,
+    };
+    cards.push(duplicate);
+
+    let mut config = ValidationConfig::default();
+    let report = validate_cards(&cards, &config);
+
+    let codes: Vec<(&str, Severity, &str)> = report
+        .issues
+        .iter()
+        .map(|i| (i.reference.as_str(), i.severity, i.code.as_str()))
+        .collect();
+    assert!(codes.contains(&("ALT_CORE_B_AX_04_C", Severity::Error, "out_of_range")));
+    assert!(codes.contains(&("ALT_CORE_B_AX_04_C", Severity::Warning, "unknown_reference")));
+    assert!(codes.contains(&("ALT_CORE_B_BR_06_C", Severity::Warning, "suspicious_value")));
+    assert!(codes.contains(&("ALT_CORE_B_BR_06_C", Severity::Warning, "missing_element")));
+    assert!(codes.contains(&("ALT_CORE_B_BR_06_C", Severity::Warning, "duplicate_reference")));
+    assert_eq!(report.errors, 1);
+
+    assert!(report.check_thresholds(&config).is_err());
+    config.max_errors = 1;
+    assert!(report.check_thresholds(&config).is_ok());
+    config.max_warnings = Some(2);
+    assert!(report.check_thresholds(&config).is_err());
+
+    let json = serde_json::to_value(&report).unwrap();
+    assert_eq!(json["issues"][0]["severity"], "error");
+}
+
+#[test]
+fn reports_records_that_do_not_parse() {
+    let mut records: Vec<Value> = fixture_cards()
+        .iter()
+        .map(|card| serde_json::to_value(card).unwrap())
+        .collect();
+    records[0]["card_data"]["elements"]["MAIN_COST"] = json!("X");
+    records[0]["card_data"]["elements"]["FOREST_POWER"] = json!("1+");
+    records[1]["card_data"].as_object_mut().unwrap().remove("mainFaction");
+    let mut good = records[1].clone();
+    good["card_data"]["reference"] = json!("ALT_CORE_B_BR_06_R1");
+    good["card_data"]["mainFaction"] = json!({ "reference": "BR", "name": "Bravos", "color": "#9a4734" });
+    records.push(good);
+
+    let config = ValidationConfig::default();
+    let (cards, report) = validate_records(records, RawCardMode::Strict, &config);
+
+    assert_eq!(cards.len(), 1);
+    assert_eq!(cards[0].card_data.reference, "ALT_CORE_B_BR_06_R1");
+    assert_eq!(report.total_cards, 3);
+    let issues: Vec<(&str, &str, &str)> = report
+        .issues
+        .iter()
+        .map(|i| (i.reference.as_str(), i.code.as_str(), i.field.as_str()))
+        .collect();
+    assert_eq!(
+        issues,
+        [
+            ("ALT_CORE_B_AX_04_C", "invalid_number", "elements.MAIN_COST"),
+            ("ALT_CORE_B_AX_04_C", "invalid_number", "elements.FOREST_POWER"),
+            ("ALT_CORE_B_BR_06_C", "unparsable_card", ""),
+        ]
+    );
+    assert!(report.issues[2].message.contains("mainFaction"), "{}", report.issues[2].message);
+    assert_eq!(report.errors, 3);
+    assert!(report.check_thresholds(&config).is_err());
+}